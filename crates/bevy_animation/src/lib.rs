@@ -42,7 +42,7 @@ use bevy_ecs::{
 };
 use bevy_math::FloatOrd;
 use bevy_reflect::{prelude::ReflectDefault, Reflect, TypePath};
-use bevy_time::Time;
+use bevy_time::{BoundTimeDomain, Time, TimeDomainDeltas};
 use bevy_transform::TransformSystem;
 use bevy_utils::{
     hashbrown::HashMap,
@@ -992,19 +992,32 @@ fn trigger_untargeted_animation_events(
 }
 
 /// A system that advances the time for all playing animations.
+///
+/// Players of entities [bound](BoundTimeDomain) to a time domain advance with the time
+/// of this domain.
 pub fn advance_animations(
     time: Res<Time>,
+    time_domains: Option<Res<TimeDomainDeltas>>,
     animation_clips: Res<Assets<AnimationClip>>,
     animation_graphs: Res<Assets<AnimationGraph>>,
-    mut players: Query<(&mut AnimationPlayer, &AnimationGraphHandle)>,
+    mut players: Query<(
+        &mut AnimationPlayer,
+        &AnimationGraphHandle,
+        Option<&BoundTimeDomain>,
+    )>,
 ) {
-    let delta_seconds = time.delta_secs();
     players
         .par_iter_mut()
-        .for_each(|(mut player, graph_handle)| {
+        .for_each(|(mut player, graph_handle, time_domain)| {
             let Some(animation_graph) = animation_graphs.get(graph_handle) else {
                 return;
             };
+            let delta_seconds = match (&time_domains, time_domain) {
+                (Some(time_domains), Some(time_domain)) => time_domains
+                    .delta_or(Some(time_domain), time.delta())
+                    .as_secs_f32(),
+                _ => time.delta_secs(),
+            };
 
             // Tick animations, and schedule them.
 
@@ -1541,10 +1554,17 @@ impl<'a> Iterator for TriggeredEventsIter<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bevy_app::Update;
+    use bevy_time::{Domain, TimeDomain, TimeDomainPlugin, TimePlugin, TimeUpdateStrategy};
+    use core::time::Duration;
 
     #[derive(Event, Reflect, Clone)]
     struct A;
 
+    struct Slow;
+
+    impl TimeDomain for Slow {}
+
     #[track_caller]
     fn assert_triggered_events_with(
         active_animation: &ActiveAnimation,
@@ -1671,4 +1691,63 @@ mod tests {
         active_animation.update(clip.duration, clip.duration); // 0.3 : 0.0
         assert_triggered_events_with(&active_animation, &clip, [0.3, 0.2]);
     }
+
+    #[test]
+    fn players_advance_with_their_bound_time_domain() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TimeDomainPlugin::<Slow>::default()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .init_resource::<Assets<AnimationClip>>()
+            .init_resource::<Assets<AnimationGraph>>()
+            .add_systems(Update, advance_animations);
+        app.world_mut()
+            .resource_mut::<Time<Domain<Slow>>>()
+            .set_relative_speed(0.5);
+
+        let clip = app
+            .world_mut()
+            .resource_mut::<Assets<AnimationClip>>()
+            .add(AnimationClip {
+                duration: 10.0,
+                ..Default::default()
+            });
+        let (graph, node) = AnimationGraph::from_clip(clip);
+        let graph = app
+            .world_mut()
+            .resource_mut::<Assets<AnimationGraph>>()
+            .add(graph);
+        let mut player = AnimationPlayer::default();
+        player.play(node);
+        let unbound = app
+            .world_mut()
+            .spawn((player.clone(), AnimationGraphHandle(graph.clone())))
+            .id();
+        let bound = app
+            .world_mut()
+            .spawn((
+                player,
+                AnimationGraphHandle(graph),
+                BoundTimeDomain::new::<Slow>(),
+            ))
+            .id();
+
+        // The first update only starts the clocks.
+        app.update();
+        for _ in 0..4 {
+            app.update();
+        }
+
+        let seek_time = |entity| {
+            app.world()
+                .get::<AnimationPlayer>(entity)
+                .unwrap()
+                .animation(node)
+                .unwrap()
+                .seek_time()
+        };
+        assert!((seek_time(unbound) - 0.4).abs() < 1e-5);
+        assert!((seek_time(bound) - 0.2).abs() < 1e-5);
+    }
 }
@@ -9,7 +9,7 @@ use bevy_ecs::{
     system::{Query, Res},
 };
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
-use bevy_time::Time;
+use bevy_time::{BoundTimeDomain, Time, TimeDomainDeltas};
 use bevy_utils::Duration;
 
 use crate::{graph::AnimationNodeIndex, ActiveAnimation, AnimationPlayer};
@@ -108,9 +108,17 @@ impl AnimationTransitions {
 
 /// A system that alters the weight of currently-playing transitions based on
 /// the current time and decline amount.
+///
+/// Transitions of entities [bound](BoundTimeDomain) to a time domain advance with the time
+/// of this domain.
 pub fn advance_transitions(
-    mut query: Query<(&mut AnimationTransitions, &mut AnimationPlayer)>,
+    mut query: Query<(
+        &mut AnimationTransitions,
+        &mut AnimationPlayer,
+        Option<&BoundTimeDomain>,
+    )>,
     time: Res<Time>,
+    time_domains: Option<Res<TimeDomainDeltas>>,
 ) {
     // We use a "greedy layer" system here. The top layer (most recent
     // transition) gets as much as weight as it wants, and the remaining amount
@@ -118,12 +126,18 @@ pub fn advance_transitions(
     // currently-playing animation receiving whatever's left. This results in a
     // nicely normalized weight.
     let mut remaining_weight = 1.0;
-    for (mut animation_transitions, mut player) in query.iter_mut() {
+    for (mut animation_transitions, mut player, time_domain) in query.iter_mut() {
+        let delta_seconds = match (&time_domains, time_domain) {
+            (Some(time_domains), Some(time_domain)) => time_domains
+                .delta_or(Some(time_domain), time.delta())
+                .as_secs_f32(),
+            _ => time.delta_secs(),
+        };
         for transition in &mut animation_transitions.transitions.iter_mut().rev() {
             // Decrease weight.
             transition.current_weight = (transition.current_weight
-                - transition.weight_decline_per_sec * time.delta_secs())
-            .max(0.0);
+                - transition.weight_decline_per_sec * delta_seconds)
+                .max(0.0);
 
             // Update weight.
             let Some(ref mut animation) = player.animation_mut(transition.animation) else {
@@ -5,13 +5,14 @@
 use bevy_macro_utils::BevyManifest;
 use proc_macro::{Span, TokenStream};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, Data, DeriveInput, Path};
+use syn::{parse_macro_input, Data, DeriveInput, LitStr, Path};
 
 pub(crate) fn bevy_asset_path() -> Path {
     BevyManifest::default().get_path("bevy_asset")
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
 
 #[proc_macro_derive(Asset, attributes(dependency))]
 pub fn derive_asset(input: TokenStream) -> TokenStream {
@@ -123,3 +124,91 @@ fn derive_dependency_visitor_internal(
         }
     })
 }
+
+#[proc_macro_derive(AssetCollection, attributes(asset))]
+pub fn derive_asset_collection(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_asset_path: Path = bevy_asset_path();
+    match derive_asset_collection_internal(&ast, &bevy_asset_path) {
+        Ok(asset_collection) => TokenStream::from(asset_collection),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn derive_asset_collection_internal(
+    ast: &DeriveInput,
+    bevy_asset_path: &Path,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new(
+            Span::call_site().into(),
+            "AssetCollection derive only works on structs",
+        ));
+    };
+
+    let mut field_loads = Vec::new();
+    let mut field_visitors = Vec::new();
+    for (i, field) in data_struct.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(i);
+                quote!(#index)
+            }
+        };
+
+        let mut load = None;
+        let is_asset_attribute = |a: &&syn::Attribute| a.path().is_ident(ASSET_ATTRIBUTE);
+        for attribute in field.attrs.iter().filter(is_asset_attribute) {
+            attribute.parse_nested_meta(|meta| {
+                let method = if meta.path.is_ident("path") {
+                    quote!(load)
+                } else if meta.path.is_ident("folder") {
+                    quote!(load_folder)
+                } else {
+                    return Err(meta.error("expected `path` or `folder`"));
+                };
+                if load.is_some() {
+                    return Err(meta.error("an asset collection field can only load one path"));
+                }
+                let path: LitStr = meta.value()?.parse()?;
+                load = Some(quote!(asset_server.#method(#path)));
+                Ok(())
+            })?;
+        }
+
+        match load {
+            Some(load) => {
+                field_loads.push(quote!(#member: #load));
+                field_visitors.push(quote!(#bevy_asset_path::VisitAssetDependencies::visit_dependencies(&self.#member, visit);));
+            }
+            None => field_loads.push(quote!(#member: ::core::default::Default::default())),
+        }
+    }
+
+    // prevent unused variable warnings in case no field is loaded
+    let (asset_server, visit) = if field_visitors.is_empty() {
+        (quote! { _asset_server }, quote! { _visit })
+    } else {
+        (quote! { asset_server }, quote! { visit })
+    };
+
+    Ok(quote! {
+        impl #impl_generics #bevy_asset_path::AssetCollection for #struct_name #type_generics #where_clause {
+            fn load(#asset_server: &#bevy_asset_path::AssetServer) -> Self {
+                Self {
+                    #(#field_loads,)*
+                }
+            }
+        }
+
+        impl #impl_generics #bevy_asset_path::VisitAssetDependencies for #struct_name #type_generics #where_clause {
+            fn visit_dependencies(&self, #visit: &mut impl FnMut(#bevy_asset_path::UntypedAssetId)) {
+                #(#field_visitors)*
+            }
+        }
+    })
+}
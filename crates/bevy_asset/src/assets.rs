@@ -1,14 +1,15 @@
 use crate::{
-    self as bevy_asset, Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer, Handle,
-    UntypedHandle,
+    self as bevy_asset, Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer,
+    DependencyLoadState, Handle, LoadState, RecursiveDependencyLoadState, UntypedHandle,
 };
+use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
 use bevy_ecs::{
     prelude::EventWriter,
     system::{Res, ResMut, Resource},
 };
 use bevy_reflect::{Reflect, TypePath};
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use core::{any::TypeId, iter::Enumerate, marker::PhantomData, sync::atomic::AtomicU32};
 use crossbeam_channel::{Receiver, Sender};
 use derive_more::derive::{Display, Error};
@@ -272,6 +273,48 @@ impl<A: Asset> DenseAssetStorage<A> {
     }
 }
 
+/// Tracks the memory used by the assets of a type, see [`Assets::set_memory_budget`].
+struct MemoryBudget<A: Asset> {
+    max_bytes: usize,
+    size_of: fn(&A) -> usize,
+    used_bytes: usize,
+    sizes: HashMap<AssetId<A>, usize>,
+    /// The last use of every tracked asset, used to evict the least recently used assets first.
+    last_used: HashMap<AssetId<A>, u64>,
+    by_last_use: BTreeMap<u64, AssetId<A>>,
+    next_use: u64,
+    pinned: HashSet<AssetId<A>>,
+    cacheable: HashSet<AssetId<A>>,
+    /// The assets removed by an eviction, which keep their flags to be reloaded.
+    evicted: HashSet<AssetId<A>>,
+}
+
+impl<A: Asset> MemoryBudget<A> {
+    fn touch(&mut self, id: AssetId<A>) {
+        if let Some(last_use) = self.last_used.insert(id, self.next_use) {
+            self.by_last_use.remove(&last_use);
+        }
+        self.by_last_use.insert(self.next_use, id);
+        self.next_use += 1;
+    }
+
+    fn track(&mut self, id: AssetId<A>, asset: &A) {
+        let size = (self.size_of)(asset);
+        let previous = self.sizes.insert(id, size).unwrap_or(0);
+        self.used_bytes = self.used_bytes - previous + size;
+        self.touch(id);
+    }
+
+    fn forget(&mut self, id: AssetId<A>) {
+        if let Some(size) = self.sizes.remove(&id) {
+            self.used_bytes -= size;
+        }
+        if let Some(last_use) = self.last_used.remove(&id) {
+            self.by_last_use.remove(&last_use);
+        }
+    }
+}
+
 /// Stores [`Asset`] values identified by their [`AssetId`].
 ///
 /// Assets identified by [`AssetId::Index`] will be stored in a "dense" vec-like storage. This is more efficient, but it means that
@@ -281,6 +324,9 @@ impl<A: Asset> DenseAssetStorage<A> {
 /// at compile time.
 ///
 /// This tracks (and queues) [`AssetEvent`] events whenever changes to the collection occur.
+///
+/// An optional [memory budget](Assets::set_memory_budget) can be set to evict the least recently
+/// used assets when the assets of this type use too much memory.
 #[derive(Resource)]
 pub struct Assets<A: Asset> {
     dense_storage: DenseAssetStorage<A>,
@@ -290,6 +336,7 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    memory_budget: Option<MemoryBudget<A>>,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -303,6 +350,7 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            memory_budget: None,
         }
     }
 }
@@ -556,6 +604,192 @@ impl<A: Asset> Assets<A> {
         }
     }
 
+    /// Sets a budget of `max_bytes` for the memory used by the assets in this collection, as measured by `size_of`.
+    ///
+    /// When the assets use more memory than the budget, the least recently used evictable assets are removed from this
+    /// collection and an [`AssetEvent::Removed`] is sent for them. An asset loaded by the [`AssetServer`] is evictable
+    /// when no strong handle keeps it alive, or when it is [cacheable](Assets::mark_cacheable), even if strong handles
+    /// to it are alive. The load state of evicted assets goes back to [`LoadState::NotLoaded`], so the next
+    /// [`AssetServer::load`] of their path transparently reloads them into the same handle. Assets added directly to
+    /// this collection and [pinned](Assets::pin) assets are never evicted.
+    ///
+    /// An asset is used when it is added or modified, or when [`Assets::touch`] is called.
+    ///
+    /// ```
+    /// # use bevy_asset::{Asset, Assets};
+    /// # use bevy_reflect::TypePath;
+    /// #[derive(Asset, TypePath)]
+    /// struct Audio {
+    ///     samples: Vec<f32>,
+    /// }
+    ///
+    /// let mut audio = Assets::<Audio>::default();
+    /// audio.set_memory_budget(64 * 1024 * 1024, |audio| {
+    ///     audio.samples.len() * size_of::<f32>()
+    /// });
+    /// ```
+    ///
+    /// [`LoadState::NotLoaded`]: crate::LoadState::NotLoaded
+    pub fn set_memory_budget(&mut self, max_bytes: usize, size_of: fn(&A) -> usize) {
+        let mut budget = MemoryBudget {
+            max_bytes,
+            size_of,
+            used_bytes: 0,
+            sizes: Default::default(),
+            last_used: Default::default(),
+            by_last_use: Default::default(),
+            next_use: 0,
+            pinned: Default::default(),
+            cacheable: Default::default(),
+            evicted: Default::default(),
+        };
+        if let Some(previous) = self.memory_budget.take() {
+            budget.pinned = previous.pinned;
+            budget.cacheable = previous.cacheable;
+            budget.evicted = previous.evicted;
+        }
+        for (id, asset) in self.iter() {
+            budget.track(id, asset);
+        }
+        self.memory_budget = Some(budget);
+    }
+
+    /// Removes the memory budget of this collection, see [`Assets::set_memory_budget`].
+    pub fn remove_memory_budget(&mut self) {
+        self.memory_budget = None;
+    }
+
+    /// Returns the memory budget of this collection in bytes, if it has one.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget.as_ref().map(|budget| budget.max_bytes)
+    }
+
+    /// Returns the memory used by the assets in this collection in bytes, if it has a [memory budget](Assets::set_memory_budget).
+    ///
+    /// This is updated when [`AssetEvent`]s are sent, after the assets are added or modified.
+    pub fn memory_usage(&self) -> Option<usize> {
+        self.memory_budget.as_ref().map(|budget| budget.used_bytes)
+    }
+
+    /// Marks the asset `id` as used, so that it is evicted after the other assets when this collection is over its
+    /// [memory budget](Assets::set_memory_budget).
+    pub fn touch(&mut self, id: impl Into<AssetId<A>>) {
+        let id = id.into();
+        if let Some(budget) = &mut self.memory_budget {
+            if budget.last_used.contains_key(&id) {
+                budget.touch(id);
+            }
+        }
+    }
+
+    /// Prevents the asset `id` from being evicted when this collection is over its [memory budget](Assets::set_memory_budget).
+    pub fn pin(&mut self, id: impl Into<AssetId<A>>) {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.pinned.insert(id.into());
+        }
+    }
+
+    /// Allows the asset `id` to be evicted again after [`Assets::pin`].
+    pub fn unpin(&mut self, id: impl Into<AssetId<A>>) {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.pinned.remove(&id.into());
+        }
+    }
+
+    /// Allows the asset `id` to be evicted when this collection is over its [memory budget](Assets::set_memory_budget),
+    /// even while strong handles to it are alive. It is reloaded by the next [`AssetServer::load`] of its path.
+    pub fn mark_cacheable(&mut self, id: impl Into<AssetId<A>>) {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.cacheable.insert(id.into());
+        }
+    }
+
+    /// Prevents the asset `id` from being evicted while strong handles to it are alive, after [`Assets::mark_cacheable`].
+    pub fn unmark_cacheable(&mut self, id: impl Into<AssetId<A>>) {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.cacheable.remove(&id.into());
+        }
+    }
+
+    /// A system that evicts the least recently used assets while this collection is over its
+    /// [memory budget](Assets::set_memory_budget).
+    pub fn evict_over_budget(mut assets: ResMut<Self>, asset_server: Res<AssetServer>) {
+        let assets = &mut *assets;
+        let Some(mut budget) = assets.memory_budget.take() else {
+            return;
+        };
+        for event in &assets.queued_events {
+            match *event {
+                AssetEvent::Added { id } | AssetEvent::Modified { id } => {
+                    let asset = match id {
+                        AssetId::Index { index, .. } => assets.dense_storage.get(index),
+                        AssetId::Uuid { uuid } => assets.hash_map.get(&uuid),
+                    };
+                    match asset {
+                        Some(asset) => budget.track(id, asset),
+                        None => budget.forget(id),
+                    }
+                }
+                AssetEvent::Removed { id } => {
+                    budget.forget(id);
+                    if !budget.evicted.remove(&id) {
+                        budget.pinned.remove(&id);
+                        budget.cacheable.remove(&id);
+                    }
+                }
+                AssetEvent::Unused { .. } | AssetEvent::LoadedWithDependencies { .. } => {}
+            }
+        }
+        if budget.used_bytes > budget.max_bytes {
+            assets.evict(&mut budget, &asset_server);
+        }
+        assets.memory_budget = Some(budget);
+    }
+
+    fn evict(&mut self, budget: &mut MemoryBudget<A>, asset_server: &AssetServer) {
+        // hold the lock while evicting, so that loads requested meanwhile see the new load states
+        let mut infos = asset_server.data.infos.write();
+        let mut evicted = Vec::new();
+        let mut used_bytes = budget.used_bytes;
+        for &id in budget.by_last_use.values() {
+            if used_bytes <= budget.max_bytes {
+                break;
+            }
+            if budget.pinned.contains(&id) {
+                continue;
+            }
+            // only assets loaded from a path can be reloaded
+            let Some(info) = infos.get_mut(id.untyped()) else {
+                continue;
+            };
+            if info.path.is_none() || !info.load_state.is_loaded() {
+                continue;
+            }
+            if info.has_strong_handle() && !budget.cacheable.contains(&id) {
+                continue;
+            }
+            info.load_state = LoadState::NotLoaded;
+            info.dep_load_state = DependencyLoadState::NotLoaded;
+            info.rec_dep_load_state = RecursiveDependencyLoadState::NotLoaded;
+            used_bytes -= budget.sizes[&id];
+            evicted.push(id);
+        }
+        drop(infos);
+
+        for id in evicted {
+            budget.forget(id);
+            budget.evicted.insert(id);
+            self.remove(id);
+        }
+    }
+
+    /// A run condition for [`evict_over_budget`]. The system will not run if there is no memory budget.
+    ///
+    /// [`evict_over_budget`]: Self::evict_over_budget
+    pub(crate) fn memory_budget_condition(assets: Res<Self>) -> bool {
+        assets.memory_budget.is_some()
+    }
+
     /// A system that applies accumulated asset change events to the [`Events`] resource.
     ///
     /// [`Events`]: bevy_ecs::event::Events
@@ -0,0 +1,309 @@
+use crate::{
+    io::Reader, Asset, AssetLoader, AssetPath, AssetServer, Handle, LoadContext,
+    LoadedUntypedAsset, ParseAssetPathError, RecursiveDependencyLoadState, UntypedAssetId,
+    VisitAssetDependencies,
+};
+use alloc::collections::BTreeMap;
+use bevy_ecs::{
+    system::{Commands, Res, ResMut, Resource},
+    world::World,
+};
+use bevy_reflect::TypePath;
+use bevy_utils::tracing::error;
+use derive_more::derive::{Display, Error, From};
+use serde::Deserialize;
+
+pub use bevy_asset_macros::AssetCollection;
+
+/// A group of assets that are loaded together, such as the assets needed by a level or a loading screen.
+///
+/// An asset collection is usually derived on a struct of [`Handle`] fields, with the path of each asset in an
+/// `#[asset(path = "...")]` attribute. `#[asset(folder = "...")]` loads a [`Handle<LoadedFolder>`] with
+/// [`AssetServer::load_folder`] instead, and fields without an attribute are initialized with [`Default`].
+///
+/// Initializing the collection with [`AssetApp::init_asset_collection`] starts loading all of its assets, tracked by
+/// the [`LoadingAssetCollection`] resource. Once every asset and its dependencies are loaded, the collection is
+/// inserted as a resource, which systems can wait for with the
+/// [`resource_exists`](bevy_ecs::schedule::common_conditions::resource_exists) run condition.
+///
+/// ```
+/// # use bevy_asset::{AssetCollection, AssetManifest, Handle, LoadedFolder};
+/// # use bevy_ecs::system::Resource;
+/// # use bevy_reflect::TypePath;
+/// # #[derive(bevy_asset::Asset, TypePath)]
+/// # struct Image;
+/// #[derive(Resource, AssetCollection)]
+/// struct LevelAssets {
+///     #[asset(path = "textures/player.png")]
+///     player: Handle<Image>,
+///     #[asset(folder = "textures/tiles")]
+///     tiles: Handle<LoadedFolder>,
+///     #[asset(path = "levels/forest.assets.ron")]
+///     props: Handle<AssetManifest>,
+/// }
+/// ```
+///
+/// [`Handle<LoadedFolder>`]: crate::LoadedFolder
+/// [`AssetApp::init_asset_collection`]: crate::AssetApp::init_asset_collection
+pub trait AssetCollection: VisitAssetDependencies + Resource + Sized {
+    /// Starts loading the assets of the collection with `asset_server`.
+    fn load(asset_server: &AssetServer) -> Self;
+
+    /// Returns the load state of the whole collection, aggregated from the [`RecursiveDependencyLoadState`] of each
+    /// of its assets.
+    ///
+    /// The collection is [loaded](RecursiveDependencyLoadState::Loaded) once all of its assets are loaded with their
+    /// dependencies, and [failed](RecursiveDependencyLoadState::Failed) as soon as any of them failed to load.
+    fn load_state(&self, asset_server: &AssetServer) -> RecursiveDependencyLoadState {
+        let mut total = 0;
+        let mut loaded = 0;
+        let mut not_loaded = 0;
+        let mut failed = None;
+        self.visit_dependencies(&mut |id| {
+            total += 1;
+            match asset_server.recursive_dependency_load_state(id) {
+                RecursiveDependencyLoadState::NotLoaded => not_loaded += 1,
+                RecursiveDependencyLoadState::Loading => {}
+                RecursiveDependencyLoadState::Loaded => loaded += 1,
+                RecursiveDependencyLoadState::Failed(error) => {
+                    failed.get_or_insert(error);
+                }
+            }
+        });
+        if let Some(error) = failed {
+            RecursiveDependencyLoadState::Failed(error)
+        } else if loaded == total {
+            RecursiveDependencyLoadState::Loaded
+        } else if not_loaded == total {
+            RecursiveDependencyLoadState::NotLoaded
+        } else {
+            RecursiveDependencyLoadState::Loading
+        }
+    }
+
+    /// Returns how many assets of the collection are loaded with their dependencies, to display the progress of a
+    /// loading screen.
+    fn progress(&self, asset_server: &AssetServer) -> AssetCollectionProgress {
+        let mut progress = AssetCollectionProgress::default();
+        self.visit_dependencies(&mut |id| {
+            progress.total += 1;
+            if asset_server.recursive_dependency_load_state(id).is_loaded() {
+                progress.loaded += 1;
+            }
+        });
+        progress
+    }
+}
+
+/// The number of assets of an [`AssetCollection`] loaded with their dependencies, returned by
+/// [`AssetCollection::progress`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct AssetCollectionProgress {
+    /// The number of assets loaded with their dependencies.
+    pub loaded: usize,
+    /// The number of assets in the collection.
+    pub total: usize,
+}
+
+impl AssetCollectionProgress {
+    /// Returns `true` if every asset of the collection is loaded with its dependencies.
+    pub fn is_done(&self) -> bool {
+        self.loaded == self.total
+    }
+
+    /// Returns the fraction of the assets of the collection that are loaded, between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+}
+
+/// An [`AssetCollection`] that is still loading, inserted by
+/// [`AssetApp::init_asset_collection`](crate::AssetApp::init_asset_collection).
+///
+/// This resource is replaced by the collection itself once all of its assets are loaded. If any of them fails to
+/// load, the error is logged and this resource is kept, with a [failed](RecursiveDependencyLoadState::Failed)
+/// [`LoadingAssetCollection::load_state`].
+#[derive(Resource)]
+pub struct LoadingAssetCollection<T: AssetCollection> {
+    collection: T,
+    failed: bool,
+}
+
+impl<T: AssetCollection> LoadingAssetCollection<T> {
+    /// Starts loading the collection `T` with `asset_server`.
+    pub fn new(asset_server: &AssetServer) -> Self {
+        Self {
+            collection: T::load(asset_server),
+            failed: false,
+        }
+    }
+
+    /// Returns the collection being loaded.
+    pub fn collection(&self) -> &T {
+        &self.collection
+    }
+
+    /// Returns the aggregated load state of the collection. See [`AssetCollection::load_state`].
+    pub fn load_state(&self, asset_server: &AssetServer) -> RecursiveDependencyLoadState {
+        self.collection.load_state(asset_server)
+    }
+
+    /// Returns how many assets of the collection are loaded. See [`AssetCollection::progress`].
+    pub fn progress(&self, asset_server: &AssetServer) -> AssetCollectionProgress {
+        self.collection.progress(asset_server)
+    }
+
+    /// Replaces the [`LoadingAssetCollection<T>`] resource with the collection `T` once it is loaded.
+    pub(crate) fn insert_when_loaded(
+        mut commands: Commands,
+        mut loading: ResMut<Self>,
+        asset_server: Res<AssetServer>,
+    ) {
+        match loading.load_state(&asset_server) {
+            RecursiveDependencyLoadState::Loaded => {
+                commands.queue(|world: &mut World| {
+                    if let Some(loading) = world.remove_resource::<Self>() {
+                        world.insert_resource(loading.collection);
+                    }
+                });
+            }
+            RecursiveDependencyLoadState::Failed(error) if !loading.failed => {
+                error!(
+                    "Failed to load asset collection {}: {error}",
+                    core::any::type_name::<T>()
+                );
+                loading.failed = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A list of named assets loaded from a `.assets.ron` file by the [`AssetManifestLoader`], for
+/// [asset collections](AssetCollection) whose content is defined by data rather than code.
+///
+/// The assets are loaded with their type inferred from their path, and the manifest is
+/// [loaded with its dependencies](crate::AssetServer::is_loaded_with_dependencies) once all of them are:
+///
+/// ```ron
+/// (
+///     assets: {
+///         "player": "textures/player.png",
+///         "theme": "audio/theme.ogg",
+///     },
+/// )
+/// ```
+///
+/// Paths are relative to the root of their asset source, like the paths passed to [`AssetServer::load`].
+#[derive(TypePath, Debug)]
+pub struct AssetManifest {
+    assets: BTreeMap<String, (AssetPath<'static>, Handle<LoadedUntypedAsset>)>,
+}
+
+impl Asset for AssetManifest {}
+
+impl VisitAssetDependencies for AssetManifest {
+    fn visit_dependencies(&self, visit: &mut impl FnMut(UntypedAssetId)) {
+        for (_, handle) in self.assets.values() {
+            visit(handle.id().untyped());
+        }
+    }
+}
+
+impl AssetManifest {
+    /// Returns the path of the asset `name`.
+    pub fn path(&self, name: &str) -> Option<&AssetPath<'static>> {
+        self.assets.get(name).map(|(path, _)| path)
+    }
+
+    /// Returns the handle of the asset `name`, once it is loaded as an `A`.
+    pub fn get<A: Asset>(&self, name: &str, asset_server: &AssetServer) -> Option<Handle<A>> {
+        asset_server.get_handle(self.path(name)?)
+    }
+
+    /// Returns the handle of the [`LoadedUntypedAsset`] wrapping the asset `name`, whatever its type.
+    pub fn get_untyped(&self, name: &str) -> Option<&Handle<LoadedUntypedAsset>> {
+        self.assets.get(name).map(|(_, handle)| handle)
+    }
+
+    /// Returns an iterator over the names and paths of the assets, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AssetPath<'static>)> {
+        self.assets
+            .iter()
+            .map(|(name, (path, _))| (name.as_str(), path))
+    }
+
+    /// Returns the number of assets in the manifest.
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Returns `true` if the manifest doesn't list any asset.
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+}
+
+#[derive(Deserialize)]
+struct AssetManifestRon {
+    assets: BTreeMap<String, String>,
+}
+
+/// Loads an [`AssetManifest`] from a `.assets.ron` file.
+#[derive(Default)]
+pub struct AssetManifestLoader;
+
+/// An error that occurs while loading an [`AssetManifest`].
+#[derive(Error, Display, Debug, From)]
+pub enum AssetManifestLoaderError {
+    #[display("An IO error occurred while loading the asset manifest: {_0}")]
+    Io(std::io::Error),
+    #[display("The asset manifest could not be parsed: {_0}")]
+    Ron(ron::error::SpannedError),
+    #[display("The path '{path}' of asset '{name}' is invalid: {error}")]
+    InvalidPath {
+        name: String,
+        path: String,
+        error: ParseAssetPathError,
+    },
+}
+
+impl AssetLoader for AssetManifestLoader {
+    type Asset = AssetManifest;
+    type Settings = ();
+    type Error = AssetManifestLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<AssetManifest, AssetManifestLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let manifest: AssetManifestRon = ron::de::from_bytes(&bytes)?;
+        let mut assets = BTreeMap::new();
+        for (name, path) in manifest.assets {
+            let asset_path = match AssetPath::try_parse(&path) {
+                Ok(asset_path) => asset_path.into_owned(),
+                Err(error) => {
+                    return Err(AssetManifestLoaderError::InvalidPath { name, path, error })
+                }
+            };
+            let handle = load_context
+                .loader()
+                .with_unknown_type()
+                .load(asset_path.clone());
+            assets.insert(name, (asset_path, handle));
+        }
+        Ok(AssetManifest { assets })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["assets.ron"]
+    }
+}
@@ -97,6 +97,14 @@ impl AssetReader for FileAssetReader {
             .map_err(|_e| AssetReaderError::NotFound(path.to_owned()))?;
         Ok(metadata.file_type().is_dir())
     }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        match async_fs::metadata(self.root_path.join(path)).await {
+            Ok(metadata) => Ok(metadata.is_file()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
 }
 
 impl AssetWriter for FileAssetWriter {
@@ -168,6 +168,10 @@ impl AssetReader for FileAssetReader {
             .map_err(|_e| AssetReaderError::NotFound(path.to_owned()))?;
         Ok(metadata.file_type().is_dir())
     }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.root_path.join(path).is_file())
+    }
 }
 
 impl AssetWriter for FileAssetWriter {
@@ -0,0 +1,513 @@
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder,
+    AssetSourceEvent, AssetWatcher, ErasedAssetReader, PathStream, Reader,
+};
+use alloc::sync::Arc;
+use bevy_tasks::block_on;
+use bevy_utils::HashSet;
+use futures_lite::StreamExt;
+use parking_lot::Mutex;
+use std::path::Path;
+
+/// An [`AssetReader`] stacking the readers of several layers, such as the base game, its DLCs
+/// and user mods, so that mods and patches can replace or add assets without changing the base game.
+///
+/// Layers are ordered from the highest priority to the lowest: a path resolves to the first layer
+/// that has it. The `.meta` file of an asset is always read from the layer the asset is read from.
+/// Directories are merged across all the layers.
+///
+/// Use [`AssetSourceBuilder::layered`] to build an asset source from several layers.
+pub struct LayeredAssetReader {
+    layers: Vec<Box<dyn ErasedAssetReader>>,
+}
+
+impl LayeredAssetReader {
+    /// Creates a reader stacking `layers`, ordered from the highest priority to the lowest.
+    pub fn new(layers: impl IntoIterator<Item = Box<dyn ErasedAssetReader>>) -> Self {
+        Self {
+            layers: layers.into_iter().collect(),
+        }
+    }
+
+    /// Adds `layer` below the existing layers, so that it has the lowest priority.
+    pub fn with_layer(mut self, layer: impl AssetReader) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Returns the layers of this reader, ordered from the highest priority to the lowest.
+    pub fn layers(&self) -> &[Box<dyn ErasedAssetReader>] {
+        &self.layers
+    }
+}
+
+impl AssetReader for LayeredAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_path_buf()))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read_meta(path).await {
+                Err(AssetReaderError::NotFound(meta_path)) => {
+                    // Don't use the meta file of an asset overridden by an asset without one
+                    if layer.exists(path).await? {
+                        return Err(AssetReaderError::NotFound(meta_path));
+                    }
+                }
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(get_meta_path(path)))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut seen = HashSet::new();
+        let mut paths = Vec::new();
+        for layer in &self.layers {
+            let mut stream = match layer.read_directory(path).await {
+                Ok(stream) => stream,
+                Err(AssetReaderError::NotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            found = true;
+            while let Some(path) = stream.next().await {
+                if seen.insert(path.clone()) {
+                    paths.push(path);
+                }
+            }
+        }
+        if !found {
+            return Err(AssetReaderError::NotFound(path.to_path_buf()));
+        }
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let mut found = false;
+        for layer in &self.layers {
+            match layer.is_directory(path).await {
+                Ok(true) => return Ok(true),
+                Ok(false) => found = true,
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        if found {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_path_buf()))
+        }
+    }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        for layer in &self.layers {
+            if layer.exists(path).await? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+/// An [`AssetWatcher`] keeping the watchers of every layer of a layered asset source alive.
+struct LayeredWatcher {
+    _watchers: Vec<Box<dyn AssetWatcher>>,
+}
+
+impl AssetWatcher for LayeredWatcher {}
+
+type ReaderBuilder = Box<dyn FnMut() -> Box<dyn ErasedAssetReader> + Send + Sync>;
+
+type WatcherBuilder = Box<
+    dyn FnMut(crossbeam_channel::Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>>
+        + Send
+        + Sync,
+>;
+
+/// Builds a watcher forwarding the events of the watchers of every layer, once resolved against
+/// the readers of all the layers with [`resolve_event`].
+///
+/// `readers` and `watchers` hold the reader and watcher of each layer, ordered from the highest
+/// priority to the lowest.
+fn layered_watcher(
+    readers: Arc<Mutex<Vec<Option<ReaderBuilder>>>>,
+    mut watchers: Vec<Option<WatcherBuilder>>,
+) -> impl FnMut(crossbeam_channel::Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>> + Send + Sync
+{
+    move |sender| {
+        let mut layer_watchers = Vec::new();
+        let mut receivers = Vec::new();
+        for (layer, watcher) in watchers.iter_mut().enumerate() {
+            let Some(watcher) = watcher else {
+                continue;
+            };
+            let (layer_sender, receiver) = crossbeam_channel::unbounded();
+            if let Some(watcher) = watcher(layer_sender) {
+                layer_watchers.push(watcher);
+                receivers.push((layer, receiver));
+            }
+        }
+        if layer_watchers.is_empty() {
+            return None;
+        }
+        let layers = readers
+            .lock()
+            .iter_mut()
+            .map(|reader| reader.as_mut().map(|reader| reader()))
+            .collect::<Vec<_>>();
+        std::thread::spawn(move || forward_events(&layers, &receivers, &sender));
+        Some(Box::new(LayeredWatcher {
+            _watchers: layer_watchers,
+        }))
+    }
+}
+
+/// Forwards the events received from the watchers of the layers to `sender`, until every layer
+/// watcher or the receiver of `sender` is dropped.
+fn forward_events(
+    layers: &[Option<Box<dyn ErasedAssetReader>>],
+    receivers: &[(usize, crossbeam_channel::Receiver<AssetSourceEvent>)],
+    sender: &crossbeam_channel::Sender<AssetSourceEvent>,
+) {
+    let mut select = crossbeam_channel::Select::new();
+    for (_, receiver) in receivers {
+        select.recv(receiver);
+    }
+    let mut open = receivers.len();
+    while open > 0 {
+        let operation = select.select();
+        let index = operation.index();
+        let (layer, receiver) = &receivers[index];
+        match operation.recv(receiver) {
+            Ok(event) => {
+                for event in block_on(resolve_event(layers, *layer, event)) {
+                    if sender.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(_) => {
+                select.remove(index);
+                open -= 1;
+            }
+        }
+    }
+}
+
+/// Returns `true` if any of `layers` has an asset at `path`.
+async fn has_asset(layers: &[Option<Box<dyn ErasedAssetReader>>], path: &Path) -> bool {
+    for layer in layers.iter().flatten() {
+        if let Ok(true) = layer.exists(path).await {
+            return true;
+        }
+    }
+    false
+}
+
+/// Resolves an `event` of the watcher of the layer at index `layer` against the whole stack of
+/// `layers`, returning the events seen by the layered source.
+///
+/// Events for paths hidden by a higher priority layer are dropped, and the removal of an asset
+/// still provided by a lower priority layer becomes a modification.
+async fn resolve_event(
+    layers: &[Option<Box<dyn ErasedAssetReader>>],
+    layer: usize,
+    event: AssetSourceEvent,
+) -> Vec<AssetSourceEvent> {
+    let (higher, lower) = (&layers[..layer], &layers[layer + 1..]);
+    match event {
+        AssetSourceEvent::AddedAsset(ref path)
+        | AssetSourceEvent::ModifiedAsset(ref path)
+        | AssetSourceEvent::AddedMeta(ref path)
+        | AssetSourceEvent::ModifiedMeta(ref path)
+        | AssetSourceEvent::RemovedMeta(ref path)
+        | AssetSourceEvent::RemovedUnknown {
+            ref path,
+            is_meta: true,
+        } => {
+            if has_asset(higher, path).await {
+                Vec::new()
+            } else {
+                vec![event]
+            }
+        }
+        AssetSourceEvent::RemovedAsset(ref path)
+        | AssetSourceEvent::RemovedUnknown {
+            ref path,
+            is_meta: false,
+        } => {
+            if has_asset(higher, path).await {
+                Vec::new()
+            } else if has_asset(lower, path).await {
+                vec![AssetSourceEvent::ModifiedAsset(path.clone())]
+            } else {
+                vec![event]
+            }
+        }
+        AssetSourceEvent::RenamedAsset { old, new } => {
+            let old_hidden = has_asset(higher, &old).await;
+            let new_hidden = has_asset(higher, &new).await;
+            let old_provided = has_asset(lower, &old).await;
+            if !old_hidden && !new_hidden && !old_provided {
+                return vec![AssetSourceEvent::RenamedAsset { old, new }];
+            }
+            let mut events = Vec::new();
+            if !old_hidden {
+                events.push(if old_provided {
+                    AssetSourceEvent::ModifiedAsset(old)
+                } else {
+                    AssetSourceEvent::RemovedAsset(old)
+                });
+            }
+            if !new_hidden {
+                events.push(AssetSourceEvent::AddedAsset(new));
+            }
+            events
+        }
+        AssetSourceEvent::RenamedMeta { old, new } => {
+            let old_hidden = has_asset(higher, &old).await;
+            let new_hidden = has_asset(higher, &new).await;
+            if !old_hidden && !new_hidden {
+                return vec![AssetSourceEvent::RenamedMeta { old, new }];
+            }
+            let mut events = Vec::new();
+            if !old_hidden {
+                events.push(AssetSourceEvent::RemovedMeta(old));
+            }
+            if !new_hidden {
+                events.push(AssetSourceEvent::AddedMeta(new));
+            }
+            events
+        }
+        // Directories are merged across the layers, so folder events are never hidden.
+        AssetSourceEvent::AddedFolder(_)
+        | AssetSourceEvent::RemovedFolder(_)
+        | AssetSourceEvent::RenamedFolder { .. } => vec![event],
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for an asset source stacking the sources built by `layers`, ordered from
+    /// the highest priority to the lowest. See [`LayeredAssetReader`].
+    ///
+    /// The unprocessed and processed readers and watchers of every layer are stacked, so that
+    /// changes to any layer are watched. Changes are resolved against the whole stack: changes
+    /// to an asset hidden by a higher priority layer are ignored, and removing an asset still provided
+    /// by a lower priority layer modifies it. Writers aren't stacked: the source writes to the
+    /// writers of the highest priority layer that has one.
+    ///
+    /// ```no_run
+    /// # use bevy_app::App;
+    /// # use bevy_asset::{AssetApp, io::AssetSourceBuilder};
+    /// # let mut app = App::new();
+    /// app.register_asset_source(
+    ///     "game",
+    ///     AssetSourceBuilder::layered([
+    ///         AssetSourceBuilder::platform_default("mods", None),
+    ///         AssetSourceBuilder::platform_default("dlc", None),
+    ///         AssetSourceBuilder::platform_default("assets", None),
+    ///     ]),
+    /// );
+    /// ```
+    pub fn layered(layers: impl IntoIterator<Item = AssetSourceBuilder>) -> Self {
+        let mut readers = Vec::new();
+        let mut processed_readers = Vec::new();
+        let mut watchers = Vec::new();
+        let mut processed_watchers = Vec::new();
+        let mut builder = AssetSource::build();
+        for layer in layers {
+            readers.push(layer.reader);
+            processed_readers.push(layer.processed_reader);
+            watchers.push(layer.watcher);
+            processed_watchers.push(layer.processed_watcher);
+            builder.writer = builder.writer.or(layer.writer);
+            builder.processed_writer = builder.processed_writer.or(layer.processed_writer);
+            builder.watch_warning = builder.watch_warning.or(layer.watch_warning);
+            builder.processed_watch_warning = builder
+                .processed_watch_warning
+                .or(layer.processed_watch_warning);
+        }
+        let has_readers = readers.iter().any(Option::is_some);
+        let has_processed_readers = processed_readers.iter().any(Option::is_some);
+        let has_watchers = watchers.iter().any(Option::is_some);
+        let has_processed_watchers = processed_watchers.iter().any(Option::is_some);
+        // The readers are shared with the watchers, which resolve events against every layer.
+        let readers = Arc::new(Mutex::new(readers));
+        let processed_readers = Arc::new(Mutex::new(processed_readers));
+        if has_readers {
+            let readers = readers.clone();
+            builder = builder.with_reader(move || {
+                Box::new(LayeredAssetReader::new(
+                    readers.lock().iter_mut().flatten().map(|reader| reader()),
+                ))
+            });
+        }
+        if has_processed_readers {
+            let processed_readers = processed_readers.clone();
+            builder = builder.with_processed_reader(move || {
+                Box::new(LayeredAssetReader::new(
+                    processed_readers
+                        .lock()
+                        .iter_mut()
+                        .flatten()
+                        .map(|reader| reader()),
+                ))
+            });
+        }
+        if has_watchers {
+            builder = builder.with_watcher(layered_watcher(readers, watchers));
+        }
+        if has_processed_watchers {
+            builder = builder
+                .with_processed_watcher(layered_watcher(processed_readers, processed_watchers));
+        }
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_event, LayeredAssetReader};
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        AssetReader, AssetReaderError, AssetSourceEvent, Reader,
+    };
+    use bevy_tasks::block_on;
+    use futures_lite::StreamExt;
+    use std::path::{Path, PathBuf};
+
+    fn reader() -> LayeredAssetReader {
+        let base = Dir::default();
+        base.insert_asset_text(Path::new("a.txt"), "base a");
+        base.insert_meta_text(Path::new("a.txt"), "base a meta");
+        base.insert_asset_text(Path::new("b.txt"), "base b");
+        base.insert_meta_text(Path::new("b.txt"), "base b meta");
+        base.insert_asset_text(Path::new("x/c.txt"), "base c");
+
+        let patch = Dir::default();
+        patch.insert_asset_text(Path::new("a.txt"), "patch a");
+        patch.insert_asset_text(Path::new("x/d.txt"), "patch d");
+
+        LayeredAssetReader::new([])
+            .with_layer(MemoryAssetReader { root: patch })
+            .with_layer(MemoryAssetReader { root: base })
+    }
+
+    fn read(reader: &LayeredAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            reader
+                .read(Path::new(path))
+                .await?
+                .read_to_end(&mut bytes)
+                .await?;
+            Ok(String::from_utf8(bytes).unwrap())
+        })
+    }
+
+    fn read_meta(reader: &LayeredAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(reader.read_meta_bytes(Path::new(path)))
+            .map(|bytes| String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn highest_layer_wins() {
+        let reader = reader();
+
+        assert_eq!(read(&reader, "a.txt").unwrap(), "patch a");
+        assert_eq!(read(&reader, "b.txt").unwrap(), "base b");
+        assert_eq!(read(&reader, "x/d.txt").unwrap(), "patch d");
+        assert!(read(&reader, "e.txt").is_err());
+
+        // The overriding asset has no meta file, the meta file of the base asset must not be used.
+        assert!(matches!(
+            read_meta(&reader, "a.txt"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        assert_eq!(read_meta(&reader, "b.txt").unwrap(), "base b meta");
+    }
+
+    #[test]
+    fn directories_are_merged() {
+        let reader = reader();
+        let mut paths = block_on(async {
+            reader
+                .read_directory(Path::new("x"))
+                .await
+                .unwrap()
+                .collect::<Vec<_>>()
+                .await
+        });
+        paths.sort();
+
+        assert_eq!(paths, [PathBuf::from("x/c.txt"), PathBuf::from("x/d.txt")]);
+        assert_eq!(block_on(reader.is_directory(Path::new("x"))), Ok(true));
+        assert_eq!(block_on(reader.is_directory(Path::new("a.txt"))), Ok(false));
+    }
+
+    #[test]
+    fn watched_events_are_resolved_against_all_layers() {
+        let reader = reader();
+        let layers = reader.layers.into_iter().map(Some).collect::<Vec<_>>();
+        let resolve = |layer, event| block_on(resolve_event(&layers, layer, event));
+        let path = PathBuf::from;
+
+        // "a.txt" is hidden by the patch layer.
+        assert_eq!(
+            resolve(1, AssetSourceEvent::ModifiedAsset(path("a.txt"))),
+            []
+        );
+        assert_eq!(resolve(1, AssetSourceEvent::RemovedMeta(path("a.txt"))), []);
+        assert_eq!(
+            resolve(1, AssetSourceEvent::ModifiedAsset(path("b.txt"))),
+            [AssetSourceEvent::ModifiedAsset(path("b.txt"))]
+        );
+        // Removing "a.txt" from the patch layer reveals the base asset.
+        assert_eq!(
+            resolve(0, AssetSourceEvent::RemovedAsset(path("a.txt"))),
+            [AssetSourceEvent::ModifiedAsset(path("a.txt"))]
+        );
+        assert_eq!(
+            resolve(0, AssetSourceEvent::RemovedAsset(path("x/d.txt"))),
+            [AssetSourceEvent::RemovedAsset(path("x/d.txt"))]
+        );
+        assert_eq!(
+            resolve(
+                0,
+                AssetSourceEvent::RenamedAsset {
+                    old: path("a.txt"),
+                    new: path("e.txt"),
+                }
+            ),
+            [
+                AssetSourceEvent::ModifiedAsset(path("a.txt")),
+                AssetSourceEvent::AddedAsset(path("e.txt")),
+            ]
+        );
+        assert_eq!(
+            resolve(
+                0,
+                AssetSourceEvent::RenamedAsset {
+                    old: path("x/d.txt"),
+                    new: path("e.txt"),
+                }
+            ),
+            [AssetSourceEvent::RenamedAsset {
+                old: path("x/d.txt"),
+                new: path("e.txt"),
+            }]
+        );
+    }
+}
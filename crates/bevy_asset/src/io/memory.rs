@@ -324,6 +324,10 @@ impl AssetReader for MemoryAssetReader {
     async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
         Ok(self.root.get_dir(path).is_some())
     }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.root.get_asset(path).is_some())
+    }
 }
 
 #[cfg(test)]
@@ -11,7 +11,10 @@ pub mod embedded;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod file;
 pub mod gated;
+pub mod layered;
 pub mod memory;
+#[cfg(feature = "pak")]
+pub mod pak;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -256,6 +259,20 @@ pub trait AssetReader: Send + Sync + 'static {
             Ok(meta_bytes)
         }
     }
+    /// Returns true if an asset exists at the provided path. This opens the asset with
+    /// [`AssetReader::read`] by default, readers that can check it more cheaply should override it.
+    fn exists<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> impl ConditionalSendFuture<Output = Result<bool, AssetReaderError>> {
+        async {
+            match self.read(path).await {
+                Ok(_) => Ok(true),
+                Err(AssetReaderError::NotFound(_)) => Ok(false),
+                Err(err) => Err(err),
+            }
+        }
+    }
 }
 
 /// Equivalent to an [`AssetReader`] but using boxed futures, necessary eg. when using a `dyn AssetReader`,
@@ -287,6 +304,8 @@ pub trait ErasedAssetReader: Send + Sync + 'static {
         &'a self,
         path: &'a Path,
     ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>>;
+    /// Returns true if an asset exists at the provided path.
+    fn exists<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<bool, AssetReaderError>>;
 }
 
 impl<T: AssetReader> ErasedAssetReader for T {
@@ -326,6 +345,9 @@ impl<T: AssetReader> ErasedAssetReader for T {
     ) -> BoxedFuture<'a, Result<Vec<u8>, AssetReaderError>> {
         Box::pin(Self::read_meta_bytes(self, path))
     }
+    fn exists<'a>(&'a self, path: &'a Path) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        Box::pin(Self::exists(self, path))
+    }
 }
 
 pub type Writer = dyn AsyncWrite + Unpin + Send + Sync;
@@ -0,0 +1,839 @@
+//! An [`AssetReader`] serving assets out of a single "pak" archive file.
+//!
+//! A pak archive starts with an index listing every asset and `.meta` file it contains, followed
+//! by the (optionally compressed) bytes of each entry. Archives are built with a [`PakBuilder`],
+//! usually from the `processed` directory of the [`AssetProcessor`](crate::processor::AssetProcessor)
+//! or from the `assets` directory, so that release builds can ship all of their assets as one file:
+//!
+//! ```no_run
+//! # use bevy_app::App;
+//! # use bevy_asset::{AssetApp, io::{AssetSourceBuilder, pak::{PakArchive, PakBuilder, PakCompression}}};
+//! // At build time.
+//! PakBuilder::from_directory("assets", PakCompression::Deflate)
+//!     .unwrap()
+//!     .write_to_file("assets.pak")
+//!     .unwrap();
+//!
+//! // In the game, before adding the `AssetPlugin`.
+//! # let mut app = App::new();
+//! let archive = PakArchive::open("assets.pak").unwrap();
+//! app.register_asset_source("pak", AssetSourceBuilder::pak(archive));
+//! ```
+
+use crate::{
+    io::{
+        get_meta_path, AssetReader, AssetReaderError, AssetSource, AssetSourceBuilder, PathStream,
+        Reader, VecReader,
+    },
+    meta::AssetHash,
+};
+use alloc::{collections::BTreeMap, sync::Arc};
+use bevy_utils::HashMap;
+use derive_more::derive::{Display, Error, From};
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+};
+
+/// The bytes every pak archive starts with.
+pub const PAK_MAGIC: [u8; 8] = *b"BEVYPAK\0";
+
+/// The version of the pak format written by [`PakBuilder`].
+pub const PAK_VERSION: u32 = 1;
+
+/// The size of the header preceding the index: magic, version, entry count and index size.
+const HEADER_SIZE: u64 = 8 + 4 + 4 + 8;
+
+/// The maximum number of bytes allocated up front to decompress an entry, so that a corrupt
+/// decompressed size can't trigger a huge allocation.
+const MAX_DECOMPRESSION_PREALLOCATION: u64 = 16 * 1024 * 1024;
+
+/// How the bytes of an entry are stored in a pak archive.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PakCompression {
+    /// The bytes are stored as they are.
+    #[default]
+    None,
+    /// The bytes are compressed with DEFLATE.
+    Deflate,
+}
+
+impl PakCompression {
+    fn from_byte(byte: u8) -> Result<Self, PakError> {
+        match byte {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Deflate),
+            _ => Err(PakError::UnsupportedCompression(byte)),
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Deflate => 1,
+        }
+    }
+
+    fn compress(self, bytes: &[u8]) -> Vec<u8> {
+        match self {
+            Self::None => bytes.to_vec(),
+            Self::Deflate => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+                // Writing to a `Vec` can't fail.
+                encoder.write_all(bytes).unwrap();
+                encoder.finish().unwrap()
+            }
+        }
+    }
+
+    fn decompress(self, stored: Vec<u8>, len: u64) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(stored),
+            Self::Deflate => {
+                let mut bytes =
+                    Vec::with_capacity(len.min(MAX_DECOMPRESSION_PREALLOCATION) as usize);
+                flate2::read::DeflateDecoder::new(stored.as_slice())
+                    .take(len)
+                    .read_to_end(&mut bytes)?;
+                Ok(bytes)
+            }
+        }
+    }
+}
+
+/// Errors that occur while opening a pak archive.
+#[derive(Error, Display, Debug, From)]
+pub enum PakError {
+    /// The archive doesn't start with [`PAK_MAGIC`].
+    #[display("The file is not a pak archive")]
+    InvalidMagic,
+    /// The archive was written with a version of the format this reader doesn't support.
+    #[display("Unsupported pak archive version {_0}")]
+    #[error(ignore)]
+    UnsupportedVersion(u32),
+    /// An entry of the archive uses an unknown compression method.
+    #[display("Unsupported pak entry compression {_0}")]
+    #[error(ignore)]
+    UnsupportedCompression(u8),
+    /// The index of the archive is truncated or malformed, or refers to bytes past the end
+    /// of the archive.
+    #[display("The index of the pak archive is malformed")]
+    InvalidIndex,
+    /// Encountered an I/O error while reading the archive.
+    #[display("Encountered an I/O error while reading the pak archive: {_0}")]
+    #[from]
+    Io(std::io::Error),
+}
+
+/// The location and description of an asset or `.meta` file in a pak archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PakEntry {
+    /// How the bytes of the entry are stored.
+    pub compression: PakCompression,
+    /// The offset of the stored bytes, relative to the end of the index.
+    pub offset: u64,
+    /// The size of the stored bytes.
+    pub stored_len: u64,
+    /// The size of the bytes once decompressed.
+    pub len: u64,
+    /// A hash identifying the content of the entry: the [`blake3`] hash of its decompressed bytes,
+    /// or the [`ProcessedInfo::full_hash`](crate::meta::ProcessedInfo::full_hash) of processed
+    /// assets bundled by the [`AssetProcessor`](crate::processor::AssetProcessor).
+    pub hash: AssetHash,
+}
+
+/// Whether an entry holds the bytes of an asset or of its `.meta` file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum EntryKind {
+    Asset,
+    Meta,
+}
+
+/// Where the bytes of a [`PakArchive`] are read from.
+#[derive(Debug)]
+enum PakData {
+    Bytes(Arc<[u8]>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+struct PakArchiveInternal {
+    assets: HashMap<PathBuf, PakEntry>,
+    metas: HashMap<PathBuf, PakEntry>,
+    dirs: HashMap<PathBuf, Vec<PathBuf>>,
+    data_start: u64,
+    data: PakData,
+}
+
+/// The index of a pak archive, along with the source of its bytes.
+///
+/// This is cheap to clone. Only the index is kept in memory for archives
+/// [opened](Self::open) from a file: entries are read from the file when they are loaded.
+#[derive(Debug, Clone)]
+pub struct PakArchive(Arc<PakArchiveInternal>);
+
+impl PakArchive {
+    /// Reads the pak archive held in `bytes`, for example included in the executable
+    /// with [`include_bytes`].
+    pub fn from_bytes(bytes: impl Into<Arc<[u8]>>) -> Result<Self, PakError> {
+        let bytes = bytes.into();
+        let mut reader = bytes.as_ref();
+        let (entry_count, index_len) = read_header(&mut reader)?;
+        let index = reader
+            .get(..usize::try_from(index_len).map_err(|_| PakError::InvalidIndex)?)
+            .ok_or(PakError::InvalidIndex)?;
+        let data_len = bytes.len() as u64 - HEADER_SIZE - index_len;
+        Self::new(
+            index,
+            entry_count,
+            index_len,
+            data_len,
+            PakData::Bytes(bytes.clone()),
+        )
+    }
+
+    /// Opens the pak archive at `path`, relative to the
+    /// [base path](crate::io::file::FileAssetReader::get_base_path) of the assets.
+    ///
+    /// Only the index is read, entries are read from the file as they are loaded.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PakError> {
+        let path = crate::io::file::FileAssetReader::get_base_path().join(path);
+        let mut file = std::fs::File::open(&path)?;
+        let (entry_count, index_len) = read_header(&mut file)?;
+        let mut index = Vec::new();
+        (&mut file).take(index_len).read_to_end(&mut index)?;
+        if index.len() as u64 != index_len {
+            return Err(PakError::InvalidIndex);
+        }
+        let data_len = file.metadata()?.len() - HEADER_SIZE - index_len;
+        Self::new(
+            &index,
+            entry_count,
+            index_len,
+            data_len,
+            PakData::File(path),
+        )
+    }
+
+    fn new(
+        mut index: &[u8],
+        entry_count: u32,
+        index_len: u64,
+        data_len: u64,
+        data: PakData,
+    ) -> Result<Self, PakError> {
+        let mut assets = HashMap::default();
+        let mut metas = HashMap::default();
+        let mut dirs: HashMap<PathBuf, Vec<PathBuf>> = HashMap::default();
+        dirs.insert(PathBuf::new(), Vec::new());
+        for _ in 0..entry_count {
+            let kind = match read_u8(&mut index)? {
+                0 => EntryKind::Asset,
+                1 => EntryKind::Meta,
+                _ => return Err(PakError::InvalidIndex),
+            };
+            let compression = PakCompression::from_byte(read_u8(&mut index)?)?;
+            let path_len = u16::from_le_bytes(read_array(&mut index)?);
+            let path = index
+                .get(..path_len as usize)
+                .and_then(|path| core::str::from_utf8(path).ok())
+                .map(PathBuf::from)
+                .ok_or(PakError::InvalidIndex)?;
+            index = &index[path_len as usize..];
+            let entry = PakEntry {
+                compression,
+                offset: u64::from_le_bytes(read_array(&mut index)?),
+                stored_len: u64::from_le_bytes(read_array(&mut index)?),
+                len: u64::from_le_bytes(read_array(&mut index)?),
+                hash: read_array(&mut index)?,
+            };
+            let in_bounds = entry
+                .offset
+                .checked_add(entry.stored_len)
+                .is_some_and(|end| end <= data_len);
+            let stored_as_is =
+                entry.compression != PakCompression::None || entry.stored_len == entry.len;
+            if !in_bounds || !stored_as_is {
+                return Err(PakError::InvalidIndex);
+            }
+            match kind {
+                EntryKind::Asset => {
+                    insert_into_dirs(&mut dirs, &path);
+                    assets.insert(path, entry);
+                }
+                EntryKind::Meta => {
+                    metas.insert(path, entry);
+                }
+            }
+        }
+        for children in dirs.values_mut() {
+            children.sort();
+        }
+        Ok(Self(Arc::new(PakArchiveInternal {
+            assets,
+            metas,
+            dirs,
+            data_start: HEADER_SIZE + index_len,
+            data,
+        })))
+    }
+
+    /// Returns the entry of the asset at `path`, if it is in the archive.
+    pub fn asset_entry(&self, path: &Path) -> Option<&PakEntry> {
+        self.0.assets.get(path)
+    }
+
+    /// Returns the entry of the `.meta` file of the asset at `path`, if it is in the archive.
+    pub fn meta_entry(&self, path: &Path) -> Option<&PakEntry> {
+        self.0.metas.get(path)
+    }
+
+    /// Iterates over the paths of the assets in the archive, in no particular order.
+    pub fn asset_paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.assets.keys().map(PathBuf::as_path)
+    }
+
+    /// Returns `true` if `path` is a directory of the archive.
+    pub fn is_directory(&self, path: &Path) -> bool {
+        self.0.dirs.contains_key(path)
+    }
+
+    /// Reads and decompresses the bytes of `entry`, checking them against its size.
+    async fn read_entry(&self, entry: &PakEntry) -> std::io::Result<Vec<u8>> {
+        let start = self.0.data_start + entry.offset;
+        let stored = match &self.0.data {
+            PakData::Bytes(bytes) => usize::try_from(start)
+                .ok()
+                .zip(usize::try_from(start + entry.stored_len).ok())
+                .and_then(|(start, end)| bytes.get(start..end))
+                .map(<[u8]>::to_vec)
+                .ok_or_else(|| invalid_data("pak entry is out of bounds"))?,
+            #[cfg(not(target_arch = "wasm32"))]
+            PakData::File(path) => {
+                use futures_lite::{AsyncReadExt, AsyncSeekExt};
+
+                let mut file = async_fs::File::open(path).await?;
+                file.seek(std::io::SeekFrom::Start(start)).await?;
+                let mut stored = vec![0; entry.stored_len as usize];
+                file.read_exact(&mut stored).await?;
+                stored
+            }
+        };
+        let bytes = entry.compression.decompress(stored, entry.len)?;
+        if bytes.len() as u64 != entry.len {
+            return Err(invalid_data("pak entry doesn't match its size"));
+        }
+        Ok(bytes)
+    }
+}
+
+fn invalid_data(message: &'static str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message)
+}
+
+fn invalid_input(message: &'static str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message)
+}
+
+fn read_header(reader: &mut impl Read) -> Result<(u32, u64), PakError> {
+    let mut header = [0; HEADER_SIZE as usize];
+    reader.read_exact(&mut header).map_err(|error| {
+        if error.kind() == std::io::ErrorKind::UnexpectedEof {
+            PakError::InvalidMagic
+        } else {
+            error.into()
+        }
+    })?;
+    if header[..8] != PAK_MAGIC {
+        return Err(PakError::InvalidMagic);
+    }
+    let version = u32::from_le_bytes(header[8..12].try_into().unwrap());
+    if version != PAK_VERSION {
+        return Err(PakError::UnsupportedVersion(version));
+    }
+    let entry_count = u32::from_le_bytes(header[12..16].try_into().unwrap());
+    let index_len = u64::from_le_bytes(header[16..24].try_into().unwrap());
+    Ok((entry_count, index_len))
+}
+
+fn read_u8(index: &mut &[u8]) -> Result<u8, PakError> {
+    read_array::<1>(index).map(|[byte]| byte)
+}
+
+fn read_array<const N: usize>(index: &mut &[u8]) -> Result<[u8; N], PakError> {
+    let (bytes, rest) = index.split_first_chunk().ok_or(PakError::InvalidIndex)?;
+    *index = rest;
+    Ok(*bytes)
+}
+
+/// Adds `path` to the children of its parent directory, adding the missing ancestors as well.
+fn insert_into_dirs(dirs: &mut HashMap<PathBuf, Vec<PathBuf>>, path: &Path) {
+    let mut child = path.to_path_buf();
+    while let Some(parent) = child.parent() {
+        let parent = parent.to_path_buf();
+        let known = dirs.contains_key(&parent);
+        let children = dirs.entry(parent.clone()).or_default();
+        if !children.contains(&child) {
+            children.push(child);
+        }
+        if known {
+            break;
+        }
+        child = parent;
+    }
+}
+
+/// An [`AssetReader`] serving the assets of a [`PakArchive`].
+#[derive(Debug, Clone)]
+pub struct PakAssetReader {
+    archive: PakArchive,
+}
+
+impl PakAssetReader {
+    /// Creates a reader serving the assets of `archive`.
+    pub fn new(archive: PakArchive) -> Self {
+        Self { archive }
+    }
+
+    /// Returns the archive this reader serves assets from.
+    pub fn archive(&self) -> &PakArchive {
+        &self.archive
+    }
+}
+
+impl AssetReader for PakAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let entry = self
+            .archive
+            .asset_entry(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        Ok(VecReader::new(self.archive.read_entry(entry).await?))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let entry = self
+            .archive
+            .meta_entry(path)
+            .ok_or_else(|| AssetReaderError::NotFound(get_meta_path(path)))?;
+        Ok(VecReader::new(self.archive.read_entry(entry).await?))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let children = self
+            .archive
+            .0
+            .dirs
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+        let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(children.clone()));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        if self.archive.is_directory(path) {
+            Ok(true)
+        } else if self.archive.asset_entry(path).is_some() {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_path_buf()))
+        }
+    }
+
+    async fn exists<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.archive.asset_entry(path).is_some())
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for an asset source serving the assets of `archive`, both as
+    /// unprocessed and as processed assets.
+    pub fn pak(archive: PakArchive) -> Self {
+        let processed_archive = archive.clone();
+        AssetSource::build()
+            .with_reader(move || Box::new(PakAssetReader::new(archive.clone())))
+            .with_processed_reader(move || Box::new(PakAssetReader::new(processed_archive.clone())))
+    }
+}
+
+/// Where and how the [`AssetProcessor`](crate::processor::AssetProcessor) bundles the processed
+/// assets of a source, see [`AssetProcessor::add_pak_output`](crate::processor::AssetProcessor::add_pak_output).
+#[derive(Debug, Clone)]
+pub struct PakOutput {
+    /// The path of the archive, relative to the
+    /// [base path](crate::io::file::FileAssetReader::get_base_path) of the assets.
+    pub path: PathBuf,
+    /// The compression of the `.meta` files and of the assets without a specific compression.
+    pub compression: PakCompression,
+    /// The compression of the assets with a given extension, for example to store assets in an
+    /// already compressed format as they are.
+    pub extension_compression: HashMap<Box<str>, PakCompression>,
+}
+
+impl PakOutput {
+    /// Bundles processed assets into the archive at `path`, compressing them with DEFLATE.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            compression: PakCompression::Deflate,
+            extension_compression: HashMap::default(),
+        }
+    }
+
+    /// Sets the compression of the `.meta` files and of the assets without a specific compression.
+    pub fn with_compression(mut self, compression: PakCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Sets the compression of the assets with the given `extension`.
+    pub fn with_extension_compression(
+        mut self,
+        extension: &str,
+        compression: PakCompression,
+    ) -> Self {
+        self.extension_compression
+            .insert(extension.to_ascii_lowercase().into(), compression);
+        self
+    }
+
+    /// Returns the compression of the asset at `path`.
+    pub fn compression_for(&self, path: &Path) -> PakCompression {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| {
+                self.extension_compression
+                    .get(extension.to_ascii_lowercase().as_str())
+            })
+            .copied()
+            .unwrap_or(self.compression)
+    }
+}
+
+/// Builds a [`PakArchive`] from assets and `.meta` files.
+///
+/// The archive written is deterministic: it only depends on the entries added and
+/// their compression, not on the order they were added in.
+#[derive(Debug, Default)]
+pub struct PakBuilder {
+    entries: BTreeMap<(PathBuf, EntryKind), (PakCompression, Vec<u8>, AssetHash)>,
+}
+
+impl PakBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a builder holding all the assets and `.meta` files found in the directory at `path`,
+    /// relative to the [base path](crate::io::file::FileAssetReader::get_base_path) of the assets.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_directory(
+        path: impl AsRef<Path>,
+        compression: PakCompression,
+    ) -> std::io::Result<Self> {
+        let root = crate::io::file::FileAssetReader::get_base_path().join(path);
+        let mut builder = Self::new();
+        let mut pending = vec![root.clone()];
+        while let Some(dir) = pending.pop() {
+            for entry in std::fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                let bytes = std::fs::read(&path)?;
+                let relative_path = path.strip_prefix(&root).unwrap();
+                if relative_path
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("meta"))
+                {
+                    builder.insert_meta(relative_path.with_extension(""), bytes, compression);
+                } else {
+                    builder.insert_asset(relative_path, bytes, compression);
+                }
+            }
+        }
+        Ok(builder)
+    }
+
+    /// Adds the asset at `path`, replacing the asset previously added at this path.
+    pub fn insert_asset(
+        &mut self,
+        path: impl Into<PathBuf>,
+        bytes: impl Into<Vec<u8>>,
+        compression: PakCompression,
+    ) -> &mut Self {
+        let bytes = bytes.into();
+        let hash = *blake3::hash(&bytes).as_bytes();
+        self.insert_asset_with_hash(path, bytes, compression, hash)
+    }
+
+    /// Adds the asset at `path` with an already known content `hash`, such as the
+    /// [`ProcessedInfo::full_hash`](crate::meta::ProcessedInfo::full_hash) of a processed asset,
+    /// replacing the asset previously added at this path.
+    pub fn insert_asset_with_hash(
+        &mut self,
+        path: impl Into<PathBuf>,
+        bytes: impl Into<Vec<u8>>,
+        compression: PakCompression,
+        hash: AssetHash,
+    ) -> &mut Self {
+        self.entries.insert(
+            (path.into(), EntryKind::Asset),
+            (compression, bytes.into(), hash),
+        );
+        self
+    }
+
+    /// Adds the `.meta` file of the asset at `path`, replacing the `.meta` file previously added
+    /// for this asset.
+    pub fn insert_meta(
+        &mut self,
+        path: impl Into<PathBuf>,
+        bytes: impl Into<Vec<u8>>,
+        compression: PakCompression,
+    ) -> &mut Self {
+        let bytes = bytes.into();
+        let hash = *blake3::hash(&bytes).as_bytes();
+        self.entries
+            .insert((path.into(), EntryKind::Meta), (compression, bytes, hash));
+        self
+    }
+
+    /// Returns `true` if no asset or `.meta` file was added.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the archive to `writer`.
+    ///
+    /// Fails with [`ErrorKind::InvalidInput`](std::io::ErrorKind::InvalidInput) without writing
+    /// anything if a path isn't valid UTF-8 or is longer than [`u16::MAX`] bytes.
+    pub fn write(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let mut index = Vec::new();
+        let mut data = Vec::new();
+        for ((path, kind), (compression, bytes, hash)) in &self.entries {
+            let path = path
+                .to_str()
+                .ok_or_else(|| invalid_input("pak archive paths must be valid UTF-8"))?
+                .replace('\\', "/");
+            let path_len = u16::try_from(path.len())
+                .map_err(|_| invalid_input("pak archive path is too long"))?;
+            let stored = compression.compress(bytes);
+            index.push(match kind {
+                EntryKind::Asset => 0,
+                EntryKind::Meta => 1,
+            });
+            index.push(compression.to_byte());
+            index.extend_from_slice(&path_len.to_le_bytes());
+            index.extend_from_slice(path.as_bytes());
+            index.extend_from_slice(&(data.len() as u64).to_le_bytes());
+            index.extend_from_slice(&(stored.len() as u64).to_le_bytes());
+            index.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+            index.extend_from_slice(hash);
+            data.extend_from_slice(&stored);
+        }
+        let entry_count = u32::try_from(self.entries.len())
+            .map_err(|_| invalid_data("too many entries for a pak archive"))?;
+        writer.write_all(&PAK_MAGIC)?;
+        writer.write_all(&PAK_VERSION.to_le_bytes())?;
+        writer.write_all(&entry_count.to_le_bytes())?;
+        writer.write_all(&(index.len() as u64).to_le_bytes())?;
+        writer.write_all(&index)?;
+        writer.write_all(&data)?;
+        writer.flush()
+    }
+
+    /// Returns the bytes of the archive.
+    ///
+    /// Fails like [`write`](Self::write) on invalid paths.
+    pub fn to_bytes(&self) -> std::io::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Writes the archive to the file at `path`, replacing it if it exists.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        self.write(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::block_on;
+    use futures_lite::StreamExt;
+
+    fn archive() -> PakArchive {
+        let mut builder = PakBuilder::new();
+        builder
+            .insert_asset("a.txt", "a", PakCompression::None)
+            .insert_meta("a.txt", "a meta", PakCompression::None)
+            .insert_asset("x/y/b.txt", "b".repeat(100), PakCompression::Deflate)
+            .insert_asset("x/c.txt", "c", PakCompression::Deflate);
+        PakArchive::from_bytes(builder.to_bytes().unwrap()).unwrap()
+    }
+
+    fn read(reader: &PakAssetReader, path: &str) -> Result<Vec<u8>, AssetReaderError> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            reader
+                .read(Path::new(path))
+                .await?
+                .read_to_end(&mut bytes)
+                .await?;
+            Ok(bytes)
+        })
+    }
+
+    #[test]
+    fn reads_assets_and_meta() {
+        let reader = PakAssetReader::new(archive());
+
+        assert_eq!(read(&reader, "a.txt").unwrap(), b"a");
+        assert_eq!(
+            read(&reader, "x/y/b.txt").unwrap(),
+            "b".repeat(100).as_bytes()
+        );
+        assert_eq!(
+            block_on(reader.read_meta_bytes(Path::new("a.txt"))).unwrap(),
+            b"a meta"
+        );
+        assert!(matches!(
+            read(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+        assert!(reader
+            .archive()
+            .asset_entry(Path::new("x/y/b.txt"))
+            .is_some_and(|entry| entry.stored_len < entry.len));
+    }
+
+    #[test]
+    fn lists_directories() {
+        let reader = PakAssetReader::new(archive());
+        let list = |path: &str| {
+            block_on(async {
+                reader
+                    .read_directory(Path::new(path))
+                    .await
+                    .unwrap()
+                    .collect::<Vec<_>>()
+                    .await
+            })
+        };
+
+        assert_eq!(list(""), [PathBuf::from("a.txt"), PathBuf::from("x")]);
+        assert_eq!(list("x"), [PathBuf::from("x/c.txt"), PathBuf::from("x/y")]);
+        assert_eq!(block_on(reader.is_directory(Path::new("x/y"))), Ok(true));
+        assert_eq!(
+            block_on(reader.is_directory(Path::new("x/c.txt"))),
+            Ok(false)
+        );
+        assert!(block_on(reader.is_directory(Path::new("z"))).is_err());
+    }
+
+    #[test]
+    fn output_is_deterministic() {
+        let mut first = PakBuilder::new();
+        first
+            .insert_asset("b", "b", PakCompression::Deflate)
+            .insert_asset("a", "a", PakCompression::None);
+        let mut second = PakBuilder::new();
+        second
+            .insert_asset("a", "a", PakCompression::None)
+            .insert_asset("b", "b", PakCompression::Deflate);
+
+        assert_eq!(first.to_bytes().unwrap(), second.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn rejects_too_long_paths() {
+        let mut builder = PakBuilder::new();
+        builder.insert_asset("a".repeat(u16::MAX as usize + 1), "a", PakCompression::None);
+
+        let mut bytes = Vec::new();
+        let error = builder.write(&mut bytes).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+        assert!(bytes.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_non_utf8_paths() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let mut builder = PakBuilder::new();
+        builder.insert_asset(OsStr::from_bytes(b"a\xff.txt"), "a", PakCompression::None);
+
+        let error = builder.to_bytes().unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn output_compression_by_extension() {
+        let output =
+            PakOutput::new("assets.pak").with_extension_compression("PNG", PakCompression::None);
+
+        assert_eq!(
+            output.compression_for(Path::new("textures/a.png")),
+            PakCompression::None
+        );
+        assert_eq!(
+            output.compression_for(Path::new("scenes/a.scn.ron")),
+            PakCompression::Deflate
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_archives() {
+        assert!(matches!(
+            PakArchive::from_bytes(b"not a pak archive at all".to_vec()),
+            Err(PakError::InvalidMagic)
+        ));
+        let mut bytes = PakBuilder::new().to_bytes().unwrap();
+        bytes[8] = 2;
+        assert!(matches!(
+            PakArchive::from_bytes(bytes),
+            Err(PakError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[test]
+    fn rejects_corrupt_indices() {
+        let mut builder = PakBuilder::new();
+        builder.insert_asset("a.txt", "abc", PakCompression::None);
+        let bytes = builder.to_bytes().unwrap();
+        // The offset and stored size of the only entry follow its kind, compression and path.
+        let offset = HEADER_SIZE as usize + 1 + 1 + 2 + "a.txt".len();
+        let stored_len = offset + 8;
+        let corrupt = |at: usize, value: u64| {
+            let mut bytes = bytes.clone();
+            bytes[at..at + 8].copy_from_slice(&value.to_le_bytes());
+            PakArchive::from_bytes(bytes)
+        };
+
+        assert!(PakArchive::from_bytes(bytes.clone()).is_ok());
+        assert!(matches!(corrupt(offset, 1), Err(PakError::InvalidIndex)));
+        assert!(matches!(
+            corrupt(offset, u64::MAX),
+            Err(PakError::InvalidIndex)
+        ));
+        assert!(matches!(
+            corrupt(stored_len, 1 << 40),
+            Err(PakError::InvalidIndex)
+        ));
+        assert!(matches!(
+            PakArchive::from_bytes(bytes[..bytes.len() - 1].to_vec()),
+            Err(PakError::InvalidIndex)
+        ));
+    }
+}
@@ -167,6 +167,7 @@ pub mod prelude {
 }
 
 mod assets;
+mod collection;
 mod direct_access_ext;
 mod event;
 mod folder;
@@ -181,6 +182,7 @@ mod server;
 
 pub use assets::*;
 pub use bevy_asset_macros::Asset;
+pub use collection::*;
 pub use direct_access_ext::DirectAssetAccessExt;
 pub use event::*;
 pub use folder::*;
@@ -201,13 +203,15 @@ pub use ron;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    processor::{AssetProcessor, AssetValidator, Process},
 };
 use alloc::sync::Arc;
 use bevy_app::{App, Last, Plugin, PreUpdate};
 use bevy_ecs::{
     reflect::AppTypeRegistry,
-    schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet},
+    schedule::{
+        common_conditions::resource_exists, IntoSystemConfigs, IntoSystemSetConfigs, SystemSet,
+    },
     world::FromWorld,
 };
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
@@ -374,7 +378,9 @@ impl Plugin for AssetPlugin {
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
+            .init_asset::<AssetManifest>()
             .init_asset::<()>()
+            .register_asset_loader(AssetManifestLoader)
             .add_event::<UntypedAssetLoadFailedEvent>()
             .configure_sets(PreUpdate, TrackAssets.after(handle_internal_asset_events))
             // `handle_internal_asset_events` requires the use of `&mut World`,
@@ -458,6 +464,8 @@ pub trait AssetApp {
     fn register_asset_loader<L: AssetLoader>(&mut self, loader: L) -> &mut Self;
     /// Registers the given `processor` in the [`App`]'s [`AssetProcessor`].
     fn register_asset_processor<P: Process>(&mut self, processor: P) -> &mut Self;
+    /// Registers the given `validator` in the [`App`]'s [`AssetProcessor`].
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self;
     /// Registers the given [`AssetSourceBuilder`] with the given `id`.
     ///
     /// Note that asset sources must be registered before adding [`AssetPlugin`] to your application,
@@ -479,6 +487,9 @@ pub trait AssetApp {
     ///     mutable access to this resource this causes a conflict, but they rarely actually
     ///     modify the same underlying asset.
     fn init_asset<A: Asset>(&mut self) -> &mut Self;
+    /// Starts loading the [`AssetCollection`] `T`, tracked by the [`LoadingAssetCollection<T>`] resource,
+    /// and inserts `T` as a resource once all of its assets are loaded with their dependencies.
+    fn init_asset_collection<T: AssetCollection>(&mut self) -> &mut Self;
     /// Registers the asset type `T` using `[App::register]`,
     /// and adds [`ReflectAsset`] type data to `T` and [`ReflectHandle`] type data to [`Handle<T>`] in the type registry.
     ///
@@ -506,6 +517,13 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_validator<V: AssetValidator>(&mut self, validator: V) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.register_validator(validator);
+        }
+        self
+    }
+
     fn register_asset_source(
         &mut self,
         id: impl Into<AssetSourceId<'static>>,
@@ -562,13 +580,26 @@ impl AssetApp for App {
             .register_type::<Handle<A>>()
             .add_systems(
                 Last,
-                Assets::<A>::asset_events
-                    .run_if(Assets::<A>::asset_events_condition)
+                (
+                    Assets::<A>::evict_over_budget.run_if(Assets::<A>::memory_budget_condition),
+                    Assets::<A>::asset_events.run_if(Assets::<A>::asset_events_condition),
+                )
+                    .chain()
                     .in_set(AssetEvents),
             )
             .add_systems(PreUpdate, Assets::<A>::track_assets.in_set(TrackAssets))
     }
 
+    fn init_asset_collection<T: AssetCollection>(&mut self) -> &mut Self {
+        let loading = LoadingAssetCollection::<T>::new(self.world().resource::<AssetServer>());
+        self.insert_resource(loading).add_systems(
+            PreUpdate,
+            LoadingAssetCollection::<T>::insert_when_loaded
+                .run_if(resource_exists::<LoadingAssetCollection<T>>)
+                .after(handle_internal_asset_events),
+        )
+    }
+
     fn register_asset_reflect<A>(&mut self) -> &mut Self
     where
         A: Asset + Reflect + FromReflect + GetTypeRegistration,
@@ -616,8 +647,9 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        Asset, AssetApp, AssetCollection, AssetCollectionProgress, AssetEvent, AssetId,
+        AssetLoadError, AssetLoadFailedEvent, AssetManifest, AssetPath, AssetPlugin, AssetServer,
+        Assets, LoadPriority, LoadState, LoadingAssetCollection,
     };
     use alloc::sync::Arc;
     use bevy_app::{App, Update};
@@ -1410,6 +1442,269 @@ mod tests {
         );
     }
 
+    #[test]
+    fn evict_least_recently_used_assets_over_budget() {
+        let dir = Dir::default();
+        let text = |text: &str| {
+            format!(
+                "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+            )
+        };
+        for (path, content) in [
+            ("a.cool.ron", "aaaa"),
+            ("b.cool.ron", "bbbb"),
+            ("c.cool.ron", "cccc"),
+        ] {
+            dir.insert_asset_text(Path::new(path), &text(content));
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_memory_budget(10, |text| text.text.len());
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let is_loaded =
+            |world: &World, handle: &Handle<CoolText>| get(world, handle.id()).is_some();
+
+        gate_opener.open("a.cool.ron");
+        gate_opener.open("b.cool.ron");
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        // the handles are held by the test, so the assets must be cacheable to be evicted
+        for handle in [&a, &b] {
+            app.world_mut()
+                .resource_mut::<Assets<CoolText>>()
+                .mark_cacheable(handle);
+        }
+        run_app_until(&mut app, |world| {
+            (is_loaded(world, &a) && is_loaded(world, &b)).then_some(())
+        });
+        assert_eq!(
+            app.world().resource::<Assets<CoolText>>().memory_usage(),
+            Some(8)
+        );
+
+        // `a` was used more recently than `b`, so `b` is evicted when `c` doesn't fit in the budget
+        app.world_mut().resource_mut::<Assets<CoolText>>().touch(&a);
+        gate_opener.open("c.cool.ron");
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .mark_cacheable(&c);
+        run_app_until(&mut app, |world| is_loaded(world, &c).then_some(()));
+        app.update();
+        assert!(is_loaded(app.world(), &a));
+        assert!(!is_loaded(app.world(), &b));
+        assert!(matches!(asset_server.load_state(&b), LoadState::NotLoaded));
+        assert_eq!(
+            app.world().resource::<Assets<CoolText>>().memory_usage(),
+            Some(8)
+        );
+
+        // loading `b` again reloads it into the same handle, evicting `a`
+        gate_opener.open("b.cool.ron");
+        let reloaded_b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        assert_eq!(reloaded_b, b);
+        run_app_until(&mut app, |world| is_loaded(world, &b).then_some(()));
+        app.update();
+        assert!(!is_loaded(app.world(), &a));
+        assert!(is_loaded(app.world(), &c));
+    }
+
+    #[test]
+    fn strongly_held_assets_are_not_evicted_over_budget() {
+        let dir = Dir::default();
+        let text = |text: &str| {
+            format!(
+                "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+            )
+        };
+        dir.insert_asset_text(Path::new("a.cool.ron"), &text("aaaa"));
+        dir.insert_asset_text(Path::new("b.cool.ron"), &text("bbbb"));
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_memory_budget(4, |text| text.text.len());
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let is_loaded =
+            |world: &World, handle: &Handle<CoolText>| get(world, handle.id()).is_some();
+
+        gate_opener.open("a.cool.ron");
+        gate_opener.open("b.cool.ron");
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        run_app_until(&mut app, |world| {
+            (is_loaded(world, &a) && is_loaded(world, &b)).then_some(())
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+
+        // both assets are over the budget, but the strong handles keep them loaded
+        assert!(is_loaded(app.world(), &a));
+        assert!(is_loaded(app.world(), &b));
+        assert!(asset_server.load_state(&a).is_loaded());
+        assert!(asset_server.load_state(&b).is_loaded());
+        assert_eq!(
+            app.world().resource::<Assets<CoolText>>().memory_usage(),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn dependency_graph() {
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+        "missing.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new("b.cool.ron"), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        gate_opener.open(a_path);
+        gate_opener.open("b.cool.ron");
+        gate_opener.open("missing.cool.ron");
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        run_app_until(&mut app, |_| {
+            asset_server
+                .recursive_dependency_load_state(&a)
+                .is_failed()
+                .then_some(())
+        });
+
+        let b = asset_server.get_handle_untyped("b.cool.ron").unwrap().id();
+        let missing = asset_server
+            .get_handle_untyped("missing.cool.ron")
+            .unwrap()
+            .id();
+        let graph = asset_server.dependency_graph();
+        let mut dependencies = vec![b, missing];
+        dependencies.sort();
+        assert_eq!(graph.dependencies(&a), dependencies);
+        assert_eq!(
+            graph.dependents(b).map(|node| node.id).collect::<Vec<_>>(),
+            [a.id().untyped()]
+        );
+        assert_eq!(graph.failed_leaves(&a), [missing]);
+        assert!(graph.pending_leaves(&a).is_empty());
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph assets {"));
+        assert!(dot.contains("label=\"missing.cool.ron\", fillcolor=red"));
+        assert_eq!(dot.matches(" -> ").count(), 2);
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        let a_json = json["assets"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|asset| asset["path"] == a_path)
+            .unwrap();
+        assert_eq!(a_json["load_state"]["state"], "Loaded");
+        assert_eq!(a_json["recursive_dependency_load_state"]["state"], "Failed");
+        assert_eq!(a_json["dependencies"].as_array().unwrap().len(), 2);
+    }
+
+    #[derive(Resource, AssetCollection)]
+    struct TextCollection {
+        #[asset(path = "a.cool.ron")]
+        a: Handle<CoolText>,
+        #[asset(path = "texts.assets.ron")]
+        texts: Handle<AssetManifest>,
+        not_loaded: Option<Handle<CoolText>>,
+    }
+
+    #[test]
+    fn load_asset_collection() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: [
+        "b.cool.ron",
+    ],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let manifest_ron = r#"
+(
+    assets: {
+        "c": "c.cool.ron",
+    },
+)"#;
+        dir.insert_asset_text(Path::new("a.cool.ron"), a_ron);
+        dir.insert_asset_text(Path::new("b.cool.ron"), SIMPLE_TEXT);
+        dir.insert_asset_text(Path::new("c.cool.ron"), SIMPLE_TEXT);
+        dir.insert_asset_text(Path::new("texts.assets.ron"), manifest_ron);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .init_asset_collection::<TextCollection>();
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        {
+            let loading = app
+                .world()
+                .resource::<LoadingAssetCollection<TextCollection>>();
+            assert!(loading.load_state(&asset_server).is_loading());
+            assert_eq!(
+                loading.progress(&asset_server),
+                AssetCollectionProgress {
+                    loaded: 0,
+                    total: 2
+                }
+            );
+            assert!(loading.collection().not_loaded.is_none());
+        }
+
+        for path in ["a.cool.ron", "b.cool.ron", "c.cool.ron", "texts.assets.ron"] {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |world| {
+            world.contains_resource::<TextCollection>().then_some(())
+        });
+        assert!(!app
+            .world()
+            .contains_resource::<LoadingAssetCollection<TextCollection>>());
+
+        let collection = app.world().resource::<TextCollection>();
+        assert!(collection.load_state(&asset_server).is_loaded());
+        assert!(collection.progress(&asset_server).is_done());
+        let manifest = app
+            .world()
+            .resource::<Assets<AssetManifest>>()
+            .get(&collection.texts)
+            .unwrap();
+        assert_eq!(manifest.path("c"), Some(&AssetPath::from("c.cool.ron")));
+        let c: Handle<CoolText> = manifest.get("c", &asset_server).unwrap();
+        let cool_texts = app.world().resource::<Assets<CoolText>>();
+        assert_eq!(cool_texts.get(&c).unwrap().text, "dep");
+        assert_eq!(cool_texts.get(&collection.a).unwrap().text, "a");
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -1730,6 +2025,114 @@ mod tests {
         });
     }
 
+    /// Loads a [`CoolText`] depending on the asset at the path stored in the file, then stops
+    /// starting new loads so that the dependency stays in the load queue.
+    struct PausingDependentLoader;
+
+    impl AssetLoader for PausingDependentLoader {
+        type Asset = CoolText;
+        type Settings = ();
+        type Error = CoolTextLoaderError;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &Self::Settings,
+            load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let dependency = load_context.load(String::from_utf8_lossy(&bytes).into_owned());
+            load_context.asset_server.set_max_concurrent_loads(Some(0));
+            Ok(CoolText {
+                dependencies: vec![dependency],
+                ..Default::default()
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["dependent"]
+        }
+    }
+
+    #[test]
+    fn dependencies_inherit_load_priority() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.dependent"), "b.cool.ron");
+        dir.insert_asset_text(Path::new("b.cool.ron"), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader)
+            .register_asset_loader(PausingDependentLoader);
+        gate_opener.open("a.dependent");
+        gate_opener.open("b.cool.ron");
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.set_max_concurrent_loads(Some(1));
+
+        let handle: Handle<CoolText> =
+            asset_server.load_with_priority("a.dependent", LoadPriority::HIGH);
+        run_app_until(&mut app, |_| {
+            let priority = asset_server.load_priority(asset_server.get_path_id("b.cool.ron")?)?;
+            assert_eq!(priority, LoadPriority::HIGH);
+            Some(())
+        });
+
+        asset_server.set_max_concurrent_loads(None);
+        run_app_until(&mut app, |_| {
+            asset_server
+                .is_loaded_with_dependencies(&handle)
+                .then_some(())
+        });
+    }
+
+    #[test]
+    fn loads_are_cancelled_when_their_handles_are_dropped() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.cool.ron"), SIMPLE_TEXT);
+        dir.insert_asset_text(Path::new("b.cool.ron"), SIMPLE_TEXT);
+        let reader = UnstableMemoryAssetReader::new(dir, 0);
+        let attempt_counters = reader.attempt_counters.clone();
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        // no load can start until the limit is raised
+        asset_server.set_max_concurrent_loads(Some(0));
+
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let b_id = b.id();
+        run_app_until(&mut app, |_| asset_server.load_priority(b_id).map(|_| ()));
+        drop(b);
+        app.update();
+
+        asset_server.set_max_concurrent_loads(Some(1));
+        run_app_until(&mut app, |_| asset_server.is_loaded(&a).then_some(()));
+        for _ in 0..10 {
+            app.update();
+        }
+
+        assert!(asset_server.get_load_state(b_id).is_none());
+        assert!(asset_server.load_priority(b_id).is_none());
+        assert!(!attempt_counters
+            .lock()
+            .unwrap()
+            .contains_key(Path::new("b.cool.ron")));
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -3,8 +3,8 @@ use crate::{
     loader_builders::{Deferred, NestedLoader, StaticTyped},
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
     path::AssetPath,
-    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, LoadPriority,
+    UntypedAssetId, UntypedHandle,
 };
 use atomicow::CowArc;
 use bevy_ecs::world::World;
@@ -320,6 +320,8 @@ pub struct LoadContext<'a> {
     pub(crate) should_load_dependencies: bool,
     populate_hashes: bool,
     asset_path: AssetPath<'static>,
+    /// The priority the asset is loaded with, inherited by its deferred dependencies.
+    pub(crate) priority: LoadPriority,
     pub(crate) dependencies: HashSet<UntypedAssetId>,
     /// Direct dependencies used by this loader.
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
@@ -333,12 +335,14 @@ impl<'a> LoadContext<'a> {
         asset_path: AssetPath<'static>,
         should_load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Self {
         Self {
             asset_server,
             asset_path,
             populate_hashes,
             should_load_dependencies,
+            priority,
             dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
@@ -380,6 +384,7 @@ impl<'a> LoadContext<'a> {
             self.asset_path.clone(),
             self.should_load_dependencies,
             self.populate_hashes,
+            self.priority,
         )
     }
 
@@ -450,6 +455,11 @@ impl<'a> LoadContext<'a> {
         !self.asset_server.get_handles_untyped(&path).is_empty()
     }
 
+    /// Iterate over the labels of the "labeled assets" added to this context so far.
+    pub fn iter_labels(&self) -> impl Iterator<Item = &str> {
+        self.labeled_assets.keys().map(|s| &**s)
+    }
+
     /// "Finishes" this context by populating the final [`Asset`] value (and the erased [`AssetMeta`] value, if it exists).
     /// The relevant asset metadata collected in this context will be stored in the returned [`LoadedAsset`].
     pub fn finish<A: Asset>(self, value: A, meta: Option<Box<dyn AssetMetaDyn>>) -> LoadedAsset<A> {
@@ -538,6 +548,7 @@ impl<'a> LoadContext<'a> {
                 reader,
                 false,
                 self.populate_hashes,
+                self.priority,
             )
             .await
             .map_err(|error| LoadDirectError {
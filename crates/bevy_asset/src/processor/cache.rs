@@ -0,0 +1,258 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::io::file::{FileAssetReader, FileAssetWriter};
+use crate::{
+    io::{AssetReader, AssetReaderError, AssetWriter, ErasedAssetReader, ErasedAssetWriter},
+    meta::{AssetHash, META_FORMAT_VERSION},
+};
+use bevy_utils::tracing::warn;
+use core::fmt;
+use futures_lite::AsyncReadExt;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// A content-addressed cache of processed assets, shared by the [`AssetProcessor`]s of several checkouts or machines,
+/// such as the machines of a CI pipeline, or the ones of a team through a network drive.
+///
+/// Before processing an asset, the [`AssetProcessor`] looks up the processed asset in the cache with a
+/// [`ProcessCacheKey`], which covers the bytes of the source asset, its `.meta` file (which holds the processor and
+/// its settings), the [`Process::VERSION`] of the processor and the registered [`AssetValidator`]s. On a hit, the cached processed asset is written to the
+/// destination instead of processing the asset again, as long as the process dependencies it was processed with are
+/// unchanged. On a miss, the asset is processed and the result is stored in the cache.
+///
+/// The cache is backed by an [`AssetReader`] and an [`AssetWriter`], usually reading and writing a directory with
+/// [`ProcessCache::from_directory`]. Errors while reading or writing the cache are logged and otherwise ignored: the
+/// asset is then processed as if there was no cache.
+///
+/// Each processed asset is stored as a single file holding its `.meta` file and its bytes, along with a hash of
+/// both which is checked when reading it back, so a corrupted entry is treated as a miss.
+///
+/// [`AssetProcessor`]: crate::processor::AssetProcessor
+/// [`Process::VERSION`]: crate::processor::Process::VERSION
+/// [`AssetValidator`]: crate::processor::AssetValidator
+pub struct ProcessCache {
+    reader: Box<dyn ErasedAssetReader>,
+    writer: Box<dyn ErasedAssetWriter>,
+}
+
+impl ProcessCache {
+    /// Creates a cache that reads processed assets with `reader` and writes them with `writer`, which should
+    /// both access the same storage.
+    pub fn new(reader: impl AssetReader, writer: impl AssetWriter) -> Self {
+        Self {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+        }
+    }
+
+    /// Creates a cache storing processed assets in the directory at `path`, which is created if it doesn't exist.
+    ///
+    /// Relative paths are relative to the same base path as [`FileAssetReader::new`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_directory(path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        Self::new(FileAssetReader::new(path), FileAssetWriter::new(path, true))
+    }
+
+    /// Returns the processed asset stored for `key`, as its bytes and the bytes of its `.meta` file.
+    pub(crate) async fn get(&self, key: &ProcessCacheKey) -> Option<(Vec<u8>, Vec<u8>)> {
+        let mut entry = Vec::new();
+        let result = match self.reader.read(&key.path()).await {
+            Ok(mut reader) => reader
+                .read_to_end(&mut entry)
+                .await
+                .map_err(|err| AssetReaderError::Io(err.into())),
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(_) => {}
+            Err(AssetReaderError::NotFound(_)) => return None,
+            Err(err) => {
+                warn!("Failed to read processed asset {key} from the cache: {err}");
+                return None;
+            }
+        }
+        let entry = decode_entry(&entry);
+        if entry.is_none() {
+            warn!("Ignoring corrupted processed asset {key} in the cache");
+        }
+        entry
+    }
+
+    /// Stores a processed asset for `key`, from its bytes and the bytes of its `.meta` file.
+    ///
+    /// The entry is written to a temporary path and then renamed into place, so concurrent processors never read a
+    /// partially written entry.
+    pub(crate) async fn put(&self, key: &ProcessCacheKey, bytes: &[u8], meta_bytes: &[u8]) {
+        let path = key.path();
+        let temp_path = path.with_extension(format!("{}.tmp", Uuid::new_v4().simple()));
+        let result = async {
+            self.writer
+                .write_bytes(&temp_path, &encode_entry(bytes, meta_bytes))
+                .await?;
+            self.writer.rename(&temp_path, &path).await
+        }
+        .await;
+        if let Err(err) = result {
+            warn!("Failed to write processed asset {key} to the cache: {err}");
+            let _ = self.writer.remove(&temp_path).await;
+        }
+    }
+}
+
+/// The size of the header of a cache entry: the hash of the rest of the entry, then the length of the `.meta` file.
+const ENTRY_HEADER_SIZE: usize = 32 + 8;
+
+/// Encodes a cache entry, as its header followed by the `.meta` file and the bytes of the processed asset.
+fn encode_entry(bytes: &[u8], meta_bytes: &[u8]) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(ENTRY_HEADER_SIZE + meta_bytes.len() + bytes.len());
+    entry.extend_from_slice(&[0; 32]);
+    entry.extend_from_slice(&(meta_bytes.len() as u64).to_le_bytes());
+    entry.extend_from_slice(meta_bytes);
+    entry.extend_from_slice(bytes);
+    let hash = blake3::hash(&entry[32..]);
+    entry[..32].copy_from_slice(hash.as_bytes());
+    entry
+}
+
+/// Decodes a cache entry into the bytes of the processed asset and of its `.meta` file, if it isn't corrupted.
+fn decode_entry(entry: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    if entry.len() < ENTRY_HEADER_SIZE || blake3::hash(&entry[32..]).as_bytes() != &entry[..32] {
+        return None;
+    }
+    let meta_len = u64::from_le_bytes(entry[32..ENTRY_HEADER_SIZE].try_into().unwrap());
+    let rest = &entry[ENTRY_HEADER_SIZE..];
+    let meta_len = usize::try_from(meta_len)
+        .ok()
+        .filter(|len| *len <= rest.len())?;
+    let (meta_bytes, bytes) = rest.split_at(meta_len);
+    Some((bytes.to_vec(), meta_bytes.to_vec()))
+}
+
+/// The key of a processed asset in a [`ProcessCache`].
+///
+/// This is a hash of the source asset and `.meta` hash stored in [`ProcessedInfo::hash`], the
+/// [`Process::VERSION`] of the processor and the [`META_FORMAT_VERSION`], and of the [`AssetValidator`]s the asset
+/// was validated with when added with [`with_validators`](Self::with_validators).
+///
+/// [`ProcessedInfo::hash`]: crate::meta::ProcessedInfo::hash
+/// [`Process::VERSION`]: crate::processor::Process::VERSION
+/// [`AssetValidator`]: crate::processor::AssetValidator
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ProcessCacheKey(AssetHash);
+
+impl ProcessCacheKey {
+    /// Returns the key of the asset with the given source asset and `.meta` `hash`, processed by a processor
+    /// with the given `processor_version`.
+    pub fn new(hash: AssetHash, processor_version: u32) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(META_FORMAT_VERSION.as_bytes());
+        hasher.update(&hash);
+        hasher.update(&processor_version.to_le_bytes());
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// Returns this key for an asset also validated by the [`AssetValidator`](crate::processor::AssetValidator)s
+    /// with the given type names, in any order, so that cached assets are processed and validated again when
+    /// validators are added or removed.
+    pub fn with_validators<'a>(self, validators: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut validators = validators.into_iter().collect::<Vec<_>>();
+        if validators.is_empty() {
+            return self;
+        }
+        validators.sort_unstable();
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.0);
+        for validator in validators {
+            hasher.update(&(validator.len() as u64).to_le_bytes());
+            hasher.update(validator.as_bytes());
+        }
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// The path of the processed asset in the cache, in a subdirectory named after the first byte of the key to
+    /// keep directories small.
+    fn path(&self) -> PathBuf {
+        let key = self.to_string();
+        Path::new(&key[..2]).join(&key)
+    }
+}
+
+impl fmt::Display for ProcessCacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_key() {
+        let key = ProcessCacheKey::new([1; 32], 0);
+        assert_eq!(key, ProcessCacheKey::new([1; 32], 0));
+        assert_ne!(key, ProcessCacheKey::new([2; 32], 0));
+        assert_ne!(key, ProcessCacheKey::new([1; 32], 1));
+        assert_eq!(key, key.with_validators([]));
+        assert_ne!(key, key.with_validators(["a::Validator"]));
+        assert_eq!(
+            key.with_validators(["a::Validator", "b::Validator"]),
+            key.with_validators(["b::Validator", "a::Validator"])
+        );
+        assert_ne!(
+            key.with_validators(["a::Validator"]),
+            key.with_validators(["a::Validator", "b::Validator"])
+        );
+
+        let name = key.to_string();
+        assert_eq!(name.len(), 64);
+        assert_eq!(key.path(), Path::new(&name[..2]).join(&name));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn store_processed_assets_in_directory() {
+        let path = std::env::temp_dir().join(format!(
+            "bevy_process_cache_{}_{}",
+            std::process::id(),
+            Uuid::new_v4().simple()
+        ));
+        let cache = ProcessCache::from_directory(&path);
+        let key = ProcessCacheKey::new([1; 32], 0);
+
+        bevy_tasks::block_on(async {
+            assert!(cache.get(&key).await.is_none());
+            cache.put(&key, b"processed", b"meta").await;
+            assert_eq!(
+                cache.get(&key).await,
+                Some((b"processed".to_vec(), b"meta".to_vec()))
+            );
+            assert!(cache.get(&ProcessCacheKey::new([1; 32], 1)).await.is_none());
+
+            // storing the asset again replaces it and leaves no temporary files behind
+            cache.put(&key, b"reprocessed", b"new meta").await;
+            assert_eq!(
+                cache.get(&key).await,
+                Some((b"reprocessed".to_vec(), b"new meta".to_vec()))
+            );
+        });
+        let directory = path.join(&key.to_string()[..2]);
+        let files = std::fs::read_dir(&directory).unwrap().count();
+        assert_eq!(files, 1);
+
+        // corrupted entries are misses
+        let entry_path = directory.join(key.to_string());
+        let mut entry = std::fs::read(&entry_path).unwrap();
+        let last = entry.len() - 1;
+        entry[last] ^= 1;
+        std::fs::write(&entry_path, &entry).unwrap();
+        assert!(bevy_tasks::block_on(cache.get(&key)).is_none());
+        std::fs::write(&entry_path, &entry[..ENTRY_HEADER_SIZE - 1]).unwrap();
+        assert!(bevy_tasks::block_on(cache.get(&key)).is_none());
+
+        let _ = std::fs::remove_dir_all(path);
+    }
+}
@@ -26,6 +26,18 @@
 //!
 //! If a default asset processor is set, assets with a matching extension will be processed using that processor before loading.
 //!
+//! To check loaded assets for problems at import time, register an [`AssetValidator`] with [`AssetProcessor::register_validator`].
+//!
+//! To process assets ahead of time without running the game, for example on build machines, call [`AssetProcessor::process_assets`]
+//! and report the result with [`AssetProcessor::summary`]. The `asset-processor` tool in the Bevy repository does this for
+//! the assets supported by the default plugins.
+//!
+//! To reuse the assets processed on other machines or checkouts, such as build machines or the machines of teammates,
+//! set a shared [`ProcessCache`] with [`AssetProcessor::set_cache`].
+//!
+//! With the `pak` feature, the processed assets of a source can also be bundled into a single archive
+//! to ship, using [`AssetProcessor::add_pak_output`].
+//!
 //! For an end-to-end example, check out the examples in the [`examples/asset/processing`](https://github.com/bevyengine/bevy/tree/latest/examples/asset/processing) directory of the Bevy repository.
 //!
 //!  # Defining asset processors
@@ -37,37 +49,42 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
+mod validate;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
+pub use validate::*;
 
 use crate::{
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
         AssetSources, AssetWriterError, ErasedAssetReader, ErasedAssetWriter,
-        MissingAssetSourceError,
+        MissingAssetSourceError, SliceReader,
     },
     meta::{
         get_asset_hash, get_full_asset_hash, AssetAction, AssetActionMinimal, AssetHash, AssetMeta,
         AssetMetaDyn, AssetMetaMinimal, ProcessedInfo, ProcessedInfoMinimal,
     },
     AssetLoadError, AssetMetaCheck, AssetPath, AssetServer, AssetServerMode, DeserializeMetaError,
-    MissingAssetLoaderForExtensionError,
+    ErasedLoadedAsset, LoadPriority, MissingAssetLoaderForExtensionError,
 };
 use alloc::{collections::VecDeque, sync::Arc};
 use bevy_ecs::prelude::*;
 use bevy_tasks::IoTaskPool;
 use bevy_utils::{
     tracing::{debug, error, trace, warn},
-    HashMap, HashSet,
+    HashMap, HashSet, TypeIdMap,
 };
 #[cfg(feature = "trace")]
 use bevy_utils::{
     tracing::{info_span, instrument::Instrument},
     ConditionalSendFuture,
 };
+use core::any::TypeId;
 use derive_more::derive::{Display, Error};
 use futures_io::ErrorKind;
 use futures_lite::{AsyncReadExt, AsyncWriteExt, StreamExt};
@@ -99,21 +116,38 @@ pub struct AssetProcessor {
 pub struct AssetProcessorData {
     pub(crate) asset_infos: async_lock::RwLock<ProcessorAssetInfos>,
     log: async_lock::RwLock<Option<ProcessorTransactionLog>>,
+    /// Where the [`ProcessorTransactionLog`] is written
+    log_path: PathBuf,
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// Validators run on loaded assets, by asset type
+    validators: RwLock<TypeIdMap<Vec<Arc<dyn ErasedAssetValidator>>>>,
+    /// Cache of processed assets shared across machines
+    cache: RwLock<Option<Arc<ProcessCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
     initialized_receiver: async_broadcast::Receiver<()>,
     finished_sender: async_broadcast::Sender<()>,
     finished_receiver: async_broadcast::Receiver<()>,
+    /// Pak archives the processed assets of sources are bundled into
+    #[cfg(feature = "pak")]
+    pak_outputs: RwLock<HashMap<AssetSourceId<'static>, crate::io::pak::PakOutput>>,
 }
 
 impl AssetProcessor {
     /// Creates a new [`AssetProcessor`] instance.
     pub fn new(source: &mut AssetSourceBuilders) -> Self {
-        let data = Arc::new(AssetProcessorData::new(source.build_sources(true, false)));
+        Self::with_log_path(source, ProcessorTransactionLog::default_path())
+    }
+
+    /// Creates a new [`AssetProcessor`] instance writing its [`ProcessorTransactionLog`] to
+    /// `log_path` instead of the default location.
+    pub(crate) fn with_log_path(source: &mut AssetSourceBuilders, log_path: PathBuf) -> Self {
+        let mut data = AssetProcessorData::new(source.build_sources(true, false));
+        data.log_path = log_path;
+        let data = Arc::new(data);
         // The asset processor uses its own asset server with its own id space
         let mut sources = source.build_sources(false, false);
         sources.gate_on_processor(data.clone());
@@ -153,6 +187,23 @@ impl AssetProcessor {
         *self.data.state.read().await
     }
 
+    /// Returns which assets were processed successfully and which failed, for example to report the result
+    /// of processing assets in a build pipeline once the state is [`ProcessorState::Finished`].
+    pub async fn summary(&self) -> ProcessSummary {
+        let infos = self.data.asset_infos.read().await;
+        let mut summary = ProcessSummary::default();
+        for (path, info) in &infos.infos {
+            match info.status {
+                Some(ProcessStatus::Processed) => summary.processed.push(path.clone()),
+                Some(ProcessStatus::Failed) => summary.failed.push(path.clone()),
+                Some(ProcessStatus::NonExistent) | None => {}
+            }
+        }
+        summary.processed.sort_by_cached_key(ToString::to_string);
+        summary.failed.sort_by_cached_key(ToString::to_string);
+        summary
+    }
+
     /// Retrieves the [`AssetSource`] for this processor
     #[inline]
     pub fn get_source<'a>(
@@ -475,9 +526,106 @@ impl AssetProcessor {
         self.try_reprocessing_queued().await;
         // clean up metadata in asset server
         self.server.data.infos.write().consume_handle_drop_events();
+        #[cfg(all(feature = "pak", not(target_arch = "wasm32")))]
+        self.write_pak_outputs().await;
         self.set_state(ProcessorState::Finished).await;
     }
 
+    /// Bundles the processed assets of the source `source` into the pak archive described by `output`,
+    /// each time the processor finishes processing assets.
+    ///
+    /// Only assets that were processed successfully are bundled, along with their processed `.meta` files.
+    /// The archive is deterministic: it only depends on the processed assets, so unchanged assets
+    /// produce the same archive. Load it with [`PakArchive`](crate::io::pak::PakArchive).
+    #[cfg(feature = "pak")]
+    pub fn add_pak_output(
+        &self,
+        source: impl Into<AssetSourceId<'static>>,
+        output: crate::io::pak::PakOutput,
+    ) {
+        self.data.pak_outputs.write().insert(source.into(), output);
+    }
+
+    #[cfg(all(feature = "pak", not(target_arch = "wasm32")))]
+    async fn write_pak_outputs(&self) {
+        let outputs = self.data.pak_outputs.read().clone();
+        for (source, output) in outputs {
+            match self.write_pak_output(&source, &output).await {
+                Ok(()) => debug!(
+                    "Bundled the processed assets of {source} into {}",
+                    output.path.display()
+                ),
+                Err(err) => error!(
+                    "Failed to bundle the processed assets of {source} into {}: {err}",
+                    output.path.display()
+                ),
+            }
+        }
+    }
+
+    #[cfg(all(feature = "pak", not(target_arch = "wasm32")))]
+    async fn write_pak_output(
+        &self,
+        source: &AssetSourceId<'static>,
+        output: &crate::io::pak::PakOutput,
+    ) -> Result<(), WritePakError> {
+        let reader = self.get_source(source.clone())?.processed_reader()?;
+        // Copy the processed assets out of the infos, so that the lock isn't held while reading them.
+        let processed = {
+            let infos = self.data.asset_infos.read().await;
+            infos
+                .infos
+                .iter()
+                .filter(|(asset_path, info)| {
+                    asset_path.source() == source && info.status == Some(ProcessStatus::Processed)
+                })
+                .filter_map(|(asset_path, info)| {
+                    let processed_info = info.processed_info.as_ref()?;
+                    Some((
+                        asset_path.clone(),
+                        processed_info.full_hash,
+                        info.file_transaction_lock.clone(),
+                    ))
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut builder = crate::io::pak::PakBuilder::new();
+        for (asset_path, hash, file_transaction_lock) in processed {
+            let _transaction_lock = file_transaction_lock.read().await;
+            let path = asset_path.path();
+            let mut bytes = Vec::new();
+            reader
+                .read(path)
+                .await?
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(|err| AssetReaderError::Io(err.into()))?;
+            let meta_bytes = reader.read_meta_bytes(path).await?;
+            builder
+                .insert_asset_with_hash(path, bytes, output.compression_for(path), hash)
+                .insert_meta(path, meta_bytes, output.compression);
+        }
+        // Serialize the archive before touching the file system, and drop the assets right away.
+        let bytes = builder.to_bytes()?;
+        drop(builder);
+
+        let path = crate::io::file::FileAssetReader::get_base_path().join(&output.path);
+        if let Some(parent) = path.parent() {
+            async_fs::create_dir_all(parent).await?;
+        }
+        // Write next to the archive and rename over it, so that a failed write never leaves a
+        // truncated archive behind.
+        let mut temp_path = path.clone().into_os_string();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        if let Err(err) = async_fs::write(&temp_path, bytes).await {
+            let _ = async_fs::remove_file(&temp_path).await;
+            return Err(err.into());
+        }
+        async_fs::rename(&temp_path, &path).await?;
+        Ok(())
+    }
+
     #[allow(unused)]
     #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
     async fn process_assets_internal<'scope>(
@@ -548,6 +696,60 @@ impl AssetProcessor {
         processors.get(processor_type_name).cloned()
     }
 
+    /// Register a new [`AssetValidator`], run on the assets of type [`AssetValidator::Asset`] loaded while processing.
+    pub fn register_validator<V: AssetValidator>(&self, validator: V) {
+        let mut validators = self.data.validators.write();
+        validators
+            .entry(TypeId::of::<V::Asset>())
+            .or_default()
+            .push(Arc::new(validator));
+    }
+
+    /// Sets the [`ProcessCache`] processed assets are reused from and stored in. This should be called before the
+    /// processor starts processing assets.
+    pub fn set_cache(&self, cache: ProcessCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns `true` if an [`AssetValidator`] is registered for the assets of type `type_id`.
+    fn has_validators(&self, type_id: TypeId) -> bool {
+        self.data.validators.read().contains_key(&type_id)
+    }
+
+    /// Runs the registered [`AssetValidator`]s on `asset` and its labeled assets, logging the issues they report.
+    pub(crate) fn validate_asset(
+        &self,
+        path: &AssetPath<'static>,
+        asset: &ErasedLoadedAsset,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        self.validate_asset_internal(path, asset, &mut issues);
+        for issue in &issues {
+            match issue.severity {
+                ValidationSeverity::Warning => warn!("Asset validation warning for {issue}"),
+                ValidationSeverity::Error => error!("Asset validation error for {issue}"),
+            }
+        }
+        issues
+    }
+
+    fn validate_asset_internal(
+        &self,
+        path: &AssetPath<'static>,
+        asset: &ErasedLoadedAsset,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if let Some(validators) = self.data.validators.read().get(&asset.asset_type_id()) {
+            for validator in validators {
+                validator.validate(asset, path, issues);
+            }
+        }
+        for (label, labeled) in &asset.labeled_assets {
+            let labeled_path = path.clone().with_label(label.clone());
+            self.validate_asset_internal(&labeled_path, &labeled.asset, issues);
+        }
+    }
+
     /// Populates the initial view of each asset by scanning the unprocessed and processed asset folders.
     /// This info will later be used to determine whether or not to re-process an asset
     ///
@@ -756,44 +958,47 @@ impl AssetProcessor {
         // Note: we get the asset source reader first because we don't want to create meta files for assets that don't have source files
         let mut byte_reader = reader.read(path).await.map_err(reader_err)?;
 
-        let (mut source_meta, meta_bytes, processor) = match reader.read_meta_bytes(path).await {
+        let (mut source_meta, meta_bytes, processor, loader) = match reader
+            .read_meta_bytes(path)
+            .await
+        {
             Ok(meta_bytes) => {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
-                let (meta, processor) = match minimal.asset {
+                let (meta, processor, loader) = match minimal.asset {
                     AssetActionMinimal::Load { loader } => {
                         let loader = server.get_asset_loader_with_type_name(&loader).await?;
                         let meta = loader.deserialize_meta(&meta_bytes)?;
-                        (meta, None)
+                        (meta, None, Some(loader))
                     }
                     AssetActionMinimal::Process { processor } => {
                         let processor = self
                             .get_processor(&processor)
                             .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
                         let meta = processor.deserialize_meta(&meta_bytes)?;
-                        (meta, Some(processor))
+                        (meta, Some(processor), None)
                     }
                     AssetActionMinimal::Ignore => {
                         return Ok(ProcessResult::Ignored);
                     }
                 };
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, loader)
             }
             Err(AssetReaderError::NotFound(_path)) => {
-                let (meta, processor) = if let Some(processor) = asset_path
+                let (meta, processor, loader) = if let Some(processor) = asset_path
                     .get_full_extension()
                     .and_then(|ext| self.get_default_processor(&ext))
                 {
                     let meta = processor.default_meta();
-                    (meta, Some(processor))
+                    (meta, Some(processor), None)
                 } else {
                     match server.get_path_asset_loader(asset_path.clone()).await {
-                        Ok(loader) => (loader.default_meta(), None),
+                        Ok(loader) => (loader.default_meta(), None, Some(loader)),
                         Err(MissingAssetLoaderForExtensionError { .. }) => {
                             let meta: Box<dyn AssetMetaDyn> =
                                 Box::new(AssetMeta::<(), ()>::new(AssetAction::Ignore));
-                            (meta, None)
+                            (meta, None, None)
                         }
                     }
                 };
@@ -804,7 +1009,7 @@ impl AssetProcessor {
                     .write_meta_bytes(path, &meta_bytes)
                     .await
                     .map_err(writer_err)?;
-                (meta, meta_bytes, processor)
+                (meta, meta_bytes, processor, loader)
             }
             Err(err) => {
                 return Err(ProcessError::ReadAssetMetaError {
@@ -859,6 +1064,28 @@ impl AssetProcessor {
                 }
             }
         }
+        // Only processed assets are cached, as assets that are loaded without processing are copied as-is
+        let cache =
+            self.data
+                .cache
+                .read()
+                .clone()
+                .zip(processor.as_ref())
+                .map(|(cache, processor)| {
+                    let validators = self.data.validators.read();
+                    let key = ProcessCacheKey::new(new_hash, processor.version()).with_validators(
+                        validators
+                            .values()
+                            .flatten()
+                            .map(|validator| validator.name()),
+                    );
+                    (cache, key)
+                });
+        let cached = match &cache {
+            Some((cache, key)) => self.get_cached_asset(cache, key, new_hash).await,
+            None => None,
+        };
+
         // Note: this lock must remain alive until all processed asset and meta writes have finished (or failed)
         // See ProcessedAssetInfo::file_transaction_lock docs for more info
         let _transaction_lock = {
@@ -871,15 +1098,30 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
+        if let Some((bytes, meta_bytes, processed_info)) = cached {
+            debug!("Reusing processed asset {:?} from the cache", asset_path);
+            processed_writer
+                .write_bytes(path, &bytes)
+                .await
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &meta_bytes)
+                .await
+                .map_err(writer_err)?;
+            self.log_end_processing(asset_path).await;
+            return Ok(ProcessResult::Processed(processed_info));
+        }
         if let Some(processor) = processor {
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
-            let mut processed_meta = {
+            let (mut processed_meta, validation_issues) = {
                 let mut context =
                     ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
-                processor
+                let processed_meta = processor
                     .process(&mut context, source_meta, &mut *writer)
-                    .await?
+                    .await?;
+                (processed_meta, context.validation_issues)
             };
+            fail_on_validation_errors(asset_path, validation_issues)?;
 
             writer
                 .flush()
@@ -903,7 +1145,31 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            if let Some((cache, key)) = &cache {
+                self.put_cached_asset(source, path, cache, key, &meta_bytes)
+                    .await;
+            }
         } else {
+            if let Some(loader) =
+                loader.filter(|loader| self.has_validators(loader.asset_type_id()))
+            {
+                let meta = loader.deserialize_meta(&meta_bytes)?;
+                let loaded_asset = server
+                    .load_with_meta_loader_and_reader(
+                        asset_path,
+                        meta,
+                        &*loader,
+                        &mut SliceReader::new(&asset_bytes),
+                        false,
+                        true,
+                        LoadPriority::NORMAL,
+                    )
+                    .await?;
+                fail_on_validation_errors(
+                    asset_path,
+                    self.validate_asset(asset_path, &loaded_asset),
+                )?;
+            }
             processed_writer
                 .write_bytes(path, &asset_bytes)
                 .await
@@ -920,8 +1186,73 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Returns the processed asset stored in `cache` for `key`, as its bytes, its meta bytes and its [`ProcessedInfo`],
+    /// if it was processed from a source asset with the given `hash` and its process dependencies are unchanged.
+    async fn get_cached_asset(
+        &self,
+        cache: &ProcessCache,
+        key: &ProcessCacheKey,
+        hash: AssetHash,
+    ) -> Option<(Vec<u8>, Vec<u8>, ProcessedInfo)> {
+        let (bytes, meta_bytes) = cache.get(key).await?;
+        let processed_info = match ron::de::from_bytes::<ProcessedInfoMinimal>(&meta_bytes) {
+            Ok(minimal) => minimal.processed_info?,
+            Err(err) => {
+                warn!(
+                    "Failed to deserialize the meta of processed asset {key} from the cache: {err}"
+                );
+                return None;
+            }
+        };
+        if processed_info.hash != hash {
+            return None;
+        }
+        for dependency in &processed_info.process_dependencies {
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                return None;
+            }
+        }
+        Some((bytes, meta_bytes, processed_info))
+    }
+
+    /// Stores the processed asset at `path` in `cache`, with the given `meta_bytes`.
+    async fn put_cached_asset(
+        &self,
+        source: &AssetSource,
+        path: &Path,
+        cache: &ProcessCache,
+        key: &ProcessCacheKey,
+        meta_bytes: &[u8],
+    ) {
+        let Ok(processed_reader) = source.processed_reader() else {
+            return;
+        };
+        let mut bytes = Vec::new();
+        let result = match processed_reader.read(path).await {
+            Ok(mut reader) => reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(|e| AssetReaderError::Io(e.into())),
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(_) => cache.put(key, &bytes, meta_bytes).await,
+            Err(err) => {
+                warn!("Failed to read processed asset {path:?} to store it in the cache: {err}");
+            }
+        }
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
-        if let Err(err) = ProcessorTransactionLog::validate().await {
+        if let Err(err) = ProcessorTransactionLog::validate(&self.data.log_path).await {
             let state_is_valid = match err {
                 ValidateLogError::ReadLogError(err) => {
                     error!("Failed to read processor log file. Processed assets cannot be validated so they must be re-generated {err}");
@@ -999,7 +1330,7 @@ impl AssetProcessor {
             }
         }
         let mut log = self.data.log.write().await;
-        *log = match ProcessorTransactionLog::new().await {
+        *log = match ProcessorTransactionLog::new(&self.data.log_path).await {
             Ok(log) => Some(log),
             Err(err) => panic!("Failed to initialize asset processor log. This cannot be recovered. Try restarting. If that doesn't work, try deleting processed asset folder. {}", err),
         };
@@ -1024,9 +1355,14 @@ impl AssetProcessorData {
             initialized_receiver,
             state: async_lock::RwLock::new(ProcessorState::Initializing),
             log: Default::default(),
+            log_path: ProcessorTransactionLog::default_path(),
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            validators: Default::default(),
+            cache: Default::default(),
+            #[cfg(feature = "pak")]
+            pak_outputs: Default::default(),
         }
     }
 
@@ -1124,6 +1460,22 @@ pub enum ProcessResult {
     Ignored,
 }
 
+/// The assets processed by an [`AssetProcessor`], returned by [`AssetProcessor::summary`].
+#[derive(Debug, Clone, Default)]
+pub struct ProcessSummary {
+    /// The assets that were processed successfully, including the ones that were already up to date.
+    pub processed: Vec<AssetPath<'static>>,
+    /// The assets that failed to process. Their errors are logged when they fail.
+    pub failed: Vec<AssetPath<'static>>,
+}
+
+impl ProcessSummary {
+    /// Returns `true` if no asset failed to process.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
 /// The final status of processing an asset
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ProcessStatus {
@@ -1412,6 +1764,20 @@ pub enum ProcessorState {
     Finished,
 }
 
+/// An error that occurs when bundling processed assets into a pak archive.
+#[cfg(feature = "pak")]
+#[derive(Error, Display, Debug, derive_more::derive::From)]
+pub enum WritePakError {
+    #[display("{_0}")]
+    MissingAssetSource(MissingAssetSourceError),
+    #[display("{_0}")]
+    MissingProcessedAssetReader(crate::io::MissingProcessedAssetReaderError),
+    #[display("Failed to read a processed asset: {_0}")]
+    AssetReaderError(AssetReaderError),
+    #[display("Failed to write the pak archive: {_0}")]
+    Io(std::io::Error),
+}
+
 /// An error that occurs when initializing the [`AssetProcessor`].
 #[derive(Error, Display, Debug)]
 pub enum InitializeError {
@@ -1420,3 +1786,127 @@ pub enum InitializeError {
     #[display("Failed to validate asset log: {_0}")]
     ValidateLogError(ValidateLogError),
 }
+
+#[cfg(all(
+    test,
+    feature = "pak",
+    feature = "multi_threaded",
+    not(target_arch = "wasm32")
+))]
+mod tests {
+    use super::*;
+    use crate as bevy_asset;
+    use crate::{
+        io::{
+            file::{FileAssetReader, FileAssetWriter},
+            pak::{PakArchive, PakAssetReader, PakOutput},
+        },
+        Asset, AssetLoader, LoadContext,
+    };
+    use bevy_reflect::TypePath;
+    use bevy_tasks::block_on;
+
+    #[derive(Asset, TypePath)]
+    struct Text(#[expect(dead_code, reason = "only the processed bytes are checked")] String);
+
+    struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        type Asset = Text;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            reader: &mut dyn crate::io::Reader,
+            _settings: &(),
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Text, Self::Error> {
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(Text(text))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[test]
+    fn bundles_processed_assets_into_pak() {
+        IoTaskPool::get_or_init(Default::default);
+        let root = std::env::temp_dir().join(format!(
+            "bevy_processor_pak_{}_{}",
+            std::process::id(),
+            uuid::Uuid::new_v4()
+        ));
+        let source_path = root.join("assets");
+        let processed_path = root.join("processed");
+        std::fs::create_dir_all(source_path.join("dir")).unwrap();
+        std::fs::write(source_path.join("a.txt"), "a").unwrap();
+        std::fs::write(source_path.join("dir/b.txt"), "b").unwrap();
+
+        let mut sources = AssetSourceBuilders::default();
+        let source_writer_path = source_path.clone();
+        let processed_reader_path = processed_path.clone();
+        let processed_writer_path = processed_path.clone();
+        sources.insert(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(FileAssetReader::new(&source_path)))
+                .with_writer(move |create_root| {
+                    Some(Box::new(FileAssetWriter::new(
+                        &source_writer_path,
+                        create_root,
+                    )))
+                })
+                .with_processed_reader(move || {
+                    Box::new(FileAssetReader::new(&processed_reader_path))
+                })
+                .with_processed_writer(move |create_root| {
+                    Some(Box::new(FileAssetWriter::new(
+                        &processed_writer_path,
+                        create_root,
+                    )))
+                }),
+        );
+        let processor = AssetProcessor::with_log_path(&mut sources, root.join("log"));
+        processor.server().register_loader(TextLoader);
+        let pak_path = root.join("assets.pak");
+        processor.add_pak_output(AssetSourceId::Default, PakOutput::new(&pak_path));
+        processor.process_assets();
+
+        let reader = PakAssetReader::new(PakArchive::open(&pak_path).unwrap());
+        block_on(async {
+            let infos = processor.data.asset_infos.read().await;
+            for (path, text) in [("a.txt", "a"), ("dir/b.txt", "b")] {
+                let full_hash = infos
+                    .get(&AssetPath::from(path))
+                    .and_then(|info| info.processed_info.as_ref())
+                    .unwrap()
+                    .full_hash;
+                assert_eq!(
+                    reader.archive().asset_entry(Path::new(path)).unwrap().hash,
+                    full_hash
+                );
+
+                let mut bytes = Vec::new();
+                reader
+                    .read(Path::new(path))
+                    .await
+                    .unwrap()
+                    .read_to_end(&mut bytes)
+                    .await
+                    .unwrap();
+                assert_eq!(bytes, text.as_bytes());
+                let meta = reader.read_meta_bytes(Path::new(path)).await.unwrap();
+                let meta: AssetMetaMinimal = ron::de::from_bytes(&meta).unwrap();
+                assert!(matches!(meta.asset, AssetActionMinimal::Load { .. }));
+            }
+        });
+
+        assert!(root.join("log").exists());
+        assert!(!root.join("assets.pak.tmp").exists());
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -4,10 +4,10 @@ use crate::{
         MissingProcessedAssetReaderError, MissingProcessedAssetWriterError, SliceReader, Writer,
     },
     meta::{AssetAction, AssetMeta, AssetMetaDyn, ProcessDependencyInfo, ProcessedInfo, Settings},
-    processor::AssetProcessor,
+    processor::{AssetProcessor, ValidationIssue, ValidationSeverity},
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
-    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
+    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset, LoadPriority,
     MissingAssetLoaderForExtensionError, MissingAssetLoaderForTypeNameError,
 };
 use bevy_utils::{BoxedFuture, ConditionalSendFuture};
@@ -25,6 +25,9 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of the processing logic. Bump it when a change to the processor changes its output, so that assets
+    /// it processed before are not reused from a [`ProcessCache`](crate::processor::ProcessCache).
+    const VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -167,6 +170,20 @@ pub enum ProcessError {
     AssetTransformError(Box<dyn core::error::Error + Send + Sync + 'static>),
     #[display("Assets without extensions are not supported.")]
     ExtensionRequired,
+    #[display(
+        "Asset '{path}' failed validation: {}",
+        issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>()
+            .join("; ")
+    )]
+    #[from(ignore)]
+    ValidationFailed {
+        path: AssetPath<'static>,
+        issues: Vec<ValidationIssue>,
+    },
 }
 
 impl<Loader, Transformer, Saver> Process for LoadTransformAndSave<Loader, Transformer, Saver>
@@ -230,6 +247,8 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`Process::VERSION`] of the underlying [`Process`] impl.
+    fn version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -264,6 +283,10 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        P::VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -290,6 +313,8 @@ pub struct ProcessContext<'a> {
     processor: &'a AssetProcessor,
     path: &'a AssetPath<'static>,
     asset_bytes: &'a [u8],
+    /// The issues reported by the [`AssetValidator`](crate::processor::AssetValidator)s of the loaded source assets.
+    pub(crate) validation_issues: Vec<ValidationIssue>,
 }
 
 impl<'a> ProcessContext<'a> {
@@ -304,6 +329,7 @@ impl<'a> ProcessContext<'a> {
             path,
             asset_bytes,
             new_processed_info,
+            validation_issues: Vec::new(),
         }
     }
 
@@ -327,6 +353,7 @@ impl<'a> ProcessContext<'a> {
                 &mut reader,
                 false,
                 true,
+                LoadPriority::NORMAL,
             )
             .await?;
         for (path, full_hash) in &loaded_asset.loader_dependencies {
@@ -337,6 +364,8 @@ impl<'a> ProcessContext<'a> {
                     path: path.to_owned(),
                 });
         }
+        let issues = self.processor.validate_asset(self.path, &loaded_asset);
+        self.validation_issues.extend(issues);
         Ok(loaded_asset)
     }
 
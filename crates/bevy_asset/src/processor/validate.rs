@@ -0,0 +1,250 @@
+use crate::{processor::ProcessError, Asset, AssetPath, ErasedLoadedAsset};
+use core::fmt;
+
+/// Checks the assets of type [`AssetValidator::Asset`] loaded by the [`AssetProcessor`], to catch broken assets at
+/// import time, such as textures that are too large or meshes missing normals.
+///
+/// Validators are registered with [`AssetProcessor::register_validator`] or
+/// [`AssetApp::register_asset_validator`](crate::AssetApp::register_asset_validator). They run on every asset of their
+/// type loaded while processing, including labeled assets: the source assets of processed assets loaded with
+/// [`ProcessContext::load_source_asset`](crate::processor::ProcessContext::load_source_asset), and the assets that are
+/// only loaded (and not processed) by their loader.
+///
+/// Issues are logged with the path of the offending asset. If any [error](ValidationContext::error) is reported, the
+/// asset fails processing with [`ProcessError::ValidationFailed`](crate::processor::ProcessError::ValidationFailed),
+/// while [warnings](ValidationContext::warn) are only logged.
+///
+/// ```
+/// # use bevy_asset::{Asset, processor::{AssetValidator, ValidationContext}};
+/// # use bevy_reflect::TypePath;
+/// #[derive(Asset, TypePath)]
+/// struct Texture {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// struct MaxTextureSize(u32);
+///
+/// impl AssetValidator for MaxTextureSize {
+///     type Asset = Texture;
+///
+///     fn validate(&self, texture: &Texture, context: &mut ValidationContext) {
+///         if texture.width > self.0 || texture.height > self.0 {
+///             context.error(format!(
+///                 "{}x{} is larger than the maximum texture size {}",
+///                 texture.width, texture.height, self.0
+///             ));
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`AssetProcessor`]: crate::processor::AssetProcessor
+/// [`AssetProcessor::register_validator`]: crate::processor::AssetProcessor::register_validator
+pub trait AssetValidator: Send + Sync + 'static {
+    /// The type of the assets validated by this validator.
+    type Asset: Asset;
+
+    /// Checks `asset`, reporting its issues to `context`.
+    fn validate(&self, asset: &Self::Asset, context: &mut ValidationContext);
+}
+
+/// Collects the issues reported by an [`AssetValidator`] for an asset.
+pub struct ValidationContext<'a> {
+    path: &'a AssetPath<'static>,
+    validator: &'static str,
+    issues: &'a mut Vec<ValidationIssue>,
+}
+
+impl ValidationContext<'_> {
+    /// The path of the asset being validated.
+    pub fn path(&self) -> &AssetPath<'static> {
+        self.path
+    }
+
+    /// Reports an issue that doesn't prevent the asset from being processed.
+    pub fn warn(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Warning, message.into());
+    }
+
+    /// Reports an issue that fails the processing of the asset.
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.report(ValidationSeverity::Error, message.into());
+    }
+
+    fn report(&mut self, severity: ValidationSeverity, message: String) {
+        self.issues.push(ValidationIssue {
+            path: self.path.clone(),
+            validator: self.validator,
+            severity,
+            message,
+        });
+    }
+}
+
+/// The severity of a [`ValidationIssue`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationSeverity {
+    /// The issue is logged as a warning.
+    Warning,
+    /// The issue is logged as an error, and fails the processing of the asset.
+    Error,
+}
+
+/// An issue reported by an [`AssetValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// The path of the offending asset, with its label for labeled assets.
+    pub path: AssetPath<'static>,
+    /// The type name of the validator that reported the issue.
+    pub validator: &'static str,
+    /// The severity of the issue.
+    pub severity: ValidationSeverity,
+    /// A description of the issue.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}': {} ({})", self.path, self.message, self.validator)
+    }
+}
+
+/// Returns [`ProcessError::ValidationFailed`] if an [`AssetValidator`] reported an error in `issues`.
+#[expect(
+    clippy::result_large_err,
+    reason = "Returns the `ProcessError` of the processing functions it is called from."
+)]
+pub(crate) fn fail_on_validation_errors(
+    path: &AssetPath<'static>,
+    issues: Vec<ValidationIssue>,
+) -> Result<(), ProcessError> {
+    if issues
+        .iter()
+        .any(|issue| issue.severity == ValidationSeverity::Error)
+    {
+        Err(ProcessError::ValidationFailed {
+            path: path.clone(),
+            issues,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// A type-erased [`AssetValidator`].
+pub(crate) trait ErasedAssetValidator: Send + Sync + 'static {
+    /// The type name of the validator.
+    fn name(&self) -> &'static str;
+
+    fn validate(
+        &self,
+        asset: &ErasedLoadedAsset,
+        path: &AssetPath<'static>,
+        issues: &mut Vec<ValidationIssue>,
+    );
+}
+
+impl<V: AssetValidator> ErasedAssetValidator for V {
+    fn name(&self) -> &'static str {
+        core::any::type_name::<V>()
+    }
+
+    fn validate(
+        &self,
+        asset: &ErasedLoadedAsset,
+        path: &AssetPath<'static>,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if let Some(asset) = asset.get::<V::Asset>() {
+            let mut context = ValidationContext {
+                path,
+                validator: self.name(),
+                issues,
+            };
+            AssetValidator::validate(self, asset, &mut context);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        self as bevy_asset,
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceBuilders, AssetSourceId,
+        },
+        loader::{LabeledAsset, LoadedAsset},
+        processor::AssetProcessor,
+        Handle,
+    };
+    use bevy_reflect::TypePath;
+
+    #[derive(Asset, TypePath)]
+    struct Mesh {
+        normals: Vec<[f32; 3]>,
+    }
+
+    #[derive(Asset, TypePath)]
+    struct Scene {
+        #[dependency]
+        meshes: Vec<Handle<Mesh>>,
+    }
+
+    struct RequireNormals;
+
+    impl AssetValidator for RequireNormals {
+        type Asset = Mesh;
+
+        fn validate(&self, mesh: &Mesh, context: &mut ValidationContext) {
+            if mesh.normals.is_empty() {
+                context.error("missing normals");
+            }
+        }
+    }
+
+    #[test]
+    fn validate_labeled_assets() {
+        let mut sources = AssetSourceBuilders::default();
+        sources.insert(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(|| {
+                Box::new(MemoryAssetReader {
+                    root: Dir::default(),
+                })
+            }),
+        );
+        let processor = AssetProcessor::new(&mut sources);
+        processor.register_validator(RequireNormals);
+
+        let mut scene = ErasedLoadedAsset::from(LoadedAsset::from(Scene { meshes: Vec::new() }));
+        for (label, normals) in [("Mesh0", vec![[0.0, 1.0, 0.0]]), ("Mesh1", Vec::new())] {
+            scene.labeled_assets.insert(
+                label.into(),
+                LabeledAsset {
+                    asset: LoadedAsset::from(Mesh { normals }).into(),
+                    handle: Handle::<Mesh>::default().untyped(),
+                },
+            );
+        }
+
+        let path = AssetPath::from("scene.gltf");
+        let issues = processor.validate_asset(&path, &scene);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].path, AssetPath::from("scene.gltf#Mesh1"));
+        assert_eq!(issues[0].severity, ValidationSeverity::Error);
+        assert_eq!(
+            issues[0].validator,
+            core::any::type_name::<RequireNormals>()
+        );
+
+        let error = fail_on_validation_errors(&path, issues).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Asset 'scene.gltf' failed validation: missing normals"
+        );
+        assert!(fail_on_validation_errors(&path, Vec::new()).is_ok());
+    }
+}
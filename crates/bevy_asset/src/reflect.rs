@@ -3,7 +3,7 @@ use core::any::{Any, TypeId};
 use bevy_ecs::world::{unsafe_world_cell::UnsafeWorldCell, World};
 use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect};
 
-use crate::{Asset, AssetId, Assets, Handle, UntypedAssetId, UntypedHandle};
+use crate::{Asset, AssetId, Assets, Handle, LoadContext, UntypedAssetId, UntypedHandle};
 
 /// Type data for the [`TypeRegistry`](bevy_reflect::TypeRegistry) used to operate on reflected [`Asset`]s.
 ///
@@ -23,6 +23,7 @@ pub struct ReflectAsset {
     // - may only be used to access **at most one** access at once
     get_unchecked_mut: unsafe fn(UnsafeWorldCell<'_>, UntypedHandle) -> Option<&mut dyn Reflect>,
     add: fn(&mut World, &dyn PartialReflect) -> UntypedHandle,
+    add_labeled: fn(&mut LoadContext, String, &dyn PartialReflect) -> Option<UntypedHandle>,
     insert: fn(&mut World, UntypedHandle, &dyn PartialReflect),
     len: fn(&World) -> usize,
     ids: for<'w> fn(&'w World) -> Box<dyn Iterator<Item = UntypedAssetId> + 'w>,
@@ -105,6 +106,17 @@ impl ReflectAsset {
     pub fn add(&self, world: &mut World, value: &dyn PartialReflect) -> UntypedHandle {
         (self.add)(world, value)
     }
+    /// Equivalent of [`LoadContext::add_labeled_asset`]
+    ///
+    /// Returns `None` if `value` can't be converted to the asset type with [`FromReflect`].
+    pub fn add_labeled(
+        &self,
+        load_context: &mut LoadContext,
+        label: String,
+        value: &dyn PartialReflect,
+    ) -> Option<UntypedHandle> {
+        (self.add_labeled)(load_context, label, value)
+    }
     /// Equivalent of [`Assets::insert`]
     pub fn insert(&self, world: &mut World, handle: UntypedHandle, value: &dyn PartialReflect) {
         (self.insert)(world, handle, value);
@@ -155,6 +167,10 @@ impl<A: Asset + FromReflect> FromType<A> for ReflectAsset {
                     .expect("could not call `FromReflect::from_reflect` in `ReflectAsset::add`");
                 assets.add(value).untyped()
             },
+            add_labeled: |load_context, label, value| {
+                let value: A = FromReflect::from_reflect(value)?;
+                Some(load_context.add_labeled_asset(label, value).untyped())
+            },
             insert: |world, handle, value| {
                 let mut assets = world.resource_mut::<Assets<A>>();
                 let value: A = FromReflect::from_reflect(value)
@@ -246,10 +262,13 @@ mod tests {
     use core::any::TypeId;
 
     use crate as bevy_asset;
-    use crate::{Asset, AssetApp, AssetPlugin, ReflectAsset, UntypedHandle};
+    use crate::{
+        Asset, AssetApp, AssetPlugin, AssetServer, LoadContext, LoadPriority, ReflectAsset,
+        UntypedHandle,
+    };
     use bevy_app::App;
     use bevy_ecs::reflect::AppTypeRegistry;
-    use bevy_reflect::Reflect;
+    use bevy_reflect::{DynamicStruct, Reflect};
 
     #[derive(Asset, Reflect)]
     struct AssetType {
@@ -305,4 +324,46 @@ mod tests {
             .unwrap();
         assert_eq!(reflect_asset.len(app.world()), 0);
     }
+
+    #[test]
+    fn add_labeled_rejects_values_of_the_wrong_shape() {
+        let mut app = App::new();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<AssetType>()
+            .register_asset_reflect::<AssetType>();
+
+        let reflect_asset = {
+            let type_registry = app.world().resource::<AppTypeRegistry>();
+            let type_registry = type_registry.read();
+
+            type_registry
+                .get_type_data::<ReflectAsset>(TypeId::of::<AssetType>())
+                .unwrap()
+                .clone()
+        };
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let mut load_context = LoadContext::new(
+            &asset_server,
+            "scene.scn.ron".into(),
+            false,
+            false,
+            LoadPriority::default(),
+        );
+
+        let mut wrong_shape = DynamicStruct::default();
+        wrong_shape.insert("field", 42_u32);
+        assert!(reflect_asset
+            .add_labeled(&mut load_context, "Wrong".into(), &wrong_shape)
+            .is_none());
+        assert!(!load_context.has_labeled_asset("Wrong"));
+
+        let value = AssetType {
+            field: "test".into(),
+        };
+        assert!(reflect_asset
+            .add_labeled(&mut load_context, "Right".into(), &value)
+            .is_some());
+        assert!(load_context.has_labeled_asset("Right"));
+    }
 }
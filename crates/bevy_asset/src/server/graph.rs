@@ -0,0 +1,293 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::collections::BTreeMap;
+use bevy_utils::HashSet;
+use core::fmt::Write;
+
+/// An asset tracked by the [`AssetServer`](crate::AssetServer), in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug)]
+pub struct AssetDependencyNode {
+    /// The id of the asset.
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it was loaded from a path.
+    pub path: Option<AssetPath<'static>>,
+    /// The load state of the asset.
+    pub load_state: LoadState,
+    /// The load state of the direct dependencies of the asset.
+    pub dependency_load_state: DependencyLoadState,
+    /// The load state of all the dependencies of the asset, recursively.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The assets this asset depends on, known once it is loaded.
+    pub dependencies: Vec<UntypedAssetId>,
+    /// The paths of the assets read by the loader of this asset, such as the files embedded in it.
+    ///
+    /// These are only tracked when the [`AssetServer`](crate::AssetServer) is watching for changes.
+    pub loader_dependencies: Vec<AssetPath<'static>>,
+}
+
+/// A snapshot of the dependencies between the assets tracked by the [`AssetServer`](crate::AssetServer),
+/// returned by [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph).
+///
+/// This can be used to find out why an asset is stuck loading or failed to load, with
+/// [`AssetDependencyGraph::pending_leaves`] and [`AssetDependencyGraph::failed_leaves`], or dumped with
+/// [`AssetDependencyGraph::to_dot`] and [`AssetDependencyGraph::to_json`] to inspect it with other tools.
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    nodes: BTreeMap<UntypedAssetId, AssetDependencyNode>,
+}
+
+impl AssetDependencyGraph {
+    pub(crate) fn new(nodes: impl IntoIterator<Item = AssetDependencyNode>) -> Self {
+        Self {
+            nodes: nodes.into_iter().map(|node| (node.id, node)).collect(),
+        }
+    }
+
+    /// Returns the node of the asset `id`, if it is tracked.
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetDependencyNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Returns an iterator over the nodes of every tracked asset.
+    pub fn iter(&self) -> impl Iterator<Item = &AssetDependencyNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of tracked assets.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if no asset is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the ids of the assets `id` depends on.
+    pub fn dependencies(&self, id: impl Into<UntypedAssetId>) -> &[UntypedAssetId] {
+        self.get(id)
+            .map(|node| node.dependencies.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns the nodes of the assets depending on `id`.
+    pub fn dependents(
+        &self,
+        id: impl Into<UntypedAssetId>,
+    ) -> impl Iterator<Item = &AssetDependencyNode> {
+        let id = id.into();
+        self.nodes
+            .values()
+            .filter(move |node| node.dependencies.contains(&id))
+    }
+
+    /// Returns the ids of the assets `id` depends on, directly or through other dependencies.
+    pub fn recursive_dependencies(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let id = id.into();
+        let mut visited = HashSet::new();
+        let mut dependencies = Vec::new();
+        let mut stack = vec![id];
+        while let Some(current) = stack.pop() {
+            for &dependency in self.dependencies(current).iter().rev() {
+                if dependency != id && visited.insert(dependency) {
+                    dependencies.push(dependency);
+                    stack.push(dependency);
+                }
+            }
+        }
+        dependencies
+    }
+
+    /// Returns the ids of `id` and of its recursive dependencies that haven't loaded yet, or aren't tracked at all.
+    ///
+    /// These are the assets an asset stuck in [`RecursiveDependencyLoadState::Loading`] is waiting for.
+    pub fn pending_leaves(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let id = id.into();
+        core::iter::once(id)
+            .chain(self.recursive_dependencies(id))
+            .filter(|id| {
+                self.get(*id).map_or(true, |node| {
+                    matches!(node.load_state, LoadState::NotLoaded | LoadState::Loading)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the ids of `id` and of its recursive dependencies that failed to load.
+    ///
+    /// These are the assets that caused an asset to be in [`RecursiveDependencyLoadState::Failed`].
+    pub fn failed_leaves(&self, id: impl Into<UntypedAssetId>) -> Vec<UntypedAssetId> {
+        let id = id.into();
+        core::iter::once(id)
+            .chain(self.recursive_dependencies(id))
+            .filter(|id| {
+                self.get(*id)
+                    .is_some_and(|node| node.load_state.is_failed())
+            })
+            .collect()
+    }
+
+    /// Returns the graph in the [DOT](https://graphviz.org/doc/info/lang.html) format of Graphviz, with an
+    /// edge from every asset to each of its dependencies.
+    ///
+    /// Assets are colored by load state: loaded assets are green, loading assets are yellow and failed assets are red.
+    /// Loaded assets with failed dependencies are orange.
+    /// Loader dependencies are drawn as dashed edges to their paths.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph assets {\n    node [shape=box, style=filled];\n");
+        let mut loader_dependencies = BTreeMap::new();
+        for (index, node) in self.nodes.values().enumerate() {
+            let color = match node.load_state {
+                LoadState::NotLoaded => "white",
+                LoadState::Loading => "yellow",
+                LoadState::Loaded if node.recursive_dependency_load_state.is_failed() => "orange",
+                LoadState::Loaded => "palegreen",
+                LoadState::Failed(_) => "red",
+            };
+            let _ = writeln!(
+                dot,
+                "    n{index} [label={}, fillcolor={color}];",
+                dot_string(&node_label(node)),
+            );
+            for path in &node.loader_dependencies {
+                let count = loader_dependencies.len();
+                loader_dependencies.entry(path.to_string()).or_insert(count);
+            }
+        }
+        for (path, index) in &loader_dependencies {
+            let _ = writeln!(
+                dot,
+                "    l{index} [label={}, style=dashed];",
+                dot_string(path)
+            );
+        }
+        let indices = self
+            .nodes
+            .keys()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect::<BTreeMap<_, _>>();
+        for (index, node) in self.nodes.values().enumerate() {
+            for dependency in &node.dependencies {
+                match indices.get(dependency) {
+                    Some(dependency) => {
+                        let _ = writeln!(dot, "    n{index} -> n{dependency};");
+                    }
+                    None => {
+                        let _ = writeln!(
+                            dot,
+                            "    n{index} -> {} [color=red];",
+                            dot_string(&dependency.to_string())
+                        );
+                    }
+                }
+            }
+            for path in &node.loader_dependencies {
+                let _ = writeln!(
+                    dot,
+                    "    n{index} -> l{} [style=dashed];",
+                    loader_dependencies[&path.to_string()]
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns the graph as JSON: an `assets` array with the id, path, load states, dependency ids and loader
+    /// dependency paths of every asset. Ids are the [`Display`](core::fmt::Display) output of [`UntypedAssetId`].
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n  \"assets\": [");
+        for (index, node) in self.nodes.values().enumerate() {
+            let separator = if index == 0 { "" } else { "," };
+            let path = match &node.path {
+                Some(path) => json_string(&path.to_string()),
+                None => "null".to_string(),
+            };
+            let load_state = state_json(match &node.load_state {
+                LoadState::NotLoaded => ("NotLoaded", None),
+                LoadState::Loading => ("Loading", None),
+                LoadState::Loaded => ("Loaded", None),
+                LoadState::Failed(error) => ("Failed", Some(error.to_string())),
+            });
+            let dependency_load_state = state_json(match &node.dependency_load_state {
+                DependencyLoadState::NotLoaded => ("NotLoaded", None),
+                DependencyLoadState::Loading => ("Loading", None),
+                DependencyLoadState::Loaded => ("Loaded", None),
+                DependencyLoadState::Failed(error) => ("Failed", Some(error.to_string())),
+            });
+            let recursive_dependency_load_state =
+                state_json(match &node.recursive_dependency_load_state {
+                    RecursiveDependencyLoadState::NotLoaded => ("NotLoaded", None),
+                    RecursiveDependencyLoadState::Loading => ("Loading", None),
+                    RecursiveDependencyLoadState::Loaded => ("Loaded", None),
+                    RecursiveDependencyLoadState::Failed(error) => {
+                        ("Failed", Some(error.to_string()))
+                    }
+                });
+            let dependencies = json_array(node.dependencies.iter().map(ToString::to_string));
+            let loader_dependencies =
+                json_array(node.loader_dependencies.iter().map(ToString::to_string));
+            let _ = write!(
+                json,
+                "{separator}\n    {{\"id\": {}, \"path\": {path}, \"load_state\": {load_state}, \
+                \"dependency_load_state\": {dependency_load_state}, \
+                \"recursive_dependency_load_state\": {recursive_dependency_load_state}, \
+                \"dependencies\": {dependencies}, \"loader_dependencies\": {loader_dependencies}}}",
+                json_string(&node.id.to_string()),
+            );
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+fn node_label(node: &AssetDependencyNode) -> String {
+    match &node.path {
+        Some(path) => path.to_string(),
+        None => node.id.to_string(),
+    }
+}
+
+fn state_json((state, error): (&str, Option<String>)) -> String {
+    match error {
+        Some(error) => format!(
+            "{{\"state\": \"{state}\", \"error\": {}}}",
+            json_string(&error)
+        ),
+        None => format!("{{\"state\": \"{state}\"}}"),
+    }
+}
+
+/// Formats `values` as a JSON array of strings.
+fn json_array(values: impl Iterator<Item = String>) -> String {
+    let values = values.map(|value| json_string(&value)).collect::<Vec<_>>();
+    format!("[{}]", values.join(", "))
+}
+
+/// Quotes `value` as a JSON string.
+fn json_string(value: &str) -> String {
+    let mut string = String::with_capacity(value.len() + 2);
+    string.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(string, "\\u{:04x}", char as u32);
+            }
+            char => string.push(char),
+        }
+    }
+    string.push('"');
+    string
+}
+
+/// Quotes `value` as a DOT string.
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
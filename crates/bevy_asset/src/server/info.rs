@@ -1,5 +1,6 @@
 use crate::{
     meta::{AssetHash, MetaTransform},
+    server::{AssetDependencyGraph, AssetDependencyNode},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
     UntypedAssetId, UntypedHandle,
@@ -20,6 +21,8 @@ pub(crate) struct AssetInfo {
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
     pub(crate) rec_dep_load_state: RecursiveDependencyLoadState,
+    /// All the dependencies of the asset, set when it is loaded.
+    dependencies: HashSet<UntypedAssetId>,
     loading_dependencies: HashSet<UntypedAssetId>,
     failed_dependencies: HashSet<UntypedAssetId>,
     loading_rec_dependencies: HashSet<UntypedAssetId>,
@@ -48,6 +51,7 @@ impl AssetInfo {
             load_state: LoadState::NotLoaded,
             dep_load_state: DependencyLoadState::NotLoaded,
             rec_dep_load_state: RecursiveDependencyLoadState::NotLoaded,
+            dependencies: HashSet::default(),
             loading_dependencies: HashSet::default(),
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
@@ -59,6 +63,11 @@ impl AssetInfo {
             waiting_tasks: Vec::new(),
         }
     }
+
+    /// Returns `true` if a strong handle keeps the asset alive.
+    pub(crate) fn has_strong_handle(&self) -> bool {
+        self.weak_handle.strong_count() > 0
+    }
 }
 
 #[derive(Default)]
@@ -380,6 +389,26 @@ impl AssetInfos {
         )
     }
 
+    /// Returns a snapshot of the dependencies between all the tracked assets.
+    pub(crate) fn dependency_graph(&self) -> AssetDependencyGraph {
+        AssetDependencyGraph::new(self.infos.iter().map(|(id, info)| {
+            let mut dependencies = info.dependencies.iter().copied().collect::<Vec<_>>();
+            dependencies.sort();
+            let mut loader_dependencies =
+                info.loader_dependencies.keys().cloned().collect::<Vec<_>>();
+            loader_dependencies.sort_by_cached_key(ToString::to_string);
+            AssetDependencyNode {
+                id: *id,
+                path: info.path.clone(),
+                load_state: info.load_state.clone(),
+                dependency_load_state: info.dep_load_state.clone(),
+                recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                dependencies,
+                loader_dependencies,
+            }
+        }))
+    }
+
     /// Updates [`AssetInfo`] / load state for an asset that has finished loading (and relevant dependencies / dependents).
     pub(crate) fn process_asset_load(
         &mut self,
@@ -394,6 +423,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = HashSet::new();
         let mut dep_error = None;
@@ -490,6 +520,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
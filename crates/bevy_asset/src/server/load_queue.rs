@@ -0,0 +1,270 @@
+use crate::UntypedAssetId;
+use bevy_utils::{HashMap, HashSet};
+use core::{
+    cmp::Reverse,
+    future::poll_fn,
+    task::{Poll, Waker},
+};
+use parking_lot::Mutex;
+
+/// The priority of an asset load, used to choose which loads start first when the number of
+/// concurrent loads is [limited](crate::AssetServer::set_max_concurrent_loads).
+///
+/// Loads with a higher priority start before loads with a lower priority, and loads with the same
+/// priority start in the order they were requested. For example, an open world game can load
+/// the textures of its UI with [`LoadPriority::HIGH`] and prefetch the terrain tiles around the
+/// player with [`LoadPriority::LOW`], so that background loads don't delay critical ones.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LoadPriority(pub i32);
+
+impl LoadPriority {
+    /// The priority of loads that aren't needed yet, such as prefetched assets.
+    pub const LOW: Self = Self(-100);
+    /// The priority of loads requested without a priority.
+    pub const NORMAL: Self = Self(0);
+    /// The priority of loads that are needed right away, such as visible assets.
+    pub const HIGH: Self = Self(100);
+}
+
+/// The key of a load waiting for a free slot, ordering loads by priority, then by request order.
+type QueueKey = (Reverse<LoadPriority>, u64);
+
+#[derive(Default)]
+struct LoadQueueState {
+    max_concurrent_loads: Option<usize>,
+    active_loads: usize,
+    next_sequence: u64,
+    waiting: alloc::collections::BTreeMap<QueueKey, (UntypedAssetId, Waker)>,
+    waiting_ids: HashMap<UntypedAssetId, QueueKey>,
+    granted: HashSet<u64>,
+}
+
+impl LoadQueueState {
+    /// Returns the key of the waiting load requested with `sequence`, which changes with its priority.
+    fn waiting_key(&self, id: UntypedAssetId, sequence: u64) -> Option<QueueKey> {
+        self.waiting_ids
+            .get(&id)
+            .copied()
+            .filter(|key| key.1 == sequence)
+    }
+
+    fn has_free_slot(&self) -> bool {
+        self.max_concurrent_loads
+            .map_or(true, |max| self.active_loads < max)
+    }
+
+    /// Starts the waiting loads with the highest priority while there are free slots.
+    fn start_waiting_loads(&mut self) {
+        while self.has_free_slot() {
+            let Some((key, (id, waker))) = self.waiting.pop_first() else {
+                break;
+            };
+            self.waiting_ids.remove(&id);
+            self.active_loads += 1;
+            self.granted.insert(key.1);
+            waker.wake();
+        }
+    }
+}
+
+/// Limits the number of asset loads running at the same time, starting waiting loads by priority.
+#[derive(Default)]
+pub(crate) struct LoadQueue {
+    state: Mutex<LoadQueueState>,
+}
+
+impl LoadQueue {
+    pub(crate) fn max_concurrent_loads(&self) -> Option<usize> {
+        self.state.lock().max_concurrent_loads
+    }
+
+    pub(crate) fn set_max_concurrent_loads(&self, max: Option<usize>) {
+        let mut state = self.state.lock();
+        state.max_concurrent_loads = max;
+        state.start_waiting_loads();
+    }
+
+    /// Returns the priority of the load of `id` if it is waiting for a free slot.
+    pub(crate) fn priority(&self, id: UntypedAssetId) -> Option<LoadPriority> {
+        self.state.lock().waiting_ids.get(&id).map(|key| key.0 .0)
+    }
+
+    /// Changes the priority of the load of `id` if it is waiting for a free slot.
+    pub(crate) fn set_priority(&self, id: UntypedAssetId, priority: LoadPriority) -> bool {
+        self.update_priority(id, |_| priority)
+    }
+
+    /// Raises the priority of the load of `id` to `priority` if it is waiting for a free slot
+    /// with a lower priority.
+    pub(crate) fn raise_priority(&self, id: UntypedAssetId, priority: LoadPriority) {
+        self.update_priority(id, |current| current.max(priority));
+    }
+
+    fn update_priority(
+        &self,
+        id: UntypedAssetId,
+        update: impl FnOnce(LoadPriority) -> LoadPriority,
+    ) -> bool {
+        let mut state = self.state.lock();
+        let Some(&key) = state.waiting_ids.get(&id) else {
+            return false;
+        };
+        let priority = update(key.0 .0);
+        let waiter = state.waiting.remove(&key).unwrap();
+        let new_key = (Reverse(priority), key.1);
+        state.waiting.insert(new_key, waiter);
+        state.waiting_ids.insert(id, new_key);
+        true
+    }
+
+    /// Waits for a free slot to load the asset `id`.
+    ///
+    /// If the returned future is dropped before completing, for example because the load task was
+    /// cancelled, the load leaves the queue.
+    pub(crate) async fn acquire(
+        &self,
+        id: UntypedAssetId,
+        priority: LoadPriority,
+    ) -> LoadPermit<'_> {
+        let mut ticket = Ticket {
+            queue: self,
+            id,
+            sequence: None,
+            done: false,
+        };
+        poll_fn(|cx| {
+            let mut state = self.state.lock();
+            let ready = match ticket.sequence {
+                None if state.waiting.is_empty() && state.has_free_slot() => {
+                    state.active_loads += 1;
+                    true
+                }
+                None => {
+                    let key = (Reverse(priority), state.next_sequence);
+                    state.next_sequence += 1;
+                    state.waiting.insert(key, (id, cx.waker().clone()));
+                    state.waiting_ids.insert(id, key);
+                    ticket.sequence = Some(key.1);
+                    false
+                }
+                Some(sequence) => {
+                    if state.granted.remove(&sequence) {
+                        true
+                    } else {
+                        if let Some(key) = state.waiting_key(id, sequence) {
+                            if let Some((_, waker)) = state.waiting.get_mut(&key) {
+                                waker.clone_from(cx.waker());
+                            }
+                        }
+                        false
+                    }
+                }
+            };
+            if ready {
+                ticket.done = true;
+                Poll::Ready(LoadPermit { queue: self })
+            } else {
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
+
+/// Removes a load from the [`LoadQueue`] if it is cancelled while waiting.
+struct Ticket<'a> {
+    queue: &'a LoadQueue,
+    id: UntypedAssetId,
+    sequence: Option<u64>,
+    done: bool,
+}
+
+impl Drop for Ticket<'_> {
+    fn drop(&mut self) {
+        let (Some(sequence), false) = (self.sequence, self.done) else {
+            return;
+        };
+        let mut state = self.queue.state.lock();
+        if let Some(key) = state.waiting_key(self.id, sequence) {
+            state.waiting.remove(&key);
+            state.waiting_ids.remove(&self.id);
+        } else if state.granted.remove(&sequence) {
+            // The load was started but cancelled before it could run: give its slot to the next one
+            state.active_loads -= 1;
+            state.start_waiting_loads();
+        }
+    }
+}
+
+/// A slot to run an asset load, freed when dropped.
+pub(crate) struct LoadPermit<'a> {
+    queue: &'a LoadQueue,
+}
+
+impl Drop for LoadPermit<'_> {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock();
+        state.active_loads -= 1;
+        state.start_waiting_loads();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AssetIndex;
+    use bevy_utils::futures::check_ready;
+
+    fn id(index: u32) -> UntypedAssetId {
+        UntypedAssetId::Index {
+            type_id: core::any::TypeId::of::<()>(),
+            index: AssetIndex::from_bits(index as u64),
+        }
+    }
+
+    #[test]
+    fn loads_start_by_priority() {
+        let queue = LoadQueue::default();
+        queue.set_max_concurrent_loads(Some(1));
+
+        let mut first = Box::pin(queue.acquire(id(0), LoadPriority::NORMAL));
+        let first_permit = check_ready(&mut first).expect("the first load should start right away");
+
+        let mut low = Box::pin(queue.acquire(id(1), LoadPriority::LOW));
+        let mut normal = Box::pin(queue.acquire(id(2), LoadPriority::NORMAL));
+        let mut high = Box::pin(queue.acquire(id(3), LoadPriority::NORMAL));
+        assert!(check_ready(&mut low).is_none());
+        assert!(check_ready(&mut normal).is_none());
+        assert!(check_ready(&mut high).is_none());
+        assert!(queue.set_priority(id(3), LoadPriority::HIGH));
+
+        drop(first_permit);
+        assert!(check_ready(&mut low).is_none());
+        assert!(check_ready(&mut normal).is_none());
+        let high_permit =
+            check_ready(&mut high).expect("the load with the highest priority should start next");
+
+        drop(high_permit);
+        assert!(check_ready(&mut low).is_none());
+        assert!(check_ready(&mut normal).is_some());
+    }
+
+    #[test]
+    fn cancelled_loads_leave_the_queue() {
+        let queue = LoadQueue::default();
+        queue.set_max_concurrent_loads(Some(1));
+
+        let mut first = Box::pin(queue.acquire(id(0), LoadPriority::NORMAL));
+        let first_permit = check_ready(&mut first).expect("the first load should start right away");
+        let mut cancelled = Box::pin(queue.acquire(id(1), LoadPriority::HIGH));
+        let mut next = Box::pin(queue.acquire(id(2), LoadPriority::NORMAL));
+        assert!(check_ready(&mut cancelled).is_none());
+        assert!(check_ready(&mut next).is_none());
+
+        // The slot is given to the cancelled load, which must give it back when dropped.
+        drop(first_permit);
+        drop(cancelled);
+        assert!(!queue.set_priority(id(1), LoadPriority::LOW));
+        assert!(check_ready(&mut next).is_some());
+    }
+}
@@ -1,4 +1,6 @@
+mod graph;
 mod info;
+mod load_queue;
 mod loaders;
 
 use crate::{
@@ -22,7 +24,7 @@ use atomicow::CowArc;
 use bevy_ecs::prelude::*;
 use bevy_tasks::IoTaskPool;
 use bevy_utils::{
-    tracing::{error, info},
+    tracing::{debug, error, info},
     HashSet,
 };
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
@@ -30,7 +32,10 @@ use crossbeam_channel::{Receiver, Sender};
 use derive_more::derive::{Display, Error, From};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+pub use graph::{AssetDependencyGraph, AssetDependencyNode};
 use info::*;
+pub use load_queue::LoadPriority;
+use load_queue::LoadQueue;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
 use std::path::{Path, PathBuf};
@@ -63,6 +68,7 @@ pub(crate) struct AssetServerData {
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
+    load_queue: LoadQueue,
 }
 
 /// The "asset mode" the server is currently in.
@@ -123,6 +129,7 @@ impl AssetServer {
                 asset_event_receiver,
                 loaders,
                 infos: RwLock::new(infos),
+                load_queue: LoadQueue::default(),
             }),
         }
     }
@@ -306,7 +313,46 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, ())
+        self.load_with_meta_transform(path, None, (), LoadPriority::NORMAL)
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path` with the given `priority`, see [`AssetServer::load`].
+    ///
+    /// When the number of concurrent loads is [limited](AssetServer::set_max_concurrent_loads), loads with a higher
+    /// [`LoadPriority`] start first. If the asset at this path is already waiting to be loaded with a lower priority,
+    /// its priority is raised to `priority`. The dependencies loaded by the asset's loader inherit its priority.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: LoadPriority,
+    ) -> Handle<A> {
+        self.load_with_meta_transform(path, None, (), priority)
+    }
+
+    /// Returns the [`LoadPriority`] of the asset `id` if it is waiting to be loaded.
+    pub fn load_priority(&self, id: impl Into<UntypedAssetId>) -> Option<LoadPriority> {
+        self.data.load_queue.priority(id.into())
+    }
+
+    /// Changes the [`LoadPriority`] of the asset `id` if it is waiting to be loaded, for example when a prefetched
+    /// asset becomes visible. Returns `false` if the asset isn't waiting to be loaded.
+    pub fn set_load_priority(&self, id: impl Into<UntypedAssetId>, priority: LoadPriority) -> bool {
+        self.data.load_queue.set_priority(id.into(), priority)
+    }
+
+    /// Returns the maximum number of assets loaded at the same time, or `None` if it isn't limited.
+    pub fn max_concurrent_loads(&self) -> Option<usize> {
+        self.data.load_queue.max_concurrent_loads()
+    }
+
+    /// Limits the number of assets loaded at the same time to `max`, or removes the limit if `max` is `None`.
+    ///
+    /// Loads requested while `max` loads are running wait for one of them to finish, and start by
+    /// [`LoadPriority`]. A waiting load is cancelled if all the strong handles to its asset are dropped.
+    /// Assets loaded by [`AssetServer::reload`] and the immediate dependencies of loaded assets aren't limited.
+    pub fn set_max_concurrent_loads(&self, max: Option<usize>) {
+        self.data.load_queue.set_max_concurrent_loads(max);
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -330,7 +376,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard)
+        self.load_with_meta_transform(path, None, guard, LoadPriority::NORMAL)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -342,7 +388,12 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), ())
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            (),
+            LoadPriority::NORMAL,
+        )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -361,7 +412,12 @@ impl AssetServer {
         settings: impl Fn(&mut S) + Send + Sync + 'static,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            guard,
+            LoadPriority::NORMAL,
+        )
     }
 
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
@@ -369,6 +425,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> Handle<A> {
         let path = path.into().into_owned();
         let mut infos = self.data.infos.write();
@@ -379,7 +436,11 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            self.spawn_load_task(handle.clone().untyped(), path, infos, guard, priority);
+        } else {
+            self.data
+                .load_queue
+                .raise_priority(handle.id().untyped(), priority);
         }
 
         handle
@@ -391,6 +452,7 @@ impl AssetServer {
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> UntypedHandle {
         let path = path.into().into_owned();
         let mut infos = self.data.infos.write();
@@ -403,7 +465,9 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone(), path, infos, guard);
+            self.spawn_load_task(handle.clone(), path, infos, guard, priority);
+        } else {
+            self.data.load_queue.raise_priority(handle.id(), priority);
         }
 
         handle
@@ -415,16 +479,24 @@ impl AssetServer {
         path: AssetPath<'static>,
         infos: RwLockWriteGuard<AssetInfos>,
         guard: G,
+        priority: LoadPriority,
     ) {
         // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
-        let owned_handle = handle.clone();
+        let id = handle.id();
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let _permit = server.data.load_queue.acquire(id, priority).await;
+            // the task doesn't keep the asset alive while waiting, so that the load is cancelled
+            // if all the strong handles are dropped before it starts
+            let Some(owned_handle) = server.get_id_handle_untyped(id) else {
+                debug!("Cancelled loading {path} because it is no longer used");
+                return;
+            };
             if let Err(err) = server
-                .load_internal(Some(owned_handle), path, false, None)
+                .load_internal(Some(owned_handle), path, false, None, priority)
                 .await
             {
                 error!("{}", err);
@@ -451,13 +523,15 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let path: AssetPath = path.into();
-        self.load_internal(None, path, false, None).await
+        self.load_internal(None, path, false, None, LoadPriority::NORMAL)
+            .await
     }
 
     pub(crate) fn load_unknown_type_with_meta_transform<'a>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Handle<LoadedUntypedAsset> {
         let path = path.into().into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
@@ -477,15 +551,24 @@ impl AssetServer {
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
+        let id = handle.id().untyped();
         if !should_load {
+            self.data.load_queue.raise_priority(id, priority);
             return handle;
         }
-        let id = handle.id().untyped();
 
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let _permit = server.data.load_queue.acquire(id, priority).await;
+            if server.get_id_handle_untyped(id).is_none() {
+                debug!("Cancelled loading {path} because it is no longer used");
+                return;
+            }
             let path_clone = path.clone();
-            match server.load_untyped_async(path).await {
+            match server
+                .load_internal(None, path, false, None, priority)
+                .await
+            {
                 Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
                     id,
                     loaded_asset: LoadedAsset::new_with_dependencies(
@@ -538,7 +621,7 @@ impl AssetServer {
     /// required to figure out the asset type before a handle can be created.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedUntypedAsset> {
-        self.load_unknown_type_with_meta_transform(path, None)
+        self.load_unknown_type_with_meta_transform(path, None, LoadPriority::NORMAL)
     }
 
     /// Performs an async asset load.
@@ -551,6 +634,7 @@ impl AssetServer {
         path: AssetPath<'a>,
         force: bool,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let asset_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
 
@@ -647,7 +731,15 @@ impl AssetServer {
         };
 
         match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
+            .load_with_meta_loader_and_reader(
+                &base_path,
+                meta,
+                &*loader,
+                &mut *reader,
+                true,
+                false,
+                priority,
+            )
             .await
         {
             Ok(loaded_asset) => {
@@ -710,7 +802,15 @@ impl AssetServer {
                     .infos
                     .read()
                     .get_path_handles(&path)
-                    .map(|handle| server.load_internal(Some(handle), path.clone(), true, None))
+                    .map(|handle| {
+                        server.load_internal(
+                            Some(handle),
+                            path.clone(),
+                            true,
+                            None,
+                            LoadPriority::NORMAL,
+                        )
+                    })
                     .collect::<Vec<_>>();
 
                 for result in requests {
@@ -721,7 +821,10 @@ impl AssetServer {
                 }
 
                 if !reloaded && server.data.infos.read().should_reload(&path) {
-                    if let Err(err) = server.load_internal(None, path, true, None).await {
+                    if let Err(err) = server
+                        .load_internal(None, path, true, None, LoadPriority::NORMAL)
+                        .await
+                    {
                         error!("{}", err);
                     }
                 }
@@ -1153,6 +1256,24 @@ impl AssetServer {
         Some(info.path.as_ref()?.clone())
     }
 
+    /// Returns a snapshot of the dependencies between all the assets tracked by this server, with their load states.
+    ///
+    /// This is meant for debugging, for example to find which dependency keeps a level from loading:
+    ///
+    /// ```no_run
+    /// # use bevy_asset::{AssetServer, UntypedHandle};
+    /// # fn level_loaded(asset_server: &AssetServer, level: &UntypedHandle) {
+    /// let graph = asset_server.dependency_graph();
+    /// for id in graph.pending_leaves(level) {
+    ///     println!("still waiting for {:?}", asset_server.get_path(id));
+    /// }
+    /// std::fs::write("assets.dot", graph.to_dot()).unwrap();
+    /// # }
+    /// ```
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        self.data.infos.read().dependency_graph()
+    }
+
     /// Returns the [`AssetServerMode`] this server is currently in.
     pub fn mode(&self) -> AssetServerMode {
         self.data.mode
@@ -1311,6 +1432,10 @@ impl AssetServer {
         }
     }
 
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Every argument configures the `LoadContext` the asset is loaded with."
+    )]
     pub(crate) async fn load_with_meta_loader_and_reader(
         &self,
         asset_path: &AssetPath<'_>,
@@ -1319,11 +1444,17 @@ impl AssetServer {
         reader: &mut dyn Reader,
         load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Result<ErasedLoadedAsset, AssetLoadError> {
         // TODO: experiment with this
         let asset_path = asset_path.clone_owned();
-        let load_context =
-            LoadContext::new(self, asset_path.clone(), load_dependencies, populate_hashes);
+        let load_context = LoadContext::new(
+            self,
+            asset_path.clone(),
+            load_dependencies,
+            populate_hashes,
+            priority,
+        );
         AssertUnwindSafe(loader.load(reader, meta, load_context))
             .catch_unwind()
             .await
@@ -0,0 +1,248 @@
+//! Definitions for [`Event`] reflection.
+//!
+//! # Architecture
+//!
+//! See the module doc for [`crate::reflect::component`].
+
+use crate::{
+    entity::Entity,
+    event::{Event, Events},
+    world::World,
+};
+use bevy_reflect::{FromReflect, FromType, PartialReflect, Reflect, TypePath, TypeRegistry};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to send, trigger and read reflected [`Event`]s of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+///
+/// This is used when creating custom implementations of [`ReflectEvent`] with
+/// [`ReflectEvent::new()`].
+///
+/// > **Note:**
+/// > Creating custom implementations of [`ReflectEvent`] is an advanced feature that most users
+/// > will not need.
+/// > Usually a [`ReflectEvent`] is created for a type by deriving [`Reflect`]
+/// > and adding the `#[reflect(Event)]` attribute.
+/// > After adding the event to the [`TypeRegistry`],
+/// > its [`ReflectEvent`] can then be retrieved when needed.
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> bool,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, Vec<Entity>, &TypeRegistry),
+    /// Function pointer implementing [`ReflectEvent::read()`].
+    pub read: fn(&World, Option<usize>) -> Option<(Vec<&dyn Reflect>, usize)>,
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Send a reflected [`Event`] like [`send_event()`](World::send_event).
+    ///
+    /// Returns `false` if the event could not be sent,
+    /// which happens when the [`Events`] resource for this type does not exist.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> bool {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Trigger a reflected [`Event`] for the given `targets` like [`trigger_targets()`](World::trigger_targets),
+    /// running any observers watching for it.
+    ///
+    /// If `targets` is empty, only global observers are run, like [`trigger()`](World::trigger).
+    pub fn trigger(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        targets: Vec<Entity>,
+        registry: &TypeRegistry,
+    ) {
+        (self.0.trigger)(world, event, targets, registry);
+    }
+
+    /// Gets the [`Event`]s of this type sent after the first `last_event_count` events that are
+    /// still buffered as reflected references, like an [`EventCursor`] does.
+    ///
+    /// Also returns the number of events sent so far, to pass as `last_event_count` to read only
+    /// newer events next time. If `last_event_count` is `None`, the events sent since the last
+    /// [`Events::update`] call are returned.
+    ///
+    /// Returns `None` if the [`Events`] resource for this type does not exist.
+    ///
+    /// [`EventCursor`]: crate::event::EventCursor
+    pub fn read<'a>(
+        &self,
+        world: &'a World,
+        last_event_count: Option<usize>,
+    ) -> Option<(Vec<&'a dyn Reflect>, usize)> {
+        (self.0.read)(world, last_event_count)
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`] and add the `#[reflect(Event)]` attribute
+    /// to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                if !world.contains_resource::<Events<E>>() {
+                    return false;
+                }
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.send_event(event).is_some()
+            },
+            trigger: |world, reflected_event, targets, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                if targets.is_empty() {
+                    world.trigger(event);
+                } else {
+                    world.trigger_targets(event, targets);
+                }
+            },
+            read: |world, last_event_count| {
+                let events = world.get_resource::<Events<E>>()?;
+                let event_count = events.oldest_event_count() + events.len();
+                let start = last_event_count
+                    .unwrap_or_else(|| event_count - events.iter_current_update_events().len());
+                let read = (start.max(events.oldest_event_count())..event_count)
+                    .filter_map(|id| events.get_event(id))
+                    .map(|(event, _)| event as &dyn Reflect)
+                    .collect();
+                Some((read, event_count))
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        event::{Event, Events},
+        observer::Trigger,
+        reflect::ReflectEvent,
+        system::{ResMut, Resource},
+        world::World,
+    };
+    use bevy_reflect::{DynamicStruct, Reflect, TypeRegistry};
+
+    #[derive(Event, Reflect, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Damage {
+        amount: u32,
+    }
+
+    #[derive(Resource, Default)]
+    struct Received(u32);
+
+    fn damage(amount: u32) -> DynamicStruct {
+        let mut value = DynamicStruct::default();
+        value.insert("amount", amount);
+        value
+    }
+
+    #[test]
+    fn send_and_read_reflected_event() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Damage>();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap()
+            .clone();
+
+        let mut world = World::new();
+        assert!(!reflect_event.send(&mut world, &damage(1), &registry));
+        assert!(reflect_event.read(&world, None).is_none());
+
+        world.init_resource::<Events<Damage>>();
+        assert!(reflect_event.send(&mut world, &damage(5), &registry));
+
+        let (events, event_count) = reflect_event.read(&world, None).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].downcast_ref::<Damage>(),
+            Some(&Damage { amount: 5 })
+        );
+        assert!(reflect_event
+            .read(&world, Some(event_count))
+            .unwrap()
+            .0
+            .is_empty());
+
+        reflect_event.send(&mut world, &damage(6), &registry);
+        world.resource_mut::<Events<Damage>>().update();
+        let (events, _) = reflect_event.read(&world, Some(event_count)).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].downcast_ref::<Damage>(),
+            Some(&Damage { amount: 6 })
+        );
+        assert!(reflect_event.read(&world, None).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn trigger_reflected_event() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Damage>();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap()
+            .clone();
+
+        let mut world = World::new();
+        world.init_resource::<Received>();
+        world.add_observer(|trigger: Trigger<Damage>, mut received: ResMut<Received>| {
+            received.0 += trigger.event().amount;
+        });
+        let target = world.spawn_empty().id();
+
+        reflect_event.trigger(&mut world, &damage(2), Vec::new(), &registry);
+        world.flush();
+        reflect_event.trigger(&mut world, &damage(3), vec![target], &registry);
+        world.flush();
+
+        assert_eq!(world.resource::<Received>().0, 5);
+    }
+}
@@ -0,0 +1,467 @@
+//! Calling reflected functions with arguments resolved from a [`World`].
+
+use alloc::borrow::Cow;
+
+use bevy_reflect::{
+    func::{args::Ownership, ArgList, DynamicFunction, FunctionError, Return},
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
+};
+use derive_more::derive::{Display, Error};
+
+use crate::{
+    entity::Entity,
+    reflect::{AppFunctionRegistry, AppTypeRegistry, ReflectComponent, ReflectResource},
+    world::World,
+};
+
+/// An argument to a [`DynamicFunction`] called with [`World::call_function`].
+///
+/// Components and resources are resolved using the type of the function argument
+/// at the same position, so only the entity (if any) needs to be provided.
+#[derive(Debug)]
+pub enum WorldArg {
+    /// A reflected value that is passed to the function as-is.
+    Value(Box<dyn PartialReflect>),
+    /// An [`Entity`] that is passed to the function by value.
+    Entity(Entity),
+    /// The component of the given entity whose type matches the function argument.
+    Component(Entity),
+    /// The resource whose type matches the function argument.
+    Resource,
+}
+
+impl From<Entity> for WorldArg {
+    fn from(entity: Entity) -> Self {
+        Self::Entity(entity)
+    }
+}
+
+impl From<Box<dyn PartialReflect>> for WorldArg {
+    fn from(value: Box<dyn PartialReflect>) -> Self {
+        Self::Value(value)
+    }
+}
+
+/// The error type returned by [`World::call_function`] and [`World::call_registered_function`].
+#[derive(Debug, Error, Display)]
+pub enum WorldFunctionError {
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[display("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// The [`World`] was missing the [`AppFunctionRegistry`] resource.
+    #[display("The `World` was missing the `AppFunctionRegistry` resource")]
+    MissingAppFunctionRegistry,
+    /// No function was registered with the given name.
+    #[display("No function registered with name {_0:?}")]
+    #[error(ignore)]
+    UnknownFunction(String),
+    /// The number of arguments provided does not match the function signature.
+    #[display("expected {expected} arguments but received {received}")]
+    ArgCountMismatch {
+        /// The number of arguments the function expects.
+        expected: usize,
+        /// The number of arguments that were provided.
+        received: usize,
+    },
+    /// The type of the argument at the given index is not registered in the [`AppTypeRegistry`].
+    #[display("The type `{type_path}` of argument {index} is not registered")]
+    UnregisteredType {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the argument.
+        type_path: Cow<'static, str>,
+    },
+    /// The argument at the given index was expected to be a reflected [`Component`],
+    /// but its type does not register [`ReflectComponent`].
+    ///
+    /// [`Component`]: crate::component::Component
+    #[display("The type `{type_path}` of argument {index} is not a reflected component")]
+    NotAComponent {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the argument.
+        type_path: Cow<'static, str>,
+    },
+    /// The argument at the given index was expected to be a reflected [`Resource`],
+    /// but its type does not register [`ReflectResource`].
+    ///
+    /// [`Resource`]: crate::system::Resource
+    #[display("The type `{type_path}` of argument {index} is not a reflected resource")]
+    NotAResource {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the argument.
+        type_path: Cow<'static, str>,
+    },
+    /// The type of the argument at the given index does not register [`ReflectFromReflect`],
+    /// so its value could not be copied out of the [`World`].
+    #[display("The type `{type_path}` of argument {index} does not register `ReflectFromReflect`")]
+    MissingReflectFromReflect {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the argument.
+        type_path: Cow<'static, str>,
+    },
+    /// The given entity does not exist or does not have the requested component.
+    #[display("Entity {entity} does not have a `{type_path}` component (argument {index})")]
+    ComponentNotFound {
+        /// The index of the argument.
+        index: usize,
+        /// The entity that was queried.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: Cow<'static, str>,
+    },
+    /// The requested resource does not exist in the [`World`].
+    #[display("The resource `{type_path}` does not exist (argument {index})")]
+    ResourceNotFound {
+        /// The index of the argument.
+        index: usize,
+        /// The type path of the resource.
+        type_path: Cow<'static, str>,
+    },
+    /// An error occurred while calling the function itself.
+    Function(FunctionError),
+}
+
+impl From<FunctionError> for WorldFunctionError {
+    fn from(error: FunctionError) -> Self {
+        Self::Function(error)
+    }
+}
+
+/// The place a resolved argument was read from, used to write back mutations.
+enum ArgSource<'a> {
+    Value,
+    Component(Entity, &'a ReflectComponent),
+    Resource(&'a ReflectResource),
+}
+
+impl World {
+    /// Calls the given [`DynamicFunction`], resolving each [`WorldArg`] from this [`World`].
+    ///
+    /// Components and resources are looked up by the type of the function argument in the
+    /// same position, which must be registered in the [`AppTypeRegistry`] along with
+    /// [`ReflectFromReflect`] and either [`ReflectComponent`] or [`ReflectResource`].
+    /// Their values are copied out of the world before the call, so a function may take
+    /// several components or resources at once, and any argument taken by mutable reference
+    /// is written back into the world afterwards.
+    ///
+    /// Returns `None` if the function returns `()`. Borrowed return values are cloned using
+    /// [`PartialReflect::clone_value`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::reflect::WorldArg;
+    /// # use bevy_reflect::{func::IntoFunction, Reflect};
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn heal(health: &mut Health, amount: u32) {
+    ///     health.0 += amount;
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<AppTypeRegistry>();
+    /// world.resource::<AppTypeRegistry>().write().register::<Health>();
+    ///
+    /// let entity = world.spawn(Health(10)).id();
+    /// world
+    ///     .call_function(
+    ///         &heal.into_function(),
+    ///         vec![WorldArg::Component(entity), WorldArg::Value(Box::new(5_u32))],
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.get::<Health>(entity).unwrap().0, 15);
+    /// ```
+    pub fn call_function(
+        &mut self,
+        function: &DynamicFunction,
+        args: Vec<WorldArg>,
+    ) -> Result<Option<Box<dyn PartialReflect>>, WorldFunctionError> {
+        let info = function.info();
+        if info.arg_count() != args.len() {
+            return Err(WorldFunctionError::ArgCountMismatch {
+                expected: info.arg_count(),
+                received: args.len(),
+            });
+        }
+
+        let app_type_registry = self
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(WorldFunctionError::MissingAppTypeRegistry)?
+            .clone();
+        let type_registry = app_type_registry.read();
+
+        let mut values = Vec::with_capacity(args.len());
+        let mut sources = Vec::with_capacity(args.len());
+        for (arg, arg_info) in args.into_iter().zip(info.args()) {
+            let index = arg_info.index();
+            let (value, source) = match arg {
+                WorldArg::Value(value) => (value, ArgSource::Value),
+                WorldArg::Entity(entity) => (
+                    Box::new(entity) as Box<dyn PartialReflect>,
+                    ArgSource::Value,
+                ),
+                WorldArg::Component(entity) => {
+                    let registration =
+                        get_arg_registration(&type_registry, index, arg_info.type_path())?;
+                    let type_path = registration.type_info().type_path();
+                    let reflect_component =
+                        registration.data::<ReflectComponent>().ok_or_else(|| {
+                            WorldFunctionError::NotAComponent {
+                                index,
+                                type_path: type_path.into(),
+                            }
+                        })?;
+                    let component = self
+                        .get_entity(entity)
+                        .ok()
+                        .and_then(|entity_ref| reflect_component.reflect(entity_ref))
+                        .ok_or_else(|| WorldFunctionError::ComponentNotFound {
+                            index,
+                            entity,
+                            type_path: type_path.into(),
+                        })?;
+                    (
+                        copy_from_reflect(registration, index, component.as_partial_reflect())?,
+                        ArgSource::Component(entity, reflect_component),
+                    )
+                }
+                WorldArg::Resource => {
+                    let registration =
+                        get_arg_registration(&type_registry, index, arg_info.type_path())?;
+                    let type_path = registration.type_info().type_path();
+                    let reflect_resource =
+                        registration.data::<ReflectResource>().ok_or_else(|| {
+                            WorldFunctionError::NotAResource {
+                                index,
+                                type_path: type_path.into(),
+                            }
+                        })?;
+                    let resource = reflect_resource.reflect(self).ok_or_else(|| {
+                        WorldFunctionError::ResourceNotFound {
+                            index,
+                            type_path: type_path.into(),
+                        }
+                    })?;
+                    (
+                        copy_from_reflect(registration, index, resource.as_partial_reflect())?,
+                        ArgSource::Resource(reflect_resource),
+                    )
+                }
+            };
+            values.push(Some(value));
+            sources.push(source);
+        }
+
+        let result = {
+            let mut arg_list = ArgList::new();
+            for (slot, arg_info) in values.iter_mut().zip(info.args()) {
+                arg_list = match arg_info.ownership() {
+                    Ownership::Owned => arg_list.push_boxed(slot.take().unwrap()),
+                    Ownership::Ref => arg_list.push_ref(slot.as_deref().unwrap()),
+                    Ownership::Mut => arg_list.push_mut(slot.as_deref_mut().unwrap()),
+                };
+            }
+
+            let value = function.call(arg_list)?;
+            if value.is_unit() {
+                None
+            } else {
+                Some(match value {
+                    Return::Owned(value) => value,
+                    Return::Ref(value) => value.clone_value(),
+                    Return::Mut(value) => value.clone_value(),
+                })
+            }
+        };
+
+        // Write back any component or resource that was borrowed mutably.
+        for ((value, source), arg_info) in values.iter().zip(sources).zip(info.args()) {
+            let (Ownership::Mut, Some(value)) = (arg_info.ownership(), value) else {
+                continue;
+            };
+            match source {
+                ArgSource::Value => {}
+                ArgSource::Component(entity, reflect_component) => {
+                    reflect_component.apply(self.entity_mut(entity), value.as_ref());
+                }
+                ArgSource::Resource(reflect_resource) => {
+                    reflect_resource.apply(self, value.as_ref());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Calls the function registered in the [`AppFunctionRegistry`] under the given `name`,
+    /// resolving each [`WorldArg`] from this [`World`].
+    ///
+    /// See [`World::call_function`] for details on how arguments are resolved.
+    pub fn call_registered_function(
+        &mut self,
+        name: &str,
+        args: Vec<WorldArg>,
+    ) -> Result<Option<Box<dyn PartialReflect>>, WorldFunctionError> {
+        let app_function_registry = self
+            .get_resource::<AppFunctionRegistry>()
+            .ok_or(WorldFunctionError::MissingAppFunctionRegistry)?
+            .clone();
+        let function_registry = app_function_registry.read();
+        let function = function_registry
+            .get(name)
+            .ok_or_else(|| WorldFunctionError::UnknownFunction(name.to_owned()))?;
+
+        self.call_function(function, args)
+    }
+}
+
+/// Returns the type path of the type referred to by a function argument of type `type_path`,
+/// stripping the `&` or `&mut ` of reference arguments.
+///
+/// This is the type of the value a [`WorldArg::Value`] should hold for such an argument.
+pub fn dereferenced_type_path(type_path: &str) -> &str {
+    type_path
+        .strip_prefix("&mut ")
+        .or_else(|| type_path.strip_prefix('&'))
+        .unwrap_or(type_path)
+}
+
+/// Returns the registration of the type referred to by a function argument,
+/// stripping any reference from its type path.
+fn get_arg_registration<'a>(
+    type_registry: &'a TypeRegistry,
+    index: usize,
+    type_path: &'static str,
+) -> Result<&'a TypeRegistration, WorldFunctionError> {
+    let inner_type_path = dereferenced_type_path(type_path);
+
+    type_registry
+        .get_with_type_path(inner_type_path)
+        .ok_or(WorldFunctionError::UnregisteredType {
+            index,
+            type_path: inner_type_path.into(),
+        })
+}
+
+/// Copies a reflected value into a new concrete instance of its type.
+fn copy_from_reflect(
+    registration: &TypeRegistration,
+    index: usize,
+    value: &dyn PartialReflect,
+) -> Result<Box<dyn PartialReflect>, WorldFunctionError> {
+    registration
+        .data::<ReflectFromReflect>()
+        .and_then(|reflect_from_reflect| reflect_from_reflect.from_reflect(value))
+        .map(PartialReflect::into_partial_reflect)
+        .ok_or_else(|| WorldFunctionError::MissingReflectFromReflect {
+            index,
+            type_path: registration.type_info().type_path().into(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{func::IntoFunction, Reflect};
+
+    use super::*;
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        reflect::{ReflectComponent, ReflectResource},
+        system::Resource,
+    };
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Multiplier(u32);
+
+    fn setup() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<AppFunctionRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Multiplier>();
+        }
+        world.insert_resource(Multiplier(3));
+        world
+    }
+
+    #[test]
+    fn resolves_components_and_resources() {
+        fn scaled(health: &Health, multiplier: &Multiplier) -> u32 {
+            health.0 * multiplier.0
+        }
+
+        let mut world = setup();
+        let entity = world.spawn(Health(7)).id();
+        let result = world
+            .call_function(
+                &scaled.into_function(),
+                vec![WorldArg::Component(entity), WorldArg::Resource],
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.try_downcast_ref::<u32>(), Some(&21));
+    }
+
+    #[test]
+    fn writes_back_mutable_arguments() {
+        fn apply(entity: Entity, health: &mut Health, multiplier: &mut Multiplier) -> Entity {
+            health.0 *= multiplier.0;
+            multiplier.0 += 1;
+            entity
+        }
+
+        let mut world = setup();
+        let entity = world.spawn(Health(2)).id();
+        world
+            .resource::<AppFunctionRegistry>()
+            .write()
+            .register_with_name("apply", apply)
+            .unwrap();
+
+        let result = world
+            .call_registered_function(
+                "apply",
+                vec![
+                    WorldArg::Entity(entity),
+                    WorldArg::Component(entity),
+                    WorldArg::Resource,
+                ],
+            )
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.try_downcast_ref::<Entity>(), Some(&entity));
+        assert_eq!(world.get::<Health>(entity), Some(&Health(6)));
+        assert_eq!(world.resource::<Multiplier>(), &Multiplier(4));
+    }
+
+    #[test]
+    fn reports_missing_component() {
+        fn read(_: &Health) {}
+
+        let mut world = setup();
+        let entity = world.spawn_empty().id();
+        let result = world.call_function(&read.into_function(), vec![WorldArg::Component(entity)]);
+
+        assert!(matches!(
+            result,
+            Err(WorldFunctionError::ComponentNotFound { index: 0, .. })
+        ));
+    }
+}
@@ -15,7 +15,10 @@ use bevy_reflect::{
 mod bundle;
 mod component;
 mod entity_commands;
+mod event;
 mod from_world;
+#[cfg(feature = "reflect_functions")]
+mod function;
 mod map_entities;
 mod resource;
 mod visit_entities;
@@ -23,7 +26,10 @@ mod visit_entities;
 pub use bundle::{ReflectBundle, ReflectBundleFns};
 pub use component::{ReflectComponent, ReflectComponentFns};
 pub use entity_commands::ReflectCommandExt;
+pub use event::{ReflectEvent, ReflectEventFns};
 pub use from_world::{ReflectFromWorld, ReflectFromWorldFns};
+#[cfg(feature = "reflect_functions")]
+pub use function::{dereferenced_type_path, WorldArg, WorldFunctionError};
 pub use map_entities::ReflectMapEntities;
 pub use resource::{ReflectResource, ReflectResourceFns};
 pub use visit_entities::{ReflectVisitEntities, ReflectVisitEntitiesMut};
@@ -3,26 +3,35 @@
 use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
+use bevy_app::Main;
+#[cfg(feature = "reflect_functions")]
+use bevy_ecs::reflect::{dereferenced_type_path, AppFunctionRegistry, WorldArg};
 use bevy_ecs::{
     component::ComponentId,
     entity::Entity,
     event::EventCursor,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
-    system::{In, Local},
+    schedule::{NodeId, ScheduleLabel, Schedules},
+    system::{In, Local, Res, ResMut, Resource, ScheduleSystem, System},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_hierarchy::BuildChildren as _;
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    std_traits::ReflectDefault,
+    GetPath, PartialReflect, ReflectDeserialize, ReflectSerialize, TypeInfo, TypeRegistration,
+    TypeRegistry,
 };
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::{error_codes, BrpError, BrpResult};
+use crate::{
+    error_codes, BrpError, BrpResult, RemoteComponentFilter, RemoteLast, RemoteWatchingRequests,
+    WatchingRequestId,
+};
 
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
@@ -54,6 +63,37 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/get_resource` request.
+pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
+
+/// The method path for a `bevy/insert_resource` request.
+pub const BRP_INSERT_RESOURCE_METHOD: &str = "bevy/insert_resource";
+
+/// The method path for a `bevy/mutate_component` request.
+pub const BRP_MUTATE_COMPONENT_METHOD: &str = "bevy/mutate_component";
+
+/// The method path for a `bevy/list_resources` request.
+pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
+
+/// The method path for a `bevy/registry/types` request.
+pub const BRP_REGISTRY_TYPES_METHOD: &str = "bevy/registry/types";
+
+/// The method path for a `bevy/schedules` request.
+pub const BRP_SCHEDULES_METHOD: &str = "bevy/schedules";
+
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger` request.
+pub const BRP_TRIGGER_METHOD: &str = "bevy/trigger";
+
+/// The method path for a `bevy/events+watch` request.
+pub const BRP_EVENTS_AND_WATCH_METHOD: &str = "bevy/events+watch";
+
+/// The method path for a `bevy/call` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_METHOD: &str = "bevy/call";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -187,6 +227,167 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/get_resource`: Retrieves the value of a resource.
+///
+/// The server responds with a [`BrpGetResourceResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceParams {
+    /// The [full path] of the resource type that is to be requested.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/insert_resource`: Inserts a resource into the world, replacing any
+/// existing value.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpInsertResourceParams {
+    /// The [full path] of the resource type that is to be inserted.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// `bevy/mutate_component`: Sets the value of a single field of a component
+/// on an entity.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpMutateComponentParams {
+    /// The ID of the entity whose component is to be mutated.
+    pub entity: Entity,
+
+    /// The [full path] of the component type that is to be mutated.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [reflect path] of the field within the component, e.g. `translation.x`.
+    ///
+    /// An empty path replaces the whole component.
+    ///
+    /// [reflect path]: bevy_reflect::GetPath
+    #[serde(default)]
+    pub path: String,
+
+    /// The serialized value of the field.
+    pub value: Value,
+}
+
+/// `bevy/registry/types`: Lists the types in the type registry along with
+/// their structure.
+///
+/// The server responds with a [`BrpRegistryTypesResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpRegistryTypesParams {
+    /// If not empty, only types whose [full path] starts with one of these
+    /// prefixes are listed.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+}
+
+/// `bevy/schedules`: Lists the schedules of the app along with their systems
+/// and system sets.
+///
+/// The server responds with a [`BrpSchedulesResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BrpSchedulesParams {
+    /// If present, only the schedule whose label has this name is listed.
+    #[serde(default)]
+    pub schedule: Option<String>,
+}
+
+/// `bevy/send_event`: Sends an event into the world, to be read by
+/// [`EventReader`]s.
+///
+/// The server responds with a null.
+///
+/// [`EventReader`]: bevy_ecs::event::EventReader
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type that is to be sent.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+}
+
+/// `bevy/trigger`: Triggers an event, running any observers watching for it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpTriggerParams {
+    /// The [full path] of the event type that is to be triggered.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+
+    /// The entities to trigger the event for.
+    ///
+    /// If empty, only global observers are run.
+    #[serde(default)]
+    pub targets: Vec<Entity>,
+}
+
+/// `bevy/events+watch`: Streams the events of a given type sent into the world.
+///
+/// The server responds with a [`BrpEventsWatchingResponse`] every frame in which
+/// at least one event of that type was sent.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpEventsParams {
+    /// The [full path] of the event type that is to be watched.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+}
+
+/// `bevy/call`: Calls a function registered in the [`AppFunctionRegistry`] by name.
+///
+/// The server responds with the serialized return value of the function, or null if
+/// the function returns `()`.
+///
+/// [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpCallParams {
+    /// The name the function was registered with.
+    pub function: String,
+
+    /// The arguments to call the function with, one per function parameter.
+    #[serde(default)]
+    pub args: Vec<BrpCallArg>,
+}
+
+/// A single argument of a `bevy/call` request.
+///
+/// Components and resources are resolved using the type of the function
+/// parameter at the same position.
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpCallArg {
+    /// A value deserialized as the type of the function parameter.
+    Value(Value),
+    /// An entity ID passed by value.
+    Entity(Entity),
+    /// The component of the given entity.
+    Component(Entity),
+    /// The resource of the parameter's type.
+    Resource,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 pub struct BrpQuery {
@@ -294,6 +495,88 @@ pub struct BrpListWatchingResponse {
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
+/// The response to a `bevy/get_resource` request.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpGetResourceResponse {
+    /// The serialized value of the resource.
+    pub value: Value,
+}
+
+/// The response to a `bevy/list_resources` request.
+pub type BrpListResourcesResponse = Vec<String>;
+
+/// A response from the world to the client that specifies the events sent during
+/// the last frame.
+pub type BrpEventsWatchingResponse = Vec<Value>;
+
+/// The response to a `bevy/registry/types` request.
+pub type BrpRegistryTypesResponse = Vec<BrpTypeInfo>;
+
+/// A description of a single registered type.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpTypeInfo {
+    /// The [full path] of the type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub type_path: String,
+
+    /// The [short path] of the type.
+    ///
+    /// [short path]: bevy_reflect::TypePath::short_type_path
+    pub short_path: String,
+
+    /// The [kind] of the type, e.g. `struct` or `enum`.
+    ///
+    /// [kind]: bevy_reflect::ReflectKind
+    pub kind: String,
+
+    /// The names of the notable reflected traits registered for the type:
+    /// `Component`, `Resource`, `Default`, `Serialize` and `Deserialize`.
+    pub reflect: Vec<String>,
+
+    /// For structs, a map from each field name to the full path of its type.
+    /// For tuple structs and tuples, the field indices are used as names.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub fields: Vec<(String, String)>,
+
+    /// For enums, the names of the variants.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub variants: Vec<String>,
+}
+
+/// The response to a `bevy/schedules` request.
+pub type BrpSchedulesResponse = Vec<BrpScheduleInfo>;
+
+/// A description of a single schedule.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpScheduleInfo {
+    /// The debug name of the schedule label.
+    pub label: String,
+
+    /// The systems in the schedule.
+    pub systems: Vec<BrpSystemInfo>,
+
+    /// The debug names of the system sets configured in the schedule.
+    pub sets: Vec<String>,
+
+    /// Whether the schedule was running while the request was processed.
+    ///
+    /// Running schedules are taken out of the [`Schedules`] resource, so their systems and sets
+    /// are not available and are left empty.
+    #[serde(default)]
+    pub running: bool,
+}
+
+/// A description of a single system in a schedule.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSystemInfo {
+    /// The name of the system.
+    pub name: String,
+
+    /// The debug names of the system sets the system is directly part of.
+    pub sets: Vec<String>,
+}
+
 /// One query match result: a single entity paired with the requested components.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BrpQueryRow {
@@ -341,8 +624,16 @@ pub fn process_remote_get_request(In(params): In<Option<Value>>, world: &World)
     let type_registry = app_type_registry.read();
     let entity_ref = get_entity(world, entity)?;
 
-    let response =
-        reflect_components_to_response(components, strict, entity, entity_ref, &type_registry)?;
+    let component_filter = world.get_resource::<RemoteComponentFilter>();
+
+    let response = reflect_components_to_response(
+        components,
+        strict,
+        entity,
+        entity_ref,
+        &type_registry,
+        component_filter,
+    )?;
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
@@ -418,8 +709,16 @@ pub fn process_remote_get_watching_request(
         return Ok(None);
     }
 
-    let response =
-        reflect_components_to_response(changed, strict, entity, entity_ref, &type_registry)?;
+    let component_filter = world.get_resource::<RemoteComponentFilter>();
+
+    let response = reflect_components_to_response(
+        changed,
+        strict,
+        entity,
+        entity_ref,
+        &type_registry,
+        component_filter,
+    )?;
 
     let response = match response {
         BrpGetResponse::Lenient {
@@ -451,6 +750,7 @@ fn reflect_components_to_response(
     entity: Entity,
     entity_ref: EntityRef,
     type_registry: &TypeRegistry,
+    component_filter: Option<&RemoteComponentFilter>,
 ) -> BrpResult<BrpGetResponse> {
     let mut response = if strict {
         BrpGetResponse::Strict(Default::default())
@@ -462,7 +762,13 @@ fn reflect_components_to_response(
     };
 
     for component_path in components {
-        match reflect_component(&component_path, entity, entity_ref, type_registry) {
+        match reflect_component(
+            &component_path,
+            entity,
+            entity_ref,
+            type_registry,
+            component_filter,
+        ) {
             Ok(serialized_object) => match response {
                 BrpGetResponse::Strict(ref mut components)
                 | BrpGetResponse::Lenient {
@@ -490,7 +796,10 @@ fn reflect_component(
     entity: Entity,
     entity_ref: EntityRef,
     type_registry: &TypeRegistry,
+    component_filter: Option<&RemoteComponentFilter>,
 ) -> BrpResult<Map<String, Value>> {
+    check_component_filter(type_registry, component_filter, component_path)
+        .map_err(BrpError::component_error)?;
     let reflect_component =
         get_reflect_component(type_registry, component_path).map_err(BrpError::component_error)?;
 
@@ -532,6 +841,13 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
+    // Only the components whose values or presence end up in the response are filtered.
+    let component_filter = world.get_resource::<RemoteComponentFilter>();
+    for component_path in components.iter().chain(&option).chain(&has) {
+        check_component_filter(&type_registry, component_filter, component_path)
+            .map_err(BrpError::component_error)?;
+    }
+
     let components =
         get_component_ids(&type_registry, world, components).map_err(BrpError::component_error)?;
     let option =
@@ -719,22 +1035,27 @@ pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World)
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
+    let component_filter = world.get_resource::<RemoteComponentFilter>();
     let mut response = BrpListResponse::default();
 
     // If `Some`, return all components of the provided entity.
     if let Some(BrpListParams { entity }) = params.map(parse).transpose()? {
         let entity = get_entity(world, entity)?;
         for component_id in entity.archetype().components() {
-            let Some(component_info) = world.components().get_info(component_id) else {
+            let Some(name) =
+                readable_component_name(world, &type_registry, component_filter, component_id)
+            else {
                 continue;
             };
-            response.push(component_info.name().to_owned());
+            response.push(name.to_owned());
         }
     }
     // If `None`, list all registered components.
     else {
         for registered_type in type_registry.iter() {
-            if registered_type.data::<ReflectComponent>().is_some() {
+            if registered_type.data::<ReflectComponent>().is_some()
+                && component_filter.is_none_or(|filter| filter.allows(registered_type))
+            {
                 response.push(registered_type.type_info().type_path().to_owned());
             }
         }
@@ -755,6 +1076,9 @@ pub fn process_remote_list_watching_request(
 ) -> BrpResult<Option<Value>> {
     let BrpListParams { entity } = parse_some(params)?;
     let entity_ref = get_entity(world, entity)?;
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let component_filter = world.get_resource::<RemoteComponentFilter>();
     let mut response = BrpListWatchingResponse::default();
 
     for component_id in entity_ref.archetype().components() {
@@ -763,10 +1087,12 @@ pub fn process_remote_list_watching_request(
             .ok_or(BrpError::internal("Failed to get ticks"))?;
 
         if ticks.is_added(world.last_change_tick(), world.read_change_tick()) {
-            let Some(component_info) = world.components().get_info(component_id) else {
+            let Some(name) =
+                readable_component_name(world, &type_registry, component_filter, component_id)
+            else {
                 continue;
             };
-            response.added.push(component_info.name().to_owned());
+            response.added.push(name.to_owned());
         }
     }
 
@@ -776,10 +1102,12 @@ pub fn process_remote_list_watching_request(
             .or_insert_with(|| events.get_cursor());
         for event in cursor.read(events) {
             if Entity::from(event.clone()) == entity {
-                let Some(component_info) = world.components().get_info(*component_id) else {
+                let Some(name) =
+                    readable_component_name(world, &type_registry, component_filter, *component_id)
+                else {
                     continue;
                 };
-                response.removed.push(component_info.name().to_owned());
+                response.removed.push(name.to_owned());
             }
         }
     }
@@ -793,6 +1121,491 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/call` request coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpCallParams { function, args } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let app_function_registry = world
+        .get_resource::<AppFunctionRegistry>()
+        .ok_or_else(|| BrpError::internal("The `AppFunctionRegistry` resource is missing"))?
+        .clone();
+    let function_registry = app_function_registry.read();
+
+    let Some(dynamic_function) = function_registry.get(&function) else {
+        return Err(BrpError::function_not_found(&function));
+    };
+
+    let arg_infos = dynamic_function.info().args();
+    if arg_infos.len() != args.len() {
+        return Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message: format!(
+                "Function `{function}` expects {} arguments but received {}",
+                arg_infos.len(),
+                args.len()
+            ),
+            data: None,
+        });
+    }
+
+    let filter = world.get_resource::<RemoteComponentFilter>().copied();
+    let mut world_args = Vec::with_capacity(args.len());
+    for (arg, arg_info) in args.into_iter().zip(arg_infos) {
+        world_args.push(match arg {
+            BrpCallArg::Value(value) => {
+                let type_path = dereferenced_type_path(arg_info.type_path());
+                let Some(registration) = type_registry.get_with_type_path(type_path) else {
+                    return Err(BrpError::function_error(format!(
+                        "Unknown argument type: `{type_path}`"
+                    )));
+                };
+                let value = TypedReflectDeserializer::new(registration, &type_registry)
+                    .deserialize(&value)
+                    .map_err(|err| BrpError {
+                        code: error_codes::INVALID_PARAMS,
+                        message: format!("Argument {}: {err}", arg_info.index()),
+                        data: None,
+                    })?;
+                WorldArg::Value(value)
+            }
+            BrpCallArg::Entity(entity) => WorldArg::Entity(entity),
+            BrpCallArg::Component(entity) => {
+                let type_path = dereferenced_type_path(arg_info.type_path());
+                check_component_filter(&type_registry, filter.as_ref(), type_path)
+                    .map_err(BrpError::component_error)?;
+                WorldArg::Component(entity)
+            }
+            BrpCallArg::Resource => {
+                let type_path = dereferenced_type_path(arg_info.type_path());
+                check_resource_filter(&type_registry, filter.as_ref(), type_path)
+                    .map_err(BrpError::resource_error)?;
+                WorldArg::Resource
+            }
+        });
+    }
+
+    let result = world
+        .call_function(dynamic_function, world_args)
+        .map_err(BrpError::function_error)?;
+
+    match result {
+        Some(value) => {
+            serde_json::to_value(TypedReflectSerializer::new(value.as_ref(), &type_registry))
+                .map_err(BrpError::function_error)
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+/// Handles a `bevy/get_resource` request coming from a client.
+pub fn process_remote_get_resource_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpGetResourceParams { resource } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    check_resource_filter(
+        &type_registry,
+        world.get_resource::<RemoteComponentFilter>(),
+        &resource,
+    )
+    .map_err(BrpError::resource_error)?;
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+
+    let Some(reflected) = reflect_resource.reflect(world) else {
+        return Err(BrpError::resource_not_present(&resource));
+    };
+
+    let serializer = TypedReflectSerializer::new(reflected.as_partial_reflect(), &type_registry);
+    let value = serde_json::to_value(serializer).map_err(BrpError::resource_error)?;
+
+    serde_json::to_value(BrpGetResourceResponse { value }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/insert_resource` request coming from a client.
+pub fn process_remote_insert_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertResourceParams { resource, value } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    check_resource_filter(
+        &type_registry,
+        world.get_resource::<RemoteComponentFilter>(),
+        &resource,
+    )
+    .map_err(BrpError::resource_error)?;
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource).map_err(BrpError::resource_error)?;
+    let registration =
+        get_type_registration(&type_registry, &resource).map_err(BrpError::resource_error)?;
+
+    let reflected = TypedReflectDeserializer::new(registration, &type_registry)
+        .deserialize(&value)
+        .map_err(BrpError::resource_error)?;
+    reflect_resource.apply_or_insert(world, &*reflected, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/mutate_component` request coming from a client.
+pub fn process_remote_mutate_component_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateComponentParams {
+        entity,
+        component,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    check_component_filter(
+        &type_registry,
+        world.get_resource::<RemoteComponentFilter>(),
+        &component,
+    )
+    .map_err(BrpError::component_error)?;
+    let reflect_component =
+        get_reflect_component(&type_registry, &component).map_err(BrpError::component_error)?;
+
+    let Some(mut reflected) = reflect_component.reflect_mut(get_entity_mut(world, entity)?) else {
+        return Err(BrpError::component_not_present(&component, entity));
+    };
+
+    let field = reflected
+        .reflect_path_mut(path.as_str())
+        .map_err(BrpError::component_error)?;
+    let Some(field_type) = field.get_represented_type_info() else {
+        return Err(BrpError::component_error(format!(
+            "Field `{path}` of component `{component}` has no type information"
+        )));
+    };
+    let field_registration = get_type_registration(&type_registry, field_type.type_path())
+        .map_err(BrpError::component_error)?;
+
+    let new_value = TypedReflectDeserializer::new(field_registration, &type_registry)
+        .deserialize(&value)
+        .map_err(BrpError::component_error)?;
+    field
+        .try_apply(&*new_value)
+        .map_err(BrpError::component_error)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams { event, value } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, &event).map_err(BrpError::event_error)?;
+    let reflected = deserialize_event(&type_registry, &event, &value)?;
+
+    if !reflect_event.send(world, &*reflected, &type_registry) {
+        return Err(BrpError::event_error(format!(
+            "Event `{event}` has not been added to the app"
+        )));
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger` request coming from a client.
+pub fn process_remote_trigger_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerParams {
+        event,
+        value,
+        targets,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, &event).map_err(BrpError::event_error)?;
+    let reflected = deserialize_event(&type_registry, &event, &value)?;
+
+    for &target in &targets {
+        get_entity(world, target)?;
+    }
+    reflect_event.trigger(world, &*reflected, targets, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/events+watch` request coming from a client.
+///
+/// The first response of a watching request holds the events sent since the last
+/// [`Events::update`]. Later responses only hold the events sent since the previous one, even
+/// if the events are kept across several frames, e.g. until the next fixed timestep.
+///
+/// [`Events::update`]: bevy_ecs::event::Events::update
+pub fn process_remote_events_watching_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult<Option<Value>> {
+    let BrpEventsParams { event } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+    let reflect_event = get_reflect_event(&type_registry, &event).map_err(BrpError::event_error)?;
+
+    let request_id = world.get_resource::<WatchingRequestId>().copied();
+    let last_event_count = request_id.and_then(|id| {
+        world
+            .get_resource::<EventWatcherCursors>()
+            .and_then(|cursors| cursors.0.get(&id).copied())
+    });
+    let Some((events, event_count)) = reflect_event.read(world, last_event_count) else {
+        return Err(BrpError::event_error(format!(
+            "Event `{event}` has not been added to the app"
+        )));
+    };
+    let response = events
+        .into_iter()
+        .map(|event| {
+            serde_json::to_value(TypedReflectSerializer::new(
+                event.as_partial_reflect(),
+                &type_registry,
+            ))
+            .map_err(BrpError::event_error)
+        })
+        .collect::<Result<BrpEventsWatchingResponse, _>>()?;
+
+    if let Some(request_id) = request_id {
+        world
+            .get_resource_or_init::<EventWatcherCursors>()
+            .0
+            .insert(request_id, event_count);
+    }
+    if response.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
+/// The number of events each ongoing `bevy/events+watch` request has already read, so that it
+/// only sends newer events.
+#[derive(Resource, Default)]
+pub(crate) struct EventWatcherCursors(HashMap<WatchingRequestId, usize>);
+
+/// A system that forgets the event cursors of closed `bevy/events+watch` requests.
+pub(crate) fn remove_closed_event_watcher_cursors(
+    requests: Res<RemoteWatchingRequests>,
+    cursors: Option<ResMut<EventWatcherCursors>>,
+) {
+    let Some(mut cursors) = cursors.filter(|cursors| !cursors.0.is_empty()) else {
+        return;
+    };
+    let ongoing = requests
+        .requests
+        .iter()
+        .map(|(request_id, ..)| *request_id)
+        .collect::<HashSet<_>>();
+    cursors
+        .0
+        .retain(|request_id, _| ongoing.contains(request_id));
+}
+
+/// Handles a `bevy/list_resources` request coming from a client.
+pub fn process_remote_list_resources_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let resource_filter = world.get_resource::<RemoteComponentFilter>();
+
+    let mut response = BrpListResourcesResponse::default();
+    for registered_type in type_registry.iter() {
+        let Some(reflect_resource) = registered_type.data::<ReflectResource>() else {
+            continue;
+        };
+        if resource_filter.is_some_and(|filter| !filter.allows(registered_type)) {
+            continue;
+        }
+        if reflect_resource.reflect(world).is_some() {
+            response.push(registered_type.type_info().type_path().to_owned());
+        }
+    }
+
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/registry/types` request coming from a client.
+pub fn process_remote_registry_types_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpRegistryTypesParams { prefixes } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpRegistryTypesResponse::default();
+    for registration in type_registry.iter() {
+        let type_info = registration.type_info();
+        let type_path = type_info.type_path();
+        if !prefixes.is_empty() && !prefixes.iter().any(|prefix| type_path.starts_with(prefix)) {
+            continue;
+        }
+
+        let reflect = [
+            ("Component", registration.contains::<ReflectComponent>()),
+            ("Resource", registration.contains::<ReflectResource>()),
+            ("Default", registration.contains::<ReflectDefault>()),
+            ("Serialize", registration.contains::<ReflectSerialize>()),
+            ("Deserialize", registration.contains::<ReflectDeserialize>()),
+        ]
+        .into_iter()
+        .filter(|(_, registered)| *registered)
+        .map(|(name, _)| name.to_owned())
+        .collect();
+
+        let mut fields = Vec::new();
+        let mut variants = Vec::new();
+        match type_info {
+            TypeInfo::Struct(info) => {
+                fields.extend(
+                    info.iter()
+                        .map(|field| (field.name().to_owned(), field.type_path().to_owned())),
+                );
+            }
+            TypeInfo::TupleStruct(info) => {
+                fields.extend(
+                    info.iter()
+                        .map(|field| (field.index().to_string(), field.type_path().to_owned())),
+                );
+            }
+            TypeInfo::Tuple(info) => {
+                fields.extend(
+                    info.iter()
+                        .map(|field| (field.index().to_string(), field.type_path().to_owned())),
+                );
+            }
+            TypeInfo::Enum(info) => {
+                variants.extend(info.variant_names().iter().map(ToString::to_string));
+            }
+            _ => {}
+        }
+
+        response.push(BrpTypeInfo {
+            type_path: type_path.to_owned(),
+            short_path: type_info.type_path_table().short_path().to_owned(),
+            kind: type_info.kind().to_string(),
+            reflect,
+            fields,
+            variants,
+        });
+    }
+
+    response.sort_by(|a, b| a.type_path.cmp(&b.type_path));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/schedules` request coming from a client.
+///
+/// The schedules running while the request is processed, which are [`Main`] and the
+/// [`RemoteLast`] schedule the request is processed in, are not stored in the [`Schedules`]
+/// resource. They are listed with [`BrpScheduleInfo::running`] set and without their systems
+/// and sets.
+pub fn process_remote_schedules_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let BrpSchedulesParams { schedule } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let Some(schedules) = world.get_resource::<Schedules>() else {
+        return Err(BrpError::resource_not_present(core::any::type_name::<
+            Schedules,
+        >()));
+    };
+
+    let mut response = BrpSchedulesResponse::default();
+    for (label, schedule_data) in schedules.iter() {
+        let label = format!("{label:?}");
+        if schedule.as_ref().is_some_and(|name| *name != label) {
+            continue;
+        }
+
+        let graph = schedule_data.graph();
+        let set_name = |node: NodeId| graph.get_set_at(node).map(|set| format!("{set:?}"));
+
+        // Map each system or set to the (non-system-type) sets it is directly part of.
+        let mut parents: HashMap<NodeId, Vec<String>> = HashMap::new();
+        for (parent, child) in graph.hierarchy().graph().all_edges() {
+            if graph
+                .get_set_at(parent)
+                .is_some_and(|set| set.system_type().is_none())
+            {
+                parents.entry(child).or_default().extend(set_name(parent));
+            }
+        }
+
+        let mut system_info = |(node, system): (NodeId, &ScheduleSystem)| BrpSystemInfo {
+            name: system.name().into_owned(),
+            sets: parents.remove(&node).unwrap_or_default(),
+        };
+        // Once a schedule has run, its systems are moved out of the graph into the executable
+        // schedule.
+        let systems = match schedule_data.systems() {
+            Ok(systems) => systems.map(&mut system_info).collect(),
+            Err(_) => graph
+                .systems()
+                .map(|(node, system, _)| system_info((node, system)))
+                .collect(),
+        };
+        let sets = graph
+            .system_sets()
+            .filter(|(_, set, _)| set.system_type().is_none())
+            .map(|(_, set, _)| format!("{set:?}"))
+            .collect();
+
+        response.push(BrpScheduleInfo {
+            label,
+            systems,
+            sets,
+            running: false,
+        });
+    }
+
+    for running in [Main.intern(), RemoteLast.intern()] {
+        let label = format!("{running:?}");
+        if !schedules.contains(running) && schedule.as_ref().is_none_or(|name| *name == label) {
+            response.push(BrpScheduleInfo {
+                label,
+                systems: Vec::new(),
+                sets: Vec::new(),
+                running: true,
+            });
+        }
+    }
+
+    response.sort_by(|a, b| a.label.cmp(&b.label));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -959,6 +1772,116 @@ fn get_reflect_component<'r>(
         .ok_or_else(|| anyhow!("Component `{}` isn't reflectable", component_path))
 }
 
+/// Given a resource's type path, return the associated [`ReflectResource`] from the given
+/// `type_registry` if possible.
+fn get_reflect_resource<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r ReflectResource> {
+    get_type_registration(type_registry, resource_path)?
+        .data::<ReflectResource>()
+        .ok_or_else(|| anyhow!("Resource `{}` isn't reflectable", resource_path))
+}
+
+/// Given an event's type path, return the associated [`ReflectEvent`] from the given
+/// `type_registry` if possible.
+fn get_reflect_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+) -> AnyhowResult<&'r ReflectEvent> {
+    get_type_registration(type_registry, event_path)?
+        .data::<ReflectEvent>()
+        .ok_or_else(|| anyhow!("Event `{}` isn't reflectable", event_path))
+}
+
+/// Deserializes the value of an event of the type with the given `event_path`.
+fn deserialize_event(
+    type_registry: &TypeRegistry,
+    event_path: &str,
+    value: &Value,
+) -> Result<Box<dyn PartialReflect>, BrpError> {
+    let registration =
+        get_type_registration(type_registry, event_path).map_err(BrpError::event_error)?;
+    TypedReflectDeserializer::new(registration, type_registry)
+        .deserialize(value)
+        .map_err(BrpError::event_error)
+}
+
+/// Given a type path, return the associated [`TypeRegistration`] from the given
+/// `type_registry` if possible.
+fn get_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    type_path: &str,
+) -> AnyhowResult<&'r TypeRegistration> {
+    type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| anyhow!("Unknown type: `{}`", type_path))
+}
+
+/// Checks that clients are allowed to read the component with the given type path
+/// according to the `component_filter`, if any.
+fn check_component_filter(
+    type_registry: &TypeRegistry,
+    component_filter: Option<&RemoteComponentFilter>,
+    component_path: &str,
+) -> AnyhowResult<()> {
+    let Some(component_filter) = component_filter else {
+        return Ok(());
+    };
+
+    let registration = get_component_type_registration(type_registry, component_path)?;
+    if component_filter.allows(registration) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Component `{}` isn't accessible remotely: it isn't reflected with `{}`",
+            component_path,
+            component_filter.type_data_name()
+        ))
+    }
+}
+
+/// Checks that clients are allowed to access the resource with the given type path
+/// according to the `resource_filter`, if any.
+fn check_resource_filter(
+    type_registry: &TypeRegistry,
+    resource_filter: Option<&RemoteComponentFilter>,
+    resource_path: &str,
+) -> AnyhowResult<()> {
+    let Some(resource_filter) = resource_filter else {
+        return Ok(());
+    };
+
+    let registration = get_type_registration(type_registry, resource_path)?;
+    if resource_filter.allows(registration) {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "Resource `{}` isn't accessible remotely: it isn't reflected with `{}`",
+            resource_path,
+            resource_filter.type_data_name()
+        ))
+    }
+}
+
+/// Returns the name of the component with the given ID, unless clients aren't allowed to read
+/// it according to the `component_filter`, if any.
+fn readable_component_name<'w>(
+    world: &'w World,
+    type_registry: &TypeRegistry,
+    component_filter: Option<&RemoteComponentFilter>,
+    component_id: ComponentId,
+) -> Option<&'w str> {
+    let component_info = world.components().get_info(component_id)?;
+    if let Some(component_filter) = component_filter {
+        let registration = type_registry.get(component_info.type_id()?)?;
+        if !component_filter.allows(registration) {
+            return None;
+        }
+    }
+    Some(component_info.name())
+}
+
 /// Given a component's type path, return the associated [`TypeRegistration`] from the given
 /// `type_registry` if possible.
 fn get_component_type_registration<'r>(
@@ -969,3 +1892,320 @@ fn get_component_type_registration<'r>(
         .get_with_type_path(component_path)
         .ok_or_else(|| anyhow!("Unknown component type: `{}`", component_path))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BrpMessage, BrpSender, RemotePlugin};
+    use async_channel::Receiver;
+    use bevy_app::App;
+    use bevy_ecs::{component::Component, event::Event, system::Resource};
+    use bevy_reflect::Reflect;
+    use bevy_time::{Fixed, Time, TimePlugin, TimeUpdateStrategy};
+    use core::time::Duration;
+    use serde_json::json;
+
+    fn remote_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default());
+        // runs `PreStartup` to set up the request mailbox
+        app.update();
+        app
+    }
+
+    fn send_request(app: &App, method: &str, params: Option<Value>) -> Receiver<BrpResult> {
+        let (sender, receiver) = async_channel::unbounded();
+        app.world()
+            .resource::<BrpSender>()
+            .force_send(BrpMessage {
+                method: method.to_owned(),
+                params,
+                sender,
+            })
+            .unwrap();
+        receiver
+    }
+
+    fn request(app: &mut App, method: &str, params: Option<Value>) -> BrpResult {
+        let receiver = send_request(app, method, params);
+        app.update();
+        receiver.try_recv().unwrap()
+    }
+
+    #[test]
+    fn list_schedules() {
+        let mut app = remote_app();
+        app.add_systems(bevy_app::Last, || {})
+            .add_systems(bevy_app::Update, || {});
+        let response: BrpSchedulesResponse =
+            serde_json::from_value(request(&mut app, BRP_SCHEDULES_METHOD, None).unwrap()).unwrap();
+        let schedule = |label: &str| response.iter().find(|schedule| schedule.label == label);
+
+        for label in ["Update", "Last"] {
+            let schedule = schedule(label).unwrap();
+            assert!(!schedule.running);
+            assert_eq!(schedule.systems.len(), 1);
+        }
+        for label in ["Main", "RemoteLast"] {
+            let schedule = schedule(label).unwrap();
+            assert!(schedule.running);
+            assert!(schedule.systems.is_empty());
+        }
+
+        let response: BrpSchedulesResponse = serde_json::from_value(
+            request(
+                &mut app,
+                BRP_SCHEDULES_METHOD,
+                Some(serde_json::json!({ "schedule": "Main" })),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(response.len(), 1);
+        assert!(response[0].running);
+    }
+
+    #[derive(Event, Reflect)]
+    #[reflect(Event)]
+    struct Ping {
+        id: u32,
+    }
+
+    #[test]
+    fn watch_events_across_frames() {
+        let mut app = App::new();
+        app.add_plugins((RemotePlugin::default(), TimePlugin))
+            .add_event::<Ping>()
+            .register_type::<Ping>()
+            // Run several frames per fixed timestep, so that events are kept across frames.
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                10,
+            )))
+            .insert_resource(Time::<Fixed>::from_seconds(1.0));
+        app.update();
+
+        let params = Some(json!({ "event": core::any::type_name::<Ping>() }));
+        let watcher = send_request(&app, BRP_EVENTS_AND_WATCH_METHOD, params.clone());
+        app.world_mut().send_event(Ping { id: 1 });
+        app.update();
+        assert_eq!(watcher.try_recv().unwrap().unwrap(), json!([{ "id": 1 }]));
+
+        // A watcher started later first receives the events sent since the last event update.
+        let late_watcher = send_request(&app, BRP_EVENTS_AND_WATCH_METHOD, params);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(watcher.try_recv().is_err());
+        assert_eq!(
+            late_watcher.try_recv().unwrap().unwrap(),
+            json!([{ "id": 1 }])
+        );
+        assert!(late_watcher.try_recv().is_err());
+
+        app.world_mut().send_event(Ping { id: 2 });
+        app.update();
+        assert_eq!(watcher.try_recv().unwrap().unwrap(), json!([{ "id": 2 }]));
+        assert_eq!(
+            late_watcher.try_recv().unwrap().unwrap(),
+            json!([{ "id": 2 }])
+        );
+        assert!(watcher.try_recv().is_err());
+
+        // The cursors of closed watchers are forgotten.
+        drop(watcher);
+        app.update();
+        assert_eq!(app.world().resource::<EventWatcherCursors>().0.len(), 1);
+        drop(late_watcher);
+        app.update();
+        assert!(app.world().resource::<EventWatcherCursors>().0.is_empty());
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Visible(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Hidden(u32);
+
+    /// An app whose clients may only access components reflected with `Default`.
+    fn filtered_remote_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default().with_required_component_data::<ReflectDefault>())
+            .register_type::<Visible>()
+            .register_type::<Hidden>();
+        app.update();
+        app
+    }
+
+    fn component_names(value: &Value) -> Vec<&str> {
+        value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|name| name.as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn list_filtered_components() {
+        let mut app = filtered_remote_app();
+        let entity = app.world_mut().spawn((Visible(0), Hidden(0))).id();
+        let visible = core::any::type_name::<Visible>();
+        let hidden = core::any::type_name::<Hidden>();
+
+        let response = request(&mut app, BRP_LIST_METHOD, None).unwrap();
+        assert!(component_names(&response).contains(&visible));
+        assert!(!component_names(&response).contains(&hidden));
+
+        let response =
+            request(&mut app, BRP_LIST_METHOD, Some(json!({ "entity": entity }))).unwrap();
+        assert_eq!(component_names(&response), [visible]);
+    }
+
+    #[test]
+    fn watch_filtered_components() {
+        let mut app = filtered_remote_app();
+        let entity = app.world_mut().spawn_empty().id();
+        let watcher = send_request(
+            &app,
+            BRP_LIST_AND_WATCH_METHOD,
+            Some(json!({ "entity": entity })),
+        );
+        app.update();
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert((Visible(0), Hidden(0)));
+        app.update();
+        let response: BrpListWatchingResponse =
+            serde_json::from_value(watcher.try_recv().unwrap().unwrap()).unwrap();
+        assert_eq!(response.added, [core::any::type_name::<Visible>()]);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<(Visible, Hidden)>();
+        app.update();
+        let response: BrpListWatchingResponse =
+            serde_json::from_value(watcher.try_recv().unwrap().unwrap()).unwrap();
+        assert_eq!(response.removed, [core::any::type_name::<Visible>()]);
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource, Default)]
+    struct VisibleResource(u32);
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct HiddenResource(u32);
+
+    #[test]
+    fn filtered_resources() {
+        let mut app = filtered_remote_app();
+        app.register_type::<VisibleResource>()
+            .register_type::<HiddenResource>()
+            .insert_resource(VisibleResource(1))
+            .insert_resource(HiddenResource(2));
+        let visible = core::any::type_name::<VisibleResource>();
+        let hidden = core::any::type_name::<HiddenResource>();
+
+        let response = request(&mut app, BRP_LIST_RESOURCES_METHOD, None).unwrap();
+        assert!(component_names(&response).contains(&visible));
+        assert!(!component_names(&response).contains(&hidden));
+
+        let response = request(
+            &mut app,
+            BRP_GET_RESOURCE_METHOD,
+            Some(json!({ "resource": visible })),
+        )
+        .unwrap();
+        assert_eq!(response, json!({ "value": 1 }));
+
+        let error = request(
+            &mut app,
+            BRP_GET_RESOURCE_METHOD,
+            Some(json!({ "resource": hidden })),
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_ERROR);
+
+        let error = request(
+            &mut app,
+            BRP_INSERT_RESOURCE_METHOD,
+            Some(json!({ "resource": hidden, "value": 3 })),
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_ERROR);
+        assert_eq!(app.world().resource::<HiddenResource>().0, 2);
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn call_with_filtered_arguments() {
+        fn read_visible(visible: &Visible) -> u32 {
+            visible.0
+        }
+        fn increment_hidden(hidden: &mut Hidden) -> u32 {
+            hidden.0 += 1;
+            hidden.0
+        }
+        fn read_hidden_resource(resource: &HiddenResource) -> u32 {
+            resource.0
+        }
+
+        let mut app = filtered_remote_app();
+        app.register_type::<HiddenResource>()
+            .insert_resource(HiddenResource(2));
+        {
+            let functions = app
+                .world_mut()
+                .get_resource_or_init::<AppFunctionRegistry>();
+            let mut functions = functions.write();
+            functions
+                .register_with_name("read_visible", read_visible)
+                .unwrap();
+            functions
+                .register_with_name("increment_hidden", increment_hidden)
+                .unwrap();
+            functions
+                .register_with_name("read_hidden_resource", read_hidden_resource)
+                .unwrap();
+        }
+        let entity = app.world_mut().spawn((Visible(4), Hidden(0))).id();
+        let call =
+            |function: &str, arg: Value| Some(json!({ "function": function, "args": [arg] }));
+
+        let params = call("read_visible", json!({ "component": entity }));
+        assert_eq!(
+            request(&mut app, BRP_CALL_METHOD, params).unwrap(),
+            json!(4)
+        );
+
+        let params = call("increment_hidden", json!({ "component": entity }));
+        let error = request(&mut app, BRP_CALL_METHOD, params).unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+        assert_eq!(app.world().get::<Hidden>(entity).unwrap().0, 0);
+
+        let params = call("read_hidden_resource", json!("resource"));
+        let error = request(&mut app, BRP_CALL_METHOD, params).unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_ERROR);
+    }
+
+    #[test]
+    fn mutate_filtered_components() {
+        let mut app = filtered_remote_app();
+        let entity = app.world_mut().spawn((Visible(0), Hidden(0))).id();
+        let mutate = |component: &str| {
+            Some(json!({ "entity": entity, "component": component, "path": ".0", "value": 1 }))
+        };
+
+        let params = mutate(core::any::type_name::<Visible>());
+        request(&mut app, BRP_MUTATE_COMPONENT_METHOD, params).unwrap();
+        assert_eq!(app.world().get::<Visible>(entity).unwrap().0, 1);
+
+        let params = mutate(core::any::type_name::<Hidden>());
+        let error = request(&mut app, BRP_MUTATE_COMPONENT_METHOD, params).unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+        assert_eq!(app.world().get::<Hidden>(entity).unwrap().0, 0);
+    }
+}
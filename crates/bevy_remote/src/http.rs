@@ -9,7 +9,8 @@
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    error_codes, is_authorized, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult,
+    BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -100,6 +101,8 @@ pub struct RemoteHttpPlugin {
     port: u16,
     /// The headers that Bevy will include in its HTTP responses
     headers: Headers,
+    /// The token that clients must provide in an `Authorization: Bearer` header, if any.
+    bearer_token: Option<String>,
 }
 
 impl Default for RemoteHttpPlugin {
@@ -108,6 +111,7 @@ impl Default for RemoteHttpPlugin {
             address: DEFAULT_ADDR,
             port: DEFAULT_PORT,
             headers: Headers::new(),
+            bearer_token: None,
         }
     }
 }
@@ -117,6 +121,7 @@ impl Plugin for RemoteHttpPlugin {
         app.insert_resource(HostAddress(self.address))
             .insert_resource(HostPort(self.port))
             .insert_resource(HostHeaders(self.headers.clone()))
+            .insert_resource(HostBearerToken(self.bearer_token.clone()))
             .add_systems(Startup, start_http_server);
     }
 }
@@ -170,6 +175,15 @@ impl RemoteHttpPlugin {
         self.headers = self.headers.insert(name, value);
         self
     }
+    /// Require clients to authenticate with the given bearer token.
+    ///
+    /// Requests without an `Authorization: Bearer <token>` header carrying this token are
+    /// rejected with a `401 Unauthorized` status.
+    #[must_use]
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
 }
 
 /// A resource containing the IP address that Bevy will host on.
@@ -191,12 +205,17 @@ pub struct HostPort(pub u16);
 #[derive(Debug, Resource)]
 struct HostHeaders(pub Headers);
 
+/// A resource containing the bearer token that clients must authenticate with, if any.
+#[derive(Resource)]
+struct HostBearerToken(Option<String>);
+
 /// A system that starts up the Bevy Remote Protocol HTTP server.
 fn start_http_server(
     request_sender: Res<BrpSender>,
     address: Res<HostAddress>,
     remote_port: Res<HostPort>,
     headers: Res<HostHeaders>,
+    bearer_token: Res<HostBearerToken>,
 ) {
     IoTaskPool::get()
         .spawn(server_main(
@@ -204,6 +223,7 @@ fn start_http_server(
             remote_port.0,
             request_sender.clone(),
             headers.0.clone(),
+            bearer_token.0.clone(),
         ))
         .detach();
 }
@@ -214,11 +234,13 @@ async fn server_main(
     port: u16,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    bearer_token: Option<String>,
 ) -> AnyhowResult<()> {
     listen(
         Async::<TcpListener>::bind((address, port))?,
         &request_sender,
         &headers,
+        bearer_token.as_deref(),
     )
     .await
 }
@@ -227,15 +249,17 @@ async fn listen(
     listener: Async<TcpListener>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    bearer_token: Option<&str>,
 ) -> AnyhowResult<()> {
     loop {
         let (client, _) = listener.accept().await?;
 
         let request_sender = request_sender.clone();
         let headers = headers.clone();
+        let bearer_token = bearer_token.map(ToOwned::to_owned);
         IoTaskPool::get()
             .spawn(async move {
-                let _ = handle_client(client, request_sender, headers).await;
+                let _ = handle_client(client, request_sender, headers, bearer_token).await;
             })
             .detach();
     }
@@ -245,13 +269,14 @@ async fn handle_client(
     client: Async<TcpStream>,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    bearer_token: Option<String>,
 ) -> AnyhowResult<()> {
     http1::Builder::new()
         .timer(SmolTimer::new())
         .serve_connection(
             FuturesIo::new(client),
             service::service_fn(|request| {
-                process_request_batch(request, &request_sender, &headers)
+                process_request_batch(request, &request_sender, &headers, bearer_token.as_deref())
             }),
         )
         .await?;
@@ -265,7 +290,34 @@ async fn process_request_batch(
     request: Request<Incoming>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    bearer_token: Option<&str>,
 ) -> AnyhowResult<Response<BrpHttpBody>> {
+    if let Some(token) = bearer_token {
+        let authorization = request
+            .headers()
+            .get(hyper::header::AUTHORIZATION)
+            .map(HeaderValue::as_bytes);
+        if !is_authorized(authorization, token) {
+            let serialized =
+                serde_json::to_string(&BrpResponse::new(None, Err(BrpError::unauthorized())))?;
+            let mut response =
+                Response::new(BrpHttpBody::Complete(Full::new(Bytes::from(serialized))));
+            *response.status_mut() = hyper::StatusCode::UNAUTHORIZED;
+            response.headers_mut().insert(
+                hyper::header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            );
+            response.headers_mut().insert(
+                hyper::header::WWW_AUTHENTICATE,
+                HeaderValue::from_static("Bearer"),
+            );
+            for (key, value) in &headers.headers {
+                response.headers_mut().insert(key, value.clone());
+            }
+            return Ok(response);
+        }
+    }
+
     let batch_bytes = request.into_body().collect().await?.to_bytes();
     let batch: Result<BrpBatch, _> = serde_json::from_slice(&batch_bytes);
 
@@ -458,3 +510,85 @@ impl Body for BrpHttpBody {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::{
+        futures_lite::{AsyncReadExt, AsyncWriteExt},
+        TaskPool,
+    };
+    use serde_json::json;
+
+    /// Starts a server requiring the `bearer_token`, and returns its address and the receiver
+    /// of the requests forwarded to the world.
+    fn start_server(bearer_token: &str) -> (std::net::SocketAddr, Receiver<BrpMessage>) {
+        IoTaskPool::get_or_init(TaskPool::new);
+        let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let (request_sender, request_receiver) = async_channel::unbounded();
+        let bearer_token = bearer_token.to_owned();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = listen(
+                    listener,
+                    &request_sender,
+                    &Headers::new(),
+                    Some(&bearer_token),
+                )
+                .await;
+            })
+            .detach();
+        (address, request_receiver)
+    }
+
+    /// Posts a `bevy/list` request with the given `Authorization` header and returns the raw response.
+    async fn post(address: std::net::SocketAddr, authorization: Option<&str>) -> String {
+        let body = json!({ "jsonrpc": "2.0", "method": "bevy/list", "id": 0 }).to_string();
+        let authorization = authorization
+            .map(|authorization| format!("Authorization: {authorization}\r\n"))
+            .unwrap_or_default();
+        let request = format!(
+            "POST / HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\
+            Content-Type: application/json\r\nContent-Length: {}\r\n{authorization}\r\n{body}",
+            body.len()
+        );
+        let mut stream = Async::<TcpStream>::connect(address).await.unwrap();
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    }
+
+    #[test]
+    fn accept_authorized_requests() {
+        let (address, requests) = start_server("secret");
+        bevy_tasks::block_on(async {
+            let response = bevy_tasks::futures_lite::future::zip(
+                post(address, Some("Bearer secret")),
+                async {
+                    let message = requests.recv().await.unwrap();
+                    assert_eq!(message.method, "bevy/list");
+                    message.sender.send(Ok(json!(["a"]))).await.unwrap();
+                },
+            )
+            .await
+            .0;
+            assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+            assert!(response.contains(r#"{"jsonrpc":"2.0","id":0,"result":["a"]}"#));
+        });
+    }
+
+    #[test]
+    fn reject_unauthorized_requests() {
+        let (address, requests) = start_server("secret");
+        bevy_tasks::block_on(async {
+            for authorization in [None, Some("Bearer wrong"), Some("secret")] {
+                let response = post(address, authorization).await;
+                assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+                assert!(response.contains("www-authenticate: Bearer"));
+                assert!(requests.try_recv().is_err());
+            }
+        });
+    }
+}
@@ -3,8 +3,9 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! over HTTP, or the `RemoteWebSocketPlugin` (behind the `websocket` feature, which `bevy` exposes as
+//! `bevy_remote_websocket`) to enable communication over WebSocket. These *remote clients* can inspect
+//! and alter the state of the entity-component system.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -212,6 +213,104 @@
 //!
 //! `result`: An array of fully-qualified type names of components.
 //!
+//! ### `bevy/get_resource`
+//!
+//! Retrieve the value of a resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to fetch.
+//!
+//! `result`:
+//! - `value`: The serialized value of the resource.
+//!
+//! ### `bevy/insert_resource`
+//!
+//! Insert a resource into the world, replacing any existing value.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to insert.
+//! - `value`: The serialized value of the resource.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/mutate_component`
+//!
+//! Set the value of a single field of a component on an entity.
+//!
+//! `params`:
+//! - `entity`: The ID of the entity whose component will be mutated.
+//! - `component`: The [fully-qualified type name] of the component to mutate.
+//! - `path` (optional): The [reflect path] of the field to set, e.g. `translation.x`.
+//!   If omitted or empty, the whole component is replaced.
+//! - `value`: The serialized value of the field.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_resources`
+//!
+//! List all reflectable resources present in the world.
+//!
+//! `result`: An array of fully-qualified type names of resources.
+//!
+//! ### bevy/registry/types
+//!
+//! List the types in the type registry along with their structure.
+//!
+//! `params` (optional):
+//! - `prefixes` (optional): An array of type path prefixes. If not empty, only types whose
+//!   fully-qualified type name starts with one of them are listed.
+//!
+//! `result`: An array, each of which is an object containing:
+//! - `type_path`: The fully-qualified type name of the type.
+//! - `short_path`: The short type name of the type.
+//! - `kind`: The kind of the type, e.g. `struct`, `tuple struct`, `enum` or `opaque`.
+//! - `reflect`: An array of the notable reflected traits of the type: `Component`, `Resource`,
+//!   `Default`, `Serialize` and `Deserialize`.
+//! - `fields`: For structs, tuple structs and tuples, an array of `[name, type_path]` pairs.
+//!   Omitted for other kinds.
+//! - `variants`: For enums, an array of variant names. Omitted for other kinds.
+//!
+//! ### bevy/schedules
+//!
+//! List the schedules of the app along with their systems and system sets. The schedules that
+//! are running while the request is processed, `Main` and `RemoteLast`, are listed with
+//! `running` set and without their systems and sets.
+//!
+//! `params` (optional):
+//! - `schedule` (optional): The name of a schedule label, e.g. `Update`. If present, only that
+//!   schedule is listed.
+//!
+//! `result`: An array, each of which is an object containing:
+//! - `label`: The name of the schedule label.
+//! - `running`: Whether the schedule was running while the request was processed.
+//! - `systems`: An array of objects with the `name` of each system and the `sets` it is
+//!   directly part of.
+//! - `sets`: An array of the names of the system sets configured in the schedule.
+//!
+//! ### `bevy/send_event`
+//!
+//! Send an event into the world, to be read by `EventReader`s. The event type must be registered
+//! with `#[reflect(Event)]` and added to the app.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The serialized value of the event.
+//!
+//! `result`: null.
+//!
+//! ### bevy/trigger
+//!
+//! Trigger an event, running any observers watching for it. The event type must be registered
+//! with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The serialized value of the event.
+//! - `targets` (optional): An array of entity IDs to trigger the event for. If omitted or empty,
+//!   only global observers are run.
+//!
+//! `result`: null.
+//!
 //! ### bevy/get+watch
 //!
 //! Watch the values of one or more components from an entity.
@@ -256,6 +355,35 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### bevy/events+watch
+//!
+//! Watch the events of a given type sent into the world. The event type must be registered
+//! with `#[reflect(Event)]` and added to the app.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to watch.
+//!
+//! `result`: An array of the serialized values of the events sent since the previous result, or
+//! since the last event update for the first result. Frames in which no event was sent produce no
+//! result.
+//!
+//! ### bevy/call
+//!
+//! Call a function registered in the `AppFunctionRegistry` by name. Requires the
+//! `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name the function was registered with.
+//! - `args` (optional): An array with one entry per function parameter, each of which is one of:
+//!   - `{ "value": <value> }`: A value deserialized as the type of the parameter.
+//!   - `{ "entity": <id> }`: An entity ID passed by value.
+//!   - `{ "component": <id> }`: The component of the parameter's type on the given entity.
+//!     If the parameter is a mutable reference, changes are written back to the entity.
+//!   - `"resource"`: The resource of the parameter's type. If the parameter is a mutable
+//!     reference, changes are written back to the resource.
+//!
+//! `result`: The serialized return value of the function, or null if it returns `()`.
+//!
 //!
 //! ## Custom methods
 //!
@@ -296,9 +424,29 @@
 //! Arbitrary system parameters can be used in conjunction with the optional `Value` input. The
 //! handler system will always run with exclusive `World` access.
 //!
+//! ## Access control
+//!
+//! Builds that are reachable from other machines can limit what clients are able to do:
+//!
+//! - Each transport can require a bearer token with `with_bearer_token`, rejecting connections
+//!   and requests without a matching `Authorization: Bearer <token>` header.
+//! - [`RemotePlugin::with_allowed_methods`] and [`RemotePlugin::with_denied_methods`] restrict
+//!   the methods clients may use, and [`RemotePlugin::read_only`] denies all built-in methods
+//!   that modify the world. Rejected requests fail with a `METHOD_NOT_ALLOWED` error.
+//! - [`RemotePlugin::with_required_component_data`] restricts the components and resources clients
+//!   may read, list, mutate and pass to functions to those reflected with a given type data, such
+//!   as `#[reflect(Inspectable)]`.
+//!
+//! ```ignore
+//! RemotePlugin::default()
+//!     .read_only()
+//!     .with_required_component_data::<ReflectInspectable>()
+//! ```
+//!
 //! [the `serde` documentation]: https://serde.rs/
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
+//! [reflect path]: bevy_reflect::GetPath
 
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
@@ -309,7 +457,9 @@ use bevy_ecs::{
     system::{Commands, In, IntoSystem, ResMut, Resource, System, SystemId},
     world::World,
 };
-use bevy_utils::{prelude::default, HashMap};
+use bevy_reflect::{TypeData, TypeRegistration};
+use bevy_utils::{prelude::default, HashMap, HashSet};
+use core::any::TypeId;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::RwLock;
@@ -317,6 +467,8 @@ use std::sync::RwLock;
 pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -329,6 +481,10 @@ const CHANNEL_SIZE: usize = 16;
 pub struct RemotePlugin {
     /// The verbs that the server will recognize and respond to.
     methods: RwLock<Vec<(String, RemoteMethodHandler)>>,
+    /// Which of the verbs clients are allowed to use.
+    method_access: RemoteMethodAccess,
+    /// Which components clients are allowed to read.
+    component_filter: Option<RemoteComponentFilter>,
 }
 
 impl RemotePlugin {
@@ -337,6 +493,8 @@ impl RemotePlugin {
     fn empty() -> Self {
         Self {
             methods: RwLock::new(vec![]),
+            method_access: RemoteMethodAccess::default(),
+            component_filter: None,
         }
     }
 
@@ -367,11 +525,79 @@ impl RemotePlugin {
         ));
         self
     }
+
+    /// Only allow clients to use the methods with the given names.
+    ///
+    /// Requests for any other method are rejected. Calling this more than once
+    /// extends the list of allowed methods.
+    #[must_use]
+    pub fn with_allowed_methods(
+        mut self,
+        methods: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.method_access
+            .allowed
+            .get_or_insert_with(HashSet::default)
+            .extend(methods.into_iter().map(Into::into));
+        self
+    }
+
+    /// Reject requests from clients for the methods with the given names.
+    ///
+    /// Denied methods take precedence over [allowed](Self::with_allowed_methods) ones.
+    #[must_use]
+    pub fn with_denied_methods(
+        mut self,
+        methods: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.method_access
+            .denied
+            .extend(methods.into_iter().map(Into::into));
+        self
+    }
+
+    /// Reject requests from clients for all built-in methods that modify the world,
+    /// such as `bevy/spawn` and `bevy/destroy`.
+    ///
+    /// Custom methods are not affected; use [`Self::with_denied_methods`] for those.
+    #[must_use]
+    pub fn read_only(self) -> Self {
+        let plugin = self.with_denied_methods([
+            builtin_methods::BRP_SPAWN_METHOD,
+            builtin_methods::BRP_INSERT_METHOD,
+            builtin_methods::BRP_REMOVE_METHOD,
+            builtin_methods::BRP_DESTROY_METHOD,
+            builtin_methods::BRP_REPARENT_METHOD,
+            builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+            builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
+            builtin_methods::BRP_SEND_EVENT_METHOD,
+            builtin_methods::BRP_TRIGGER_METHOD,
+        ]);
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_denied_methods([builtin_methods::BRP_CALL_METHOD]);
+
+        plugin
+    }
+
+    /// Only allow clients to access components and resources whose type registration contains
+    /// the type data `D`, i.e. types annotated with `#[reflect(D)]`.
+    ///
+    /// For components, this applies to the built-in `bevy/get`, `bevy/get+watch`, `bevy/query`,
+    /// `bevy/mutate_component`, `bevy/list` and `bevy/list+watch` methods, which treat any
+    /// other component as unknown, or leave it out of their listings. For resources, this applies
+    /// to `bevy/get_resource`, `bevy/insert_resource` and `bevy/list_resources`. `bevy/call`
+    /// rejects component and resource arguments of any other type.
+    #[must_use]
+    pub fn with_required_component_data<D: TypeData>(mut self) -> Self {
+        self.component_filter = Some(RemoteComponentFilter::new::<D>());
+        self
+    }
 }
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -404,6 +630,38 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_METHOD,
                 builtin_methods::process_remote_list_request,
             )
+            .with_method(
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::process_remote_get_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+                builtin_methods::process_remote_insert_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
+                builtin_methods::process_remote_mutate_component_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::process_remote_list_resources_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REGISTRY_TYPES_METHOD,
+                builtin_methods::process_remote_registry_types_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULES_METHOD,
+                builtin_methods::process_remote_schedules_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_METHOD,
+                builtin_methods::process_remote_trigger_request,
+            )
             .with_watching_method(
                 builtin_methods::BRP_GET_AND_WATCH_METHOD,
                 builtin_methods::process_remote_get_watching_request,
@@ -412,6 +670,18 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_watching_method(
+                builtin_methods::BRP_EVENTS_AND_WATCH_METHOD,
+                builtin_methods::process_remote_events_watching_request,
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_CALL_METHOD,
+            builtin_methods::process_remote_call_request,
+        );
+
+        plugin
     }
 }
 
@@ -439,7 +709,12 @@ impl Plugin for RemotePlugin {
             .resource_mut::<MainScheduleOrder>()
             .insert_after(Last, RemoteLast);
 
+        if let Some(component_filter) = self.component_filter {
+            app.insert_resource(component_filter);
+        }
+
         app.insert_resource(remote_methods)
+            .insert_resource(self.method_access.clone())
             .init_resource::<RemoteWatchingRequests>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
@@ -452,7 +727,12 @@ impl Plugin for RemotePlugin {
                     (process_remote_requests, process_ongoing_watching_requests)
                         .chain()
                         .in_set(RemoteSet::ProcessRequests),
-                    remove_closed_watching_requests.in_set(RemoteSet::Cleanup),
+                    (
+                        remove_closed_watching_requests,
+                        builtin_methods::remove_closed_event_watcher_cursors,
+                    )
+                        .chain()
+                        .in_set(RemoteSet::Cleanup),
                 ),
             );
     }
@@ -539,9 +819,76 @@ impl RemoteMethods {
     }
 }
 
+/// Determines which methods clients are allowed to use.
+///
+/// This is configured using [`RemotePlugin::with_allowed_methods`],
+/// [`RemotePlugin::with_denied_methods`] and [`RemotePlugin::read_only`].
+#[derive(Debug, Resource, Clone, Default)]
+pub struct RemoteMethodAccess {
+    /// If present, only these methods are allowed.
+    allowed: Option<HashSet<String>>,
+    /// These methods are never allowed.
+    denied: HashSet<String>,
+}
+
+impl RemoteMethodAccess {
+    /// Returns whether clients are allowed to use the method with the given name.
+    pub fn is_allowed(&self, method: &str) -> bool {
+        !self.denied.contains(method)
+            && self
+                .allowed
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(method))
+    }
+}
+
+/// Restricts the components and resources clients are allowed to access to those whose type
+/// registration contains a given type data.
+///
+/// This is configured using [`RemotePlugin::with_required_component_data`]. If this
+/// resource is absent, all reflectable components and resources can be accessed.
+#[derive(Debug, Resource, Clone, Copy)]
+pub struct RemoteComponentFilter {
+    /// The [`TypeId`] of the required type data.
+    type_data: TypeId,
+    /// The name of the required type data, for error messages.
+    type_data_name: &'static str,
+}
+
+impl RemoteComponentFilter {
+    /// Creates a filter that only allows components with the type data `D`.
+    pub fn new<D: TypeData>() -> Self {
+        Self {
+            type_data: TypeId::of::<D>(),
+            type_data_name: core::any::type_name::<D>(),
+        }
+    }
+
+    /// Returns whether clients are allowed to access the component or resource with the given
+    /// type registration.
+    pub fn allows(&self, registration: &TypeRegistration) -> bool {
+        registration.contains_by_id(self.type_data)
+    }
+
+    /// The name of the type data required by this filter.
+    pub fn type_data_name(&self) -> &'static str {
+        self.type_data_name
+    }
+}
+
 /// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
 #[derive(Debug, Resource, Default)]
-pub struct RemoteWatchingRequests(Vec<(BrpMessage, RemoteWatchingMethodSystemId)>);
+pub struct RemoteWatchingRequests {
+    requests: Vec<(WatchingRequestId, BrpMessage, RemoteWatchingMethodSystemId)>,
+    next_id: u64,
+}
+
+/// A unique identifier of an ongoing watching request.
+///
+/// This resource is inserted while the handler of a watching request runs, so that handlers can
+/// keep track of what they already sent to each of their clients.
+#[derive(Debug, Resource, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WatchingRequestId(u64);
 
 /// A single request from a Bevy Remote Protocol client to the server,
 /// serialized in JSON.
@@ -680,6 +1027,76 @@ impl BrpError {
         }
     }
 
+    /// An arbitrary resource error. Possibly related to reflection.
+    #[must_use]
+    pub fn resource_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::RESOURCE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Resource wasn't present in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// No function was registered with the given name.
+    #[must_use]
+    pub fn function_not_found(function: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_FOUND,
+            message: format!("Function `{function}` not found"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary error while resolving arguments for or calling a function.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// The server doesn't allow clients to use the method.
+    #[must_use]
+    pub fn method_not_allowed(method: &str) -> Self {
+        Self {
+            code: error_codes::METHOD_NOT_ALLOWED,
+            message: format!("Method `{method}` is not allowed"),
+            data: None,
+        }
+    }
+
+    /// The client didn't provide valid credentials.
+    #[must_use]
+    pub fn unauthorized() -> Self {
+        Self {
+            code: error_codes::UNAUTHORIZED,
+            message: "Missing or invalid bearer token".to_owned(),
+            data: None,
+        }
+    }
+
     /// Attempt to reparent an entity to itself.
     #[must_use]
     pub fn self_reparent(entity: Entity) -> Self {
@@ -724,6 +1141,27 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not find a registered function.
+    pub const FUNCTION_NOT_FOUND: i16 = -23405;
+
+    /// Could not resolve the arguments of or call a function.
+    pub const FUNCTION_ERROR: i16 = -23406;
+
+    /// Could not reflect or find resource.
+    pub const RESOURCE_ERROR: i16 = -23407;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23408;
+
+    /// Could not reflect, find or send event.
+    pub const EVENT_ERROR: i16 = -23409;
+
+    /// The server doesn't allow clients to use the method.
+    pub const METHOD_NOT_ALLOWED: i16 = -23410;
+
+    /// The client didn't provide valid credentials.
+    pub const UNAUTHORIZED: i16 = -23411;
 }
 
 /// The result of a request.
@@ -798,6 +1236,16 @@ fn process_remote_requests(world: &mut World) {
             return;
         };
 
+        if !world
+            .resource::<RemoteMethodAccess>()
+            .is_allowed(&message.method)
+        {
+            let _ = message
+                .sender
+                .force_send(Err(BrpError::method_not_allowed(&message.method)));
+            continue;
+        }
+
         match handler {
             RemoteMethodSystemId::Instant(id) => {
                 let result = match world.run_system_with_input(id, message.params) {
@@ -815,20 +1263,39 @@ fn process_remote_requests(world: &mut World) {
                 let _ = message.sender.force_send(result);
             }
             RemoteMethodSystemId::Watching(id) => {
-                world
-                    .resource_mut::<RemoteWatchingRequests>()
-                    .0
-                    .push((message, id));
+                let mut requests = world.resource_mut::<RemoteWatchingRequests>();
+                let request_id = WatchingRequestId(requests.next_id);
+                requests.next_id += 1;
+                requests.requests.push((request_id, message, id));
             }
         }
     }
 }
 
+/// Returns whether the value of an `Authorization` header carries the given bearer `token`.
+///
+/// The comparison takes the same time wherever the first mismatch is, so as not to leak
+/// the token through timing.
+#[cfg(any(feature = "http", feature = "websocket"))]
+fn is_authorized(authorization: Option<&[u8]>, token: &str) -> bool {
+    let Some(provided) = authorization.and_then(|value| value.strip_prefix(b"Bearer ")) else {
+        return false;
+    };
+
+    provided.len() == token.len()
+        && provided
+            .iter()
+            .zip(token.as_bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
 /// A system that checks all ongoing watching requests for changes that should be sent
 /// and handles it if so.
 fn process_ongoing_watching_requests(world: &mut World) {
     world.resource_scope::<RemoteWatchingRequests, ()>(|world, requests| {
-        for (message, system_id) in requests.0.iter() {
+        for (request_id, message, system_id) in requests.requests.iter() {
+            world.insert_resource(*request_id);
             let handler_result = process_single_ongoing_watching_request(world, message, system_id);
             let sender_result = match handler_result {
                 Ok(Some(value)) => message.sender.try_send(Ok(value)),
@@ -841,6 +1308,7 @@ fn process_ongoing_watching_requests(world: &mut World) {
                 message.sender.close();
             }
         }
+        world.remove_resource::<WatchingRequestId>();
     });
 }
 
@@ -859,13 +1327,13 @@ fn process_single_ongoing_watching_request(
 }
 
 fn remove_closed_watching_requests(mut requests: ResMut<RemoteWatchingRequests>) {
-    for i in (0..requests.0.len()).rev() {
-        let Some((message, _)) = requests.0.get(i) else {
+    for i in (0..requests.requests.len()).rev() {
+        let Some((_, message, _)) = requests.requests.get(i) else {
             unreachable!()
         };
 
         if message.sender.is_closed() {
-            requests.0.swap_remove(i);
+            requests.requests.swap_remove(i);
         }
     }
 }
@@ -0,0 +1,684 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Clients send JSON-RPC requests (or batches of requests) as text messages, and
+//! the responses are sent back as text messages carrying the same `id`. Because
+//! responses may arrive out of order, every request should have a unique `id`.
+//!
+//! Watching methods such as `bevy/get+watch` become *subscriptions*: each change
+//! is pushed to the client as a separate response with the `id` of the watching
+//! request, and a single connection may have any number of subscriptions active
+//! at the same time. A subscription is cancelled by sending a `bevy/unwatch`
+//! request with the `id` of the watching request as its parameter:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "method": "bevy/unwatch",
+//!     "id": 1,
+//!     "params": { "id": 0 }
+//! }
+//! ```
+//!
+//! All subscriptions of a connection are cancelled when it is closed.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    error_codes, is_authorized, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpSender,
+};
+use anyhow::Result as AnyhowResult;
+use async_channel::Sender;
+use async_io::Async;
+use async_tungstenite::tungstenite::{
+    handshake::server::{ErrorResponse, Request, Response},
+    http, Message,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::{Res, Resource};
+use bevy_tasks::{futures_lite::future, futures_lite::StreamExt, IoTaskPool, Task};
+use core::net::{IpAddr, Ipv4Addr};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is one above the default port of the HTTP transport so that both can
+/// be used at the same time.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The method path for a `bevy/unwatch` request.
+///
+/// This method is handled by the WebSocket transport itself and cancels the
+/// subscription started by an earlier watching request.
+pub const BRP_UNWATCH_METHOD: &str = "bevy/unwatch";
+
+/// `bevy/unwatch`: Cancels the subscription of a watching request on the same
+/// connection.
+///
+/// The server responds with a null.
+#[derive(Debug, Deserialize, Clone)]
+pub struct BrpUnwatchParams {
+    /// The `id` of the watching request to cancel.
+    pub id: Value,
+}
+
+/// Add this plugin to your [`App`] to allow remote connections over WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+    /// The token that clients must provide in an `Authorization: Bearer` header, if any.
+    bearer_token: Option<String>,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+            bearer_token: None,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHostAddress(self.address))
+            .insert_resource(WebSocketHostPort(self.port))
+            .insert_resource(WebSocketBearerToken(self.bearer_token.clone()))
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Require clients to authenticate with the given bearer token.
+    ///
+    /// Handshakes without an `Authorization: Bearer <token>` header carrying this token are
+    /// rejected with a `401 Unauthorized` status.
+    #[must_use]
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+}
+
+/// A resource containing the IP address that Bevy will host the WebSocket server on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the IP address that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostAddress(pub IpAddr);
+
+/// A resource containing the port number that Bevy will listen on for WebSocket connections.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the port that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostPort(pub u16);
+
+/// A resource containing the bearer token that clients must authenticate with, if any.
+#[derive(Resource)]
+struct WebSocketBearerToken(Option<String>);
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    address: Res<WebSocketHostAddress>,
+    remote_port: Res<WebSocketHostPort>,
+    bearer_token: Res<WebSocketBearerToken>,
+) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            address.0,
+            remote_port.0,
+            request_sender.clone(),
+            bearer_token.0.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+    bearer_token: Option<String>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        let bearer_token = bearer_token.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender, bearer_token).await;
+            })
+            .detach();
+    }
+}
+
+/// Something that happened on a single connection.
+enum ConnectionEvent {
+    /// A message (or error, or the end of the stream) was received from the client.
+    Incoming(Option<Result<Message, async_tungstenite::tungstenite::Error>>),
+    /// A serialized response is ready to be sent to the client.
+    Outgoing(String),
+}
+
+/// The subscriptions of a single connection, keyed by the serialized `id` of
+/// their watching request.
+///
+/// Dropping a subscription's task drops its receiver, which causes the watching
+/// request to be removed from the world.
+type Subscriptions = HashMap<String, Task<()>>;
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+    bearer_token: Option<String>,
+) -> AnyhowResult<()> {
+    #[expect(
+        clippy::result_large_err,
+        reason = "The handshake callback signature is defined by `tungstenite`."
+    )]
+    let authenticate = |request: &Request, response: Response| {
+        let Some(token) = &bearer_token else {
+            return Ok(response);
+        };
+        let authorization = request
+            .headers()
+            .get(http::header::AUTHORIZATION)
+            .map(http::HeaderValue::as_bytes);
+        if is_authorized(authorization, token) {
+            return Ok(response);
+        }
+
+        let mut response = ErrorResponse::new(Some(BrpError::unauthorized().message));
+        *response.status_mut() = http::StatusCode::UNAUTHORIZED;
+        response.headers_mut().insert(
+            http::header::WWW_AUTHENTICATE,
+            http::HeaderValue::from_static("Bearer"),
+        );
+        Err(response)
+    };
+    let mut socket = async_tungstenite::accept_hdr_async(client, authenticate).await?;
+
+    // Responses are produced by tasks running concurrently with the connection,
+    // so they are funneled through this channel to the socket.
+    let (outgoing_sender, outgoing_receiver) = async_channel::unbounded::<String>();
+    let mut subscriptions = Subscriptions::new();
+
+    loop {
+        let event = future::or(
+            async { ConnectionEvent::Incoming(socket.next().await) },
+            async {
+                // The connection holds a sender itself, so this never fails.
+                ConnectionEvent::Outgoing(outgoing_receiver.recv().await.unwrap_or_default())
+            },
+        )
+        .await;
+
+        match event {
+            ConnectionEvent::Incoming(Some(Ok(Message::Text(text)))) => {
+                subscriptions.retain(|_, task| !task.is_finished());
+                process_message(&text, &request_sender, &outgoing_sender, &mut subscriptions);
+            }
+            ConnectionEvent::Incoming(Some(Ok(Message::Close(_))) | None) => break,
+            // Pings are answered by the socket itself, everything else is ignored.
+            ConnectionEvent::Incoming(Some(Ok(_))) => {}
+            ConnectionEvent::Incoming(Some(Err(err))) => return Err(err.into()),
+            ConnectionEvent::Outgoing(serialized) => {
+                socket.send(Message::Text(serialized)).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes a single text message received from a client, which contains
+/// either a single request or a batch of requests.
+fn process_message(
+    text: &str,
+    request_sender: &Sender<BrpMessage>,
+    outgoing_sender: &Sender<String>,
+    subscriptions: &mut Subscriptions,
+) {
+    let batch: Result<BrpBatch, _> = serde_json::from_str(text);
+
+    match batch {
+        Ok(BrpBatch::Single(request)) => {
+            match parse_request(request) {
+                Ok(request) if request.method.contains("+watch") => {
+                    start_subscription(request, request_sender, outgoing_sender, subscriptions);
+                }
+                Ok(request) if request.method == BRP_UNWATCH_METHOD => {
+                    let response = cancel_subscription(request, subscriptions);
+                    send_response(outgoing_sender, &response);
+                }
+                Ok(request) => {
+                    let request_sender = request_sender.clone();
+                    let outgoing_sender = outgoing_sender.clone();
+                    IoTaskPool::get()
+                        .spawn(async move {
+                            let response = process_instant_request(request, &request_sender).await;
+                            send_response(&outgoing_sender, &response);
+                        })
+                        .detach();
+                }
+                Err(response) => send_response(outgoing_sender, &response),
+            };
+        }
+        Ok(BrpBatch::Batch(requests)) => {
+            let request_sender = request_sender.clone();
+            let outgoing_sender = outgoing_sender.clone();
+            IoTaskPool::get()
+                .spawn(async move {
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        responses.push(match parse_request(request) {
+                            Ok(request)
+                                if request.method.contains("+watch")
+                                    || request.method == BRP_UNWATCH_METHOD =>
+                            {
+                                BrpResponse::new(
+                                    request.id,
+                                    Err(BrpError {
+                                        code: error_codes::INVALID_REQUEST,
+                                        message: "Subscriptions can not be used in batch requests"
+                                            .to_string(),
+                                        data: None,
+                                    }),
+                                )
+                            }
+                            Ok(request) => process_instant_request(request, &request_sender).await,
+                            Err(response) => response,
+                        });
+                    }
+                    send_response(&outgoing_sender, &responses);
+                })
+                .detach();
+        }
+        Err(err) => {
+            let response = BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            );
+            send_response(outgoing_sender, &response);
+        }
+    }
+}
+
+/// Parses and validates a single request, returning the error response to send
+/// if it is invalid.
+fn parse_request(request: Value) -> Result<BrpRequest, BrpResponse> {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = serde_json::from_value(request).map_err(|err| {
+        BrpResponse::new(
+            id.clone(),
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: err.to_string(),
+                data: None,
+            }),
+        )
+    })?;
+
+    if request.jsonrpc != "2.0" {
+        return Err(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            }),
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Sends a request that produces exactly one response to the world and waits
+/// for that response.
+async fn process_instant_request(
+    request: BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+) -> BrpResponse {
+    let (result_sender, result_receiver) = async_channel::bounded(1);
+
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        })
+        .await;
+
+    let result = result_receiver.recv().await.unwrap_or_else(|_| {
+        Err(BrpError::internal(
+            "The request was dropped before a response was sent",
+        ))
+    });
+    BrpResponse::new(request.id, result)
+}
+
+/// Sends a watching request to the world and forwards every response it
+/// produces to the client until the subscription is cancelled.
+fn start_subscription(
+    request: BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+    outgoing_sender: &Sender<String>,
+    subscriptions: &mut Subscriptions,
+) {
+    let key = match subscription_key(request.id.as_ref()) {
+        Ok(key) if !subscriptions.contains_key(&key) => key,
+        Ok(_) => {
+            let message = "A subscription with this id is already active".to_string();
+            send_response(outgoing_sender, &invalid_request(request.id, message));
+            return;
+        }
+        Err(message) => {
+            send_response(outgoing_sender, &invalid_request(request.id, message));
+            return;
+        }
+    };
+
+    let request_sender = request_sender.clone();
+    let outgoing_sender = outgoing_sender.clone();
+    let task = IoTaskPool::get().spawn(async move {
+        let (result_sender, result_receiver) = async_channel::bounded(8);
+
+        let _ = request_sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await;
+
+        while let Ok(result) = result_receiver.recv().await {
+            send_response(
+                &outgoing_sender,
+                &BrpResponse::new(request.id.clone(), result),
+            );
+        }
+    });
+
+    subscriptions.insert(key, task);
+}
+
+/// Handles a `bevy/unwatch` request by cancelling the matching subscription.
+fn cancel_subscription(request: BrpRequest, subscriptions: &mut Subscriptions) -> BrpResponse {
+    let params = request
+        .params
+        .ok_or_else(|| "Params not provided".to_string())
+        .and_then(|params| {
+            serde_json::from_value::<BrpUnwatchParams>(params).map_err(|err| err.to_string())
+        });
+    let result = match params {
+        Ok(BrpUnwatchParams { id }) => match subscription_key(Some(&id))
+            .ok()
+            .and_then(|key| subscriptions.remove(&key))
+        {
+            // Dropping the task cancels it.
+            Some(_) => Ok(Value::Null),
+            None => Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("No active subscription with id {id}"),
+                data: None,
+            }),
+        },
+        Err(message) => Err(BrpError {
+            code: error_codes::INVALID_PARAMS,
+            message,
+            data: None,
+        }),
+    };
+
+    BrpResponse::new(request.id, result)
+}
+
+/// Returns the key used to identify a subscription by the `id` of its watching request.
+fn subscription_key(id: Option<&Value>) -> Result<String, String> {
+    match id {
+        None | Some(Value::Null) => {
+            Err("Watching requests over WebSocket require an `id`".to_string())
+        }
+        Some(id) => Ok(id.to_string()),
+    }
+}
+
+/// Builds an `INVALID_REQUEST` error response.
+fn invalid_request(id: Option<Value>, message: String) -> BrpResponse {
+    BrpResponse::new(
+        id,
+        Err(BrpError {
+            code: error_codes::INVALID_REQUEST,
+            message,
+            data: None,
+        }),
+    )
+}
+
+/// Serializes a response and queues it to be sent to the client.
+///
+/// If the connection has been closed in the meantime, the response is dropped.
+fn send_response(outgoing_sender: &Sender<String>, response: &impl serde::Serialize) {
+    if let Ok(serialized) = serde_json::to_string(response) {
+        let _ = outgoing_sender.try_send(serialized);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_channel::Receiver;
+    use async_io::Timer;
+    use async_tungstenite::{
+        tungstenite::{client::IntoClientRequest, Error},
+        WebSocketStream,
+    };
+    use bevy_tasks::TaskPool;
+    use core::time::Duration;
+    use serde_json::json;
+
+    type Client = WebSocketStream<Async<TcpStream>>;
+
+    /// Starts a server accepting a single connection, and connects to it with the given
+    /// `Authorization` header.
+    ///
+    /// The returned receiver gets the requests forwarded to the world.
+    async fn connect(
+        bearer_token: Option<&str>,
+        authorization: Option<&str>,
+    ) -> (Result<Client, Error>, Receiver<BrpMessage>) {
+        IoTaskPool::get_or_init(TaskPool::new);
+        let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let (request_sender, request_receiver) = async_channel::unbounded();
+        let bearer_token = bearer_token.map(ToOwned::to_owned);
+        IoTaskPool::get()
+            .spawn(async move {
+                let (client, _) = listener.accept().await.unwrap();
+                let _ = handle_client(client, request_sender, bearer_token).await;
+            })
+            .detach();
+
+        let mut request = format!("ws://{address}").into_client_request().unwrap();
+        if let Some(authorization) = authorization {
+            request.headers_mut().insert(
+                http::header::AUTHORIZATION,
+                http::HeaderValue::from_str(authorization).unwrap(),
+            );
+        }
+        let stream = Async::<TcpStream>::connect(address).await.unwrap();
+        let client = async_tungstenite::client_async(request, stream)
+            .await
+            .map(|(client, _)| client);
+        (client, request_receiver)
+    }
+
+    async fn send(client: &mut Client, request: Value) {
+        client
+            .send(Message::Text(request.to_string()))
+            .await
+            .unwrap();
+    }
+
+    async fn receive(client: &mut Client) -> Value {
+        match client.next().await {
+            Some(Ok(Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+            message => panic!("expected a text message, got {message:?}"),
+        }
+    }
+
+    #[test]
+    fn instant_requests() {
+        bevy_tasks::block_on(async {
+            let (client, requests) = connect(None, None).await;
+            let mut client = client.unwrap();
+
+            send(
+                &mut client,
+                json!({ "jsonrpc": "2.0", "method": "bevy/list", "id": 3 }),
+            )
+            .await;
+            let message = requests.recv().await.unwrap();
+            assert_eq!(message.method, "bevy/list");
+            message.sender.send(Ok(json!(["a"]))).await.unwrap();
+            assert_eq!(
+                receive(&mut client).await,
+                json!({ "jsonrpc": "2.0", "id": 3, "result": ["a"] })
+            );
+        });
+    }
+
+    #[test]
+    fn subscribe_and_unwatch() {
+        bevy_tasks::block_on(async {
+            let (client, requests) = connect(None, None).await;
+            let mut client = client.unwrap();
+
+            send(
+                &mut client,
+                json!({ "jsonrpc": "2.0", "method": "bevy/get+watch", "id": 0, "params": {} }),
+            )
+            .await;
+            let message = requests.recv().await.unwrap();
+            assert_eq!(message.method, "bevy/get+watch");
+
+            // every change is pushed with the id of the watching request
+            for change in 1..=2 {
+                message.sender.send(Ok(json!(change))).await.unwrap();
+                assert_eq!(
+                    receive(&mut client).await,
+                    json!({ "jsonrpc": "2.0", "id": 0, "result": change })
+                );
+            }
+
+            // watching requests with the id of an active subscription are rejected
+            send(
+                &mut client,
+                json!({ "jsonrpc": "2.0", "method": "bevy/get+watch", "id": 0, "params": {} }),
+            )
+            .await;
+            let response = receive(&mut client).await;
+            assert_eq!(
+                response["error"]["code"],
+                json!(error_codes::INVALID_REQUEST)
+            );
+
+            send(
+                &mut client,
+                json!({ "jsonrpc": "2.0", "method": BRP_UNWATCH_METHOD, "id": 1, "params": { "id": 0 } }),
+            )
+            .await;
+            assert_eq!(
+                receive(&mut client).await,
+                json!({ "jsonrpc": "2.0", "id": 1, "result": null })
+            );
+
+            // cancelling the subscription drops its receiver, which stops the watching request
+            for _ in 0..100 {
+                if message.sender.is_closed() {
+                    break;
+                }
+                Timer::after(Duration::from_millis(10)).await;
+            }
+            assert!(message.sender.is_closed());
+
+            send(
+                &mut client,
+                json!({ "jsonrpc": "2.0", "method": BRP_UNWATCH_METHOD, "id": 2, "params": { "id": 0 } }),
+            )
+            .await;
+            let response = receive(&mut client).await;
+            assert_eq!(response["id"], json!(2));
+            assert_eq!(
+                response["error"]["code"],
+                json!(error_codes::INVALID_PARAMS)
+            );
+        });
+    }
+
+    #[test]
+    fn accept_authorized_clients() {
+        bevy_tasks::block_on(async {
+            let (client, _requests) = connect(Some("secret"), Some("Bearer secret")).await;
+            assert!(client.is_ok());
+        });
+    }
+
+    #[test]
+    fn reject_unauthorized_clients() {
+        bevy_tasks::block_on(async {
+            for authorization in [None, Some("Bearer wrong"), Some("secret")] {
+                let (client, requests) = connect(Some("secret"), authorization).await;
+                match client {
+                    Err(Error::Http(response)) => {
+                        assert_eq!(response.status(), http::StatusCode::UNAUTHORIZED);
+                    }
+                    _ => panic!("expected the handshake to be rejected"),
+                }
+                assert!(requests.try_recv().is_err());
+            }
+        });
+    }
+}
@@ -0,0 +1,805 @@
+//! A compact binary format for Bevy scenes (`.scn.bin`).
+//!
+//! Unlike the RON scene format, the binary format isn't meant to be read or edited by humans:
+//! it's meant to be loaded as fast as possible, for example for large generated levels or
+//! for scenes produced by the asset processor.
+//!
+//! # Layout
+//!
+//! A binary scene consists of:
+//!
+//! 1. The magic number [`BINARY_SCENE_MAGIC`].
+//! 2. The format version [`BINARY_SCENE_VERSION`], as a little-endian `u32`.
+//! 3. The *type table*: the [type paths] of all resource and component types used in the
+//!    scene, each listed once.
+//! 4. The resources, entities and [prefabs] of the scene, in which every value is prefixed with
+//!    the index of its type in the type table instead of its type path. Prefabs are stored with
+//!    the asset path of their scene.
+//!
+//! The type table and the scene contents are encoded with [`postcard`], and values are
+//! serialized with their [`TypedReflectSerializer`]. [`Handle`]s in values are serialized
+//! as a reference to their asset, like in the RON format: see [`HandleSerializerProcessor`].
+//!
+//! [type paths]: bevy_reflect::TypePath::type_path
+//! [prefabs]: crate::DynamicScenePrefab
+//! [`Handle`]: bevy_asset::Handle
+
+use crate::{
+    serde::{
+        reborrow, HandleDeserializerProcessor, HandleSerializerProcessor, SceneAssets,
+        SceneLoadContext,
+    },
+    DynamicEntity, DynamicScene, DynamicScenePrefab,
+};
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    AssetLoader, AssetPath, AsyncWriteExt, LoadContext,
+};
+use bevy_ecs::{
+    entity::Entity,
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    PartialReflect, TypeRegistration, TypeRegistry, TypeRegistryArc,
+};
+use bevy_utils::HashMap;
+use core::{any::TypeId, fmt::Formatter};
+use derive_more::derive::{Display, Error, From};
+use serde::{
+    de::{DeserializeSeed, Error as _, SeqAccess, Visitor},
+    Deserializer,
+};
+
+/// The magic number at the start of every binary scene.
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"BSCN";
+
+/// The version of the binary scene format written by [`serialize_binary_scene`].
+///
+/// This is increased whenever the layout of the format changes. Binary scenes written with
+/// another version of the format can't be read.
+pub const BINARY_SCENE_VERSION: u32 = 1;
+
+/// Possible errors that can be produced while reading or writing binary scenes.
+#[non_exhaustive]
+#[derive(Debug, Error, Display, From)]
+pub enum BinarySceneError {
+    /// An [IO Error](std::io::Error)
+    #[display("Error while trying to read or write the scene file: {_0}")]
+    Io(std::io::Error),
+    /// A [Postcard Error](postcard::Error)
+    #[display("Could not encode or decode the binary scene: {_0}")]
+    Postcard(postcard::Error),
+    /// The data doesn't start with [`BINARY_SCENE_MAGIC`].
+    #[display("The data is not a binary scene")]
+    WrongFileType,
+    /// The data was written with an unsupported version of the format.
+    #[display("Unsupported binary scene version {found}, expected {BINARY_SCENE_VERSION}")]
+    #[from(ignore)]
+    WrongVersion {
+        /// The version found in the data.
+        found: u32,
+    },
+    /// A value in the scene doesn't represent a concrete type.
+    #[display("A value of type `{_0}` in the scene doesn't represent a concrete type")]
+    #[from(ignore)]
+    MissingTypeInfo(#[error(not(source))] String),
+    /// A type in the type table is not registered in the type registry.
+    #[display("Type `{_0}` in the scene is not registered in the type registry")]
+    #[from(ignore)]
+    UnregisteredType(#[error(not(source))] String),
+    /// The scene of a prefab doesn't have an asset path, so it can't be serialized.
+    #[display("The scene of the prefab of entity {_0} doesn't have an asset path")]
+    #[from(ignore)]
+    MissingPrefabPath(#[error(not(source))] Entity),
+    /// The scene has prefabs, but it was deserialized without [`SceneAssets`] to load their scenes.
+    #[display("The scene has prefabs, which requires deserializing it with `deserialize_binary_scene_with_assets`")]
+    PrefabsWithoutAssets,
+}
+
+/// Serialize a [`DynamicScene`] into the binary scene format.
+///
+/// All types used in the scene must be registered in the given `registry`.
+pub fn serialize_binary_scene<'a>(
+    scene: &'a DynamicScene,
+    registry: &'a TypeRegistry,
+) -> Result<Vec<u8>, BinarySceneError> {
+    serialize_binary_scene_internal(scene, registry, None)
+}
+
+/// Serialize a [`DynamicScene`] into the binary scene format, inlining the value of the assets
+/// without an asset path referenced by the scene, taken from the [`Assets`] in `world`.
+///
+/// All types used in the scene must be registered in the given `registry`.
+///
+/// [`Assets`]: bevy_asset::Assets
+pub fn serialize_binary_scene_with_inlined_assets<'a>(
+    scene: &'a DynamicScene,
+    registry: &'a TypeRegistry,
+    world: &'a World,
+) -> Result<Vec<u8>, BinarySceneError> {
+    serialize_binary_scene_internal(scene, registry, Some(world))
+}
+
+fn serialize_binary_scene_internal<'a>(
+    scene: &'a DynamicScene,
+    registry: &'a TypeRegistry,
+    inline_assets: Option<&'a World>,
+) -> Result<Vec<u8>, BinarySceneError> {
+    let processor = HandleSerializerProcessor { inline_assets };
+    let mut type_table = Vec::<&str>::new();
+    let mut type_indices = HashMap::<&str, u32>::default();
+    let mut index_of = |value: &dyn PartialReflect| {
+        let type_path = value
+            .get_represented_type_info()
+            .ok_or_else(|| BinarySceneError::MissingTypeInfo(value.reflect_type_path().into()))?
+            .type_path();
+        Ok::<_, BinarySceneError>(*type_indices.entry(type_path).or_insert_with(|| {
+            type_table.push(type_path);
+            type_table.len() as u32 - 1
+        }))
+    };
+
+    let mut resources = Vec::with_capacity(scene.resources.len());
+    for resource in &scene.resources {
+        resources.push((
+            index_of(resource.as_ref())?,
+            TypedReflectSerializer::with_processor(resource.as_ref(), registry, &processor),
+        ));
+    }
+
+    let mut serialize_entities = |entities: &'a [DynamicEntity]| {
+        let mut serialized = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let mut components = Vec::with_capacity(entity.components.len());
+            for component in &entity.components {
+                components.push((
+                    index_of(component.as_ref())?,
+                    TypedReflectSerializer::with_processor(
+                        component.as_ref(),
+                        registry,
+                        &processor,
+                    ),
+                ));
+            }
+            serialized.push((entity.entity.to_bits(), components));
+        }
+        Ok::<_, BinarySceneError>(serialized)
+    };
+
+    let entities = serialize_entities(&scene.entities)?;
+
+    let mut prefabs = Vec::with_capacity(scene.prefabs.len());
+    for prefab in &scene.prefabs {
+        let path = prefab
+            .scene
+            .path()
+            .ok_or(BinarySceneError::MissingPrefabPath(prefab.entity))?;
+        prefabs.push((
+            prefab.entity.to_bits(),
+            path,
+            serialize_entities(&prefab.overrides)?,
+        ));
+    }
+
+    let mut bytes = Vec::from(BINARY_SCENE_MAGIC);
+    bytes.extend_from_slice(&BINARY_SCENE_VERSION.to_le_bytes());
+    let bytes = postcard::to_extend(&type_table, bytes)?;
+    Ok(postcard::to_extend(&(resources, entities, prefabs), bytes)?)
+}
+
+/// Deserialize a [`DynamicScene`] from the binary scene format.
+///
+/// All types listed in the type table of the scene must be registered in the given `registry`.
+///
+/// Scenes with prefabs can't be deserialized with this function, since handles to their scenes
+/// must be created: use [`deserialize_binary_scene_with_assets`] instead.
+pub fn deserialize_binary_scene(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+) -> Result<DynamicScene, BinarySceneError> {
+    deserialize_binary_scene_internal(bytes, registry, None)
+}
+
+/// Deserialize a [`DynamicScene`] from the binary scene format, getting handles to the scenes
+/// of its prefabs and to the assets referenced by its values from `assets`.
+///
+/// All types listed in the type table of the scene must be registered in the given `registry`.
+pub fn deserialize_binary_scene_with_assets(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+    assets: &mut dyn SceneAssets,
+) -> Result<DynamicScene, BinarySceneError> {
+    deserialize_binary_scene_internal(bytes, registry, Some(assets))
+}
+
+fn deserialize_binary_scene_internal(
+    bytes: &[u8],
+    registry: &TypeRegistry,
+    mut assets: Option<&mut dyn SceneAssets>,
+) -> Result<DynamicScene, BinarySceneError> {
+    let Some(bytes) = bytes.strip_prefix(&BINARY_SCENE_MAGIC) else {
+        return Err(BinarySceneError::WrongFileType);
+    };
+    let Some((version, bytes)) = bytes.split_first_chunk::<4>() else {
+        return Err(BinarySceneError::WrongFileType);
+    };
+    let version = u32::from_le_bytes(*version);
+    if version != BINARY_SCENE_VERSION {
+        return Err(BinarySceneError::WrongVersion { found: version });
+    }
+
+    let (type_table, bytes) = postcard::take_from_bytes::<Vec<&str>>(bytes)?;
+    let registrations = type_table
+        .into_iter()
+        .map(|type_path| {
+            registry
+                .get_with_type_path(type_path)
+                .ok_or_else(|| BinarySceneError::UnregisteredType(type_path.into()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut deserializer = postcard::Deserializer::from_bytes(bytes);
+    let (resources, entities, prefabs) = BinarySceneContentsDeserializer {
+        registrations: &registrations,
+        registry,
+        assets: reborrow(&mut assets),
+    }
+    .deserialize(&mut deserializer)?;
+
+    let prefabs = match (prefabs.is_empty(), assets) {
+        (true, _) => Vec::new(),
+        (false, None) => return Err(BinarySceneError::PrefabsWithoutAssets),
+        (false, Some(assets)) => prefabs
+            .into_iter()
+            .map(|(entity, path, overrides)| DynamicScenePrefab {
+                entity,
+                scene: assets
+                    .load_erased(TypeId::of::<DynamicScene>(), path)
+                    .typed(),
+                overrides,
+            })
+            .collect(),
+    };
+
+    Ok(DynamicScene {
+        resources,
+        entities,
+        prefabs,
+    })
+}
+
+/// A deserialized prefab: its entity, the asset path of its scene and its overrides.
+type BinaryPrefab = (Entity, AssetPath<'static>, Vec<DynamicEntity>);
+
+/// The deserialized resources, entities and prefabs of a binary scene.
+type BinarySceneContents = (
+    Vec<Box<dyn PartialReflect>>,
+    Vec<DynamicEntity>,
+    Vec<BinaryPrefab>,
+);
+
+/// Handles deserialization of the resources, entities and prefabs of a binary scene,
+/// given its resolved type table.
+struct BinarySceneContentsDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinarySceneContentsDeserializer<'a> {
+    type Value = BinarySceneContents;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinarySceneContentsDeserializer<'a> {
+    type Value = BinarySceneContents;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("binary scene resources, entities and prefabs")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let resources = seq
+            .next_element_seed(BinaryValuesDeserializer {
+                registrations: self.registrations,
+                registry: self.registry,
+                assets: reborrow(&mut self.assets),
+            })?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let entities = seq
+            .next_element_seed(BinaryEntitiesDeserializer {
+                registrations: self.registrations,
+                registry: self.registry,
+                assets: reborrow(&mut self.assets),
+            })?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        let prefabs = seq
+            .next_element_seed(BinaryPrefabsDeserializer {
+                registrations: self.registrations,
+                registry: self.registry,
+                assets: reborrow(&mut self.assets),
+            })?
+            .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+        Ok((resources, entities, prefabs))
+    }
+}
+
+/// Handles deserialization of the prefabs of a binary scene.
+struct BinaryPrefabsDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryPrefabsDeserializer<'a> {
+    type Value = Vec<BinaryPrefab>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryPrefabsDeserializer<'a> {
+    type Value = Vec<BinaryPrefab>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("binary scene prefabs")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut prefabs = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(prefab) = seq.next_element_seed(BinaryPrefabDeserializer {
+            registrations: self.registrations,
+            registry: self.registry,
+            assets: reborrow(&mut self.assets),
+        })? {
+            prefabs.push(prefab);
+        }
+        Ok(prefabs)
+    }
+}
+
+/// Handles deserialization of a single prefab of a binary scene.
+struct BinaryPrefabDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryPrefabDeserializer<'a> {
+    type Value = BinaryPrefab;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryPrefabDeserializer<'a> {
+    type Value = BinaryPrefab;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a binary scene prefab")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let bits = seq
+            .next_element::<u64>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let entity = Entity::try_from_bits(bits).map_err(A::Error::custom)?;
+        let path = seq
+            .next_element::<AssetPath<'static>>()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        let overrides = seq
+            .next_element_seed(BinaryEntitiesDeserializer {
+                registrations: self.registrations,
+                registry: self.registry,
+                assets: reborrow(&mut self.assets),
+            })?
+            .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+        Ok((entity, path, overrides))
+    }
+}
+
+/// Handles deserialization of the entities of a binary scene.
+struct BinaryEntitiesDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("binary scene entities")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(entity) = seq.next_element_seed(BinaryEntityDeserializer {
+            registrations: self.registrations,
+            registry: self.registry,
+            assets: reborrow(&mut self.assets),
+        })? {
+            entities.push(entity);
+        }
+        Ok(entities)
+    }
+}
+
+/// Handles deserialization of a single entity of a binary scene.
+struct BinaryEntityDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a binary scene entity")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let bits = seq
+            .next_element::<u64>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let entity = Entity::try_from_bits(bits).map_err(A::Error::custom)?;
+        let components = seq
+            .next_element_seed(BinaryValuesDeserializer {
+                registrations: self.registrations,
+                registry: self.registry,
+                assets: reborrow(&mut self.assets),
+            })?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(DynamicEntity { entity, components })
+    }
+}
+
+/// Handles deserialization of a list of resources or components, each prefixed
+/// with the index of its type in the type table.
+struct BinaryValuesDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryValuesDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("binary scene values")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(BinaryValueDeserializer {
+            registrations: self.registrations,
+            registry: self.registry,
+            assets: reborrow(&mut self.assets),
+        })? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Handles deserialization of a single resource or component, prefixed with
+/// the index of its type in the type table.
+struct BinaryValueDeserializer<'a> {
+    registrations: &'a [&'a TypeRegistration],
+    registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for BinaryValueDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for BinaryValueDeserializer<'a> {
+    type Value = Box<dyn PartialReflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("a binary scene value")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let index = seq
+            .next_element::<u32>()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let registration = self.registrations.get(index as usize).ok_or_else(|| {
+            A::Error::custom(format!("type index {index} is out of the type table"))
+        })?;
+        let mut processor = HandleDeserializerProcessor {
+            assets: reborrow(&mut self.assets),
+        };
+        seq.next_element_seed(TypedReflectDeserializer::with_processor(
+            registration,
+            self.registry,
+            &mut processor,
+        ))?
+        .ok_or_else(|| A::Error::invalid_length(1, &self))
+    }
+}
+
+/// Asset loader for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`serialize_binary_scene`] or saved by
+/// the [`BinarySceneSaver`].
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = BinarySceneError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        deserialize_binary_scene_with_assets(
+            &bytes,
+            &self.type_registry.read(),
+            &mut SceneLoadContext::new(load_context),
+        )
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
+
+/// Asset saver for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// This can be used by the asset processor to turn `.scn.ron` scenes into binary scenes,
+/// which are then loaded with the [`BinarySceneLoader`].
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = BinarySceneError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, DynamicScene>,
+        _settings: &(),
+    ) -> Result<(), BinarySceneError> {
+        let bytes = serialize_binary_scene(&asset, &self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize_binary_scene, serialize_binary_scene, BinarySceneError};
+    use crate::{DynamicScene, DynamicSceneBuilder};
+    use bevy_ecs::{
+        entity::EntityHashMap,
+        prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
+        reflect::AppTypeRegistry,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name {
+        value: String,
+        aliases: Vec<String>,
+    }
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Level(u8);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Name>();
+            registry.register::<Level>();
+            registry.register::<String>();
+            registry.register::<Vec<String>>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn binary_scene_roundtrip() {
+        let mut world = create_world();
+        world.insert_resource(Level(3));
+        world.spawn((
+            Health(10),
+            Name {
+                value: "Ferris".into(),
+                aliases: vec!["Crab".into()],
+            },
+        ));
+        world.spawn(Health(20));
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build();
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let bytes = serialize_binary_scene(&scene, &registry.read()).unwrap();
+        let deserialized = deserialize_binary_scene(&bytes, &registry.read()).unwrap();
+
+        // The type table lists each type once.
+        assert_eq!(
+            bytes
+                .windows("Health".len())
+                .filter(|window| *window == b"Health")
+                .count(),
+            1
+        );
+
+        let mut dst_world = create_world();
+        deserialized
+            .write_to_world(&mut dst_world, &mut EntityHashMap::default())
+            .unwrap();
+
+        assert_eq!(dst_world.resource::<Level>(), &Level(3));
+        let mut healths = dst_world
+            .query::<&Health>()
+            .iter(&dst_world)
+            .map(|health| health.0)
+            .collect::<Vec<_>>();
+        healths.sort();
+        assert_eq!(healths, vec![10, 20]);
+        let name = dst_world.query::<&Name>().single(&dst_world);
+        assert_eq!(name.value, "Ferris");
+        assert_eq!(name.aliases, vec!["Crab".to_string()]);
+    }
+
+    #[test]
+    fn binary_scene_rejects_bad_header() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        assert!(matches!(
+            deserialize_binary_scene(b"(resources: {})", &registry),
+            Err(BinarySceneError::WrongFileType)
+        ));
+
+        let mut bytes = serialize_binary_scene(&DynamicScene::default(), &registry).unwrap();
+        bytes[4] = 255;
+        assert!(matches!(
+            deserialize_binary_scene(&bytes, &registry),
+            Err(BinarySceneError::WrongVersion { found: 255 })
+        ));
+    }
+
+    #[test]
+    fn binary_scene_rejects_unregistered_types() {
+        let mut world = create_world();
+        world.spawn(Health(1));
+        let scene = DynamicScene::from_world(&world);
+        let bytes = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            serialize_binary_scene(&scene, &registry).unwrap()
+        };
+
+        let registry = bevy_reflect::TypeRegistry::default();
+        assert!(matches!(
+            deserialize_binary_scene(&bytes, &registry),
+            Err(BinarySceneError::UnregisteredType(type_path)) if type_path.ends_with("Health")
+        ));
+    }
+}
@@ -1,12 +1,15 @@
 use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
-use bevy_asset::Asset;
+use bevy_asset::{Asset, AssetId, Assets, Handle, UntypedAssetId, VisitAssetDependencies};
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
-    entity::{Entity, EntityHashMap, SceneEntityMapper},
+    entity::{Entity, EntityHashMap, EntityMapper, SceneEntityMapper},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
-    world::World,
+    world::{Command, World},
 };
-use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use bevy_hierarchy::{AddChild, BuildChildren, DespawnRecursiveExt, Parent};
+use bevy_reflect::{PartialReflect, TypeInfo, TypePath, TypeRegistration, TypeRegistry};
+use bevy_utils::{FixedState, HashMap, HashSet};
+use core::{any::TypeId, hash::BuildHasher};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
@@ -20,12 +23,27 @@ use serde::Serialize;
 /// * [`SceneSpawner::spawn_dynamic`](crate::SceneSpawner::spawn_dynamic)
 /// * adding the [`DynamicSceneRoot`](crate::components::DynamicSceneRoot) component to an entity.
 /// * using the [`DynamicSceneBuilder`] to construct a `DynamicScene` from `World`.
-#[derive(Asset, TypePath, Default)]
+///
+/// A dynamic scene can also contain instances of other dynamic scenes, its [`prefabs`](Self::prefabs),
+/// which are spawned along with it.
+#[derive(TypePath, Default)]
 pub struct DynamicScene {
     /// Resources stored in the dynamic scene.
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic scene.
     pub entities: Vec<DynamicEntity>,
+    /// Instances of other dynamic scenes nested in the dynamic scene.
+    pub prefabs: Vec<DynamicScenePrefab>,
+}
+
+impl Asset for DynamicScene {}
+
+impl VisitAssetDependencies for DynamicScene {
+    fn visit_dependencies(&self, visit: &mut impl FnMut(UntypedAssetId)) {
+        for prefab in &self.prefabs {
+            visit(prefab.scene.id().untyped());
+        }
+    }
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -39,6 +57,53 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn PartialReflect>>,
 }
 
+/// An instance of another [`DynamicScene`] nested in a dynamic scene, like a prefab.
+///
+/// When the outer scene is written to the world, the entities of the nested scene are written
+/// along with its own entities: the root entities of the nested scene become children of
+/// [`entity`](Self::entity), and the [`overrides`](Self::overrides) are applied on top of the
+/// components of the nested scene. This makes the nested scene asset a dependency of the outer
+/// scene: it must be loaded before the outer scene can be spawned, and spawned instances of the
+/// outer scene are updated when the nested scene is modified.
+///
+/// Nested scenes can themselves contain prefabs, but a scene can't contain itself.
+pub struct DynamicScenePrefab {
+    /// The entity of the outer scene the root entities of the nested scene are parented to.
+    ///
+    /// If this entity isn't part of the outer scene, the root entities are left without a parent.
+    pub entity: Entity,
+    /// The nested scene.
+    ///
+    /// The handle must have an asset path for the outer scene to be serialized.
+    pub scene: Handle<DynamicScene>,
+    /// Components of this instance that replace or add to the components of the nested scene.
+    ///
+    /// The identifiers of these entities are the ones of the nested scene. Overrides for entities
+    /// that aren't in the nested scene are ignored.
+    pub overrides: Vec<DynamicEntity>,
+}
+
+impl DynamicScenePrefab {
+    /// Create an instance of the `scene` under the given `entity`, without any override.
+    pub fn new(entity: Entity, scene: Handle<DynamicScene>) -> Self {
+        Self {
+            entity,
+            scene,
+            overrides: Vec::new(),
+        }
+    }
+
+    /// Add an override of the components of an entity of the nested scene.
+    pub fn with_override(
+        mut self,
+        entity: Entity,
+        components: Vec<Box<dyn PartialReflect>>,
+    ) -> Self {
+        self.overrides.push(DynamicEntity { entity, components });
+        self
+    }
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
@@ -55,6 +120,9 @@ impl DynamicScene {
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
+    /// The [`prefabs`](Self::prefabs) of the scene are written as well, which requires their scenes
+    /// to be loaded in the world's [`Assets<DynamicScene>`].
+    ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
@@ -64,8 +132,24 @@ impl DynamicScene {
         entity_map: &mut EntityHashMap<Entity>,
         type_registry: &AppTypeRegistry,
     ) -> Result<(), SceneSpawnError> {
+        if let Some(prefab) = self.prefabs.first() {
+            let Some(scenes) = world.get_resource::<Assets<DynamicScene>>() else {
+                return Err(SceneSpawnError::NonExistentScene {
+                    id: prefab.scene.id(),
+                });
+            };
+            if let Some(resolved) = self.resolve_prefabs(scenes, &type_registry.read())? {
+                return resolved.write_to_world_with(world, entity_map, type_registry);
+            }
+        }
+
         let type_registry = type_registry.read();
 
+        // States are restored through transitions rather than by inserting their `State<S>`
+        // resource, so check that they can be before writing anything.
+        #[cfg(feature = "bevy_state")]
+        let state_transitions = state_transitions(&type_registry, &self.resources)?;
+
         // First ensure that every entity in the scene has a corresponding world
         // entity in the entity map.
         for scene_entity in &self.entities {
@@ -86,22 +170,8 @@ impl DynamicScene {
             // Apply/ add each component to the given entity.
             for component in &scene_entity.components {
                 let mut component = component.clone_value();
-                let type_info = component.get_represented_type_info().ok_or_else(|| {
-                    SceneSpawnError::NoRepresentedType {
-                        type_path: component.reflect_type_path().to_string(),
-                    }
-                })?;
-                let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                    SceneSpawnError::UnregisteredButReflectedType {
-                        type_path: type_info.type_path().to_string(),
-                    }
-                })?;
-                let reflect_component =
-                    registration.data::<ReflectComponent>().ok_or_else(|| {
-                        SceneSpawnError::UnregisteredComponent {
-                            type_path: type_info.type_path().to_string(),
-                        }
-                    })?;
+                let (registration, reflect_component) =
+                    get_component_registration(&type_registry, component.as_ref())?;
 
                 // If this component references entities in the scene, update
                 // them to the entities in the world.
@@ -122,22 +192,21 @@ impl DynamicScene {
         // Insert resources after all entities have been added to the world.
         // This ensures the entities are available for the resources to reference during mapping.
         for resource in &self.resources {
+            #[cfg(feature = "bevy_state")]
+            if resource
+                .get_represented_type_info()
+                .is_some_and(|type_info| {
+                    state_transitions
+                        .iter()
+                        .any(|(type_id, ..)| *type_id == type_info.type_id())
+                })
+            {
+                continue;
+            }
+
             let mut resource = resource.clone_value();
-            let type_info = resource.get_represented_type_info().ok_or_else(|| {
-                SceneSpawnError::NoRepresentedType {
-                    type_path: resource.reflect_type_path().to_string(),
-                }
-            })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                SceneSpawnError::UnregisteredButReflectedType {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
+            let (registration, reflect_resource) =
+                get_resource_registration(&type_registry, resource.as_ref())?;
 
             // If this component references entities in the scene, update
             // them to the entities in the world.
@@ -152,6 +221,11 @@ impl DynamicScene {
             reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &type_registry);
         }
 
+        #[cfg(feature = "bevy_state")]
+        for (_, reflect_state, state) in state_transitions {
+            reflect_state.set_next_state(world, state.as_ref(), &type_registry);
+        }
+
         Ok(())
     }
 
@@ -169,6 +243,370 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
+    /// Update the entities and resources that were written to the world from the `previous`
+    /// version of this scene so that they match this scene, only touching what changed
+    /// between the two versions.
+    ///
+    /// - Components and resources whose value changed are applied, or inserted if they are new.
+    /// - Components and resources that are no longer in the scene are removed.
+    /// - Entities that are new in the scene are spawned and added to the `entity_map`.
+    /// - Entities that are no longer in the scene are despawned, along with their descendants,
+    ///   and removed from the `entity_map`.
+    ///
+    /// Everything else is left untouched: components added at runtime, runtime changes to
+    /// components that didn't change between the two versions, and scene entities that were
+    /// despawned at runtime.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    pub fn reconcile_world_with(
+        &self,
+        previous: &DynamicScene,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        let type_registry = type_registry.read();
+
+        let previous_entities: EntityHashMap<&DynamicEntity> = previous
+            .entities
+            .iter()
+            .map(|scene_entity| (scene_entity.entity, scene_entity))
+            .collect();
+
+        // Spawn the entities that are new in this version of the scene, so that components
+        // can reference them.
+        for scene_entity in &self.entities {
+            if !previous_entities.contains_key(&scene_entity.entity) {
+                entity_map
+                    .entry(scene_entity.entity)
+                    .or_insert_with(|| world.spawn_empty().id());
+            }
+        }
+
+        for scene_entity in &self.entities {
+            let Some(&entity) = entity_map.get(&scene_entity.entity) else {
+                continue;
+            };
+            // The entity was despawned at runtime.
+            if world.get_entity(entity).is_err() {
+                continue;
+            }
+
+            let previous_components = previous_entities
+                .get(&scene_entity.entity)
+                .map(|previous_entity| values_by_type_id(&previous_entity.components))
+                .unwrap_or_default();
+
+            for component in &scene_entity.components {
+                let (registration, reflect_component) =
+                    get_component_registration(&type_registry, component.as_ref())?;
+                if previous_components
+                    .get(&registration.type_id())
+                    .and_then(|previous| previous.reflect_partial_eq(component.as_ref()))
+                    .unwrap_or(false)
+                {
+                    continue;
+                }
+
+                let mut component = component.clone_value();
+                if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                    SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                        map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
+                    });
+                }
+
+                reflect_component.apply_or_insert(
+                    &mut world.entity_mut(entity),
+                    component.as_partial_reflect(),
+                    &type_registry,
+                );
+            }
+
+            let components = values_by_type_id(&scene_entity.components);
+            for (type_id, previous_component) in previous_components {
+                if components.contains_key(&type_id) {
+                    continue;
+                }
+                let (_, reflect_component) =
+                    get_component_registration(&type_registry, previous_component)?;
+                reflect_component.remove(&mut world.entity_mut(entity));
+            }
+        }
+
+        // Despawn the entities that are no longer in the scene.
+        let entities: EntityHashMap<()> = self
+            .entities
+            .iter()
+            .map(|scene_entity| (scene_entity.entity, ()))
+            .collect();
+        for previous_entity in &previous.entities {
+            if entities.contains_key(&previous_entity.entity) {
+                continue;
+            }
+            let Some(entity) = entity_map.remove(&previous_entity.entity) else {
+                continue;
+            };
+            if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.remove_parent();
+                entity_mut.despawn_recursive();
+            }
+        }
+
+        // Update resources after all entities have been added to the world.
+        // This ensures the entities are available for the resources to reference during mapping.
+        let previous_resources = values_by_type_id(&previous.resources);
+        for resource in &self.resources {
+            let (registration, reflect_resource) =
+                get_resource_registration(&type_registry, resource.as_ref())?;
+            if previous_resources
+                .get(&registration.type_id())
+                .and_then(|previous| previous.reflect_partial_eq(resource.as_ref()))
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            let mut resource = resource.clone_value();
+            if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                SceneEntityMapper::world_scope(entity_map, world, |_, mapper| {
+                    map_entities.map_entities(resource.as_partial_reflect_mut(), mapper);
+                });
+            }
+
+            reflect_resource.apply_or_insert(world, resource.as_partial_reflect(), &type_registry);
+        }
+
+        let resources = values_by_type_id(&self.resources);
+        for (type_id, previous_resource) in previous_resources {
+            if resources.contains_key(&type_id) {
+                continue;
+            }
+            let (_, reflect_resource) =
+                get_resource_registration(&type_registry, previous_resource)?;
+            reflect_resource.remove(world);
+        }
+
+        Ok(())
+    }
+
+    /// Update the entities and resources that were written to the world from the `previous`
+    /// version of this scene so that they match this scene, only touching what changed.
+    ///
+    /// See [`Self::reconcile_world_with`] for details; this uses the world's [`AppTypeRegistry`].
+    pub fn reconcile_world(
+        &self,
+        previous: &DynamicScene,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        self.reconcile_world_with(previous, world, entity_map, &registry)
+    }
+
+    /// Create a copy of this scene, cloning every resource and component value.
+    pub fn clone_dynamic(&self) -> Self {
+        Self {
+            resources: self
+                .resources
+                .iter()
+                .map(|resource| resource.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(DynamicEntity::clone_dynamic)
+                .collect(),
+            prefabs: self
+                .prefabs
+                .iter()
+                .map(|prefab| DynamicScenePrefab {
+                    entity: prefab.entity,
+                    scene: prefab.scene.clone(),
+                    overrides: prefab
+                        .overrides
+                        .iter()
+                        .map(DynamicEntity::clone_dynamic)
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if the scene with the given `id` is one of the prefabs of this scene,
+    /// or of the scenes of its prefabs, recursively.
+    pub fn contains_prefab(
+        &self,
+        id: impl Into<AssetId<DynamicScene>>,
+        scenes: &Assets<DynamicScene>,
+    ) -> bool {
+        self.contains_prefab_internal(id.into(), scenes, &mut HashSet::new())
+    }
+
+    fn contains_prefab_internal(
+        &self,
+        id: AssetId<DynamicScene>,
+        scenes: &Assets<DynamicScene>,
+        visited: &mut HashSet<AssetId<DynamicScene>>,
+    ) -> bool {
+        self.prefabs.iter().any(|prefab| {
+            let prefab_id = prefab.scene.id();
+            prefab_id == id
+                || (visited.insert(prefab_id)
+                    && scenes
+                        .get(prefab_id)
+                        .is_some_and(|scene| scene.contains_prefab_internal(id, scenes, visited)))
+        })
+    }
+
+    /// Resolve the prefabs of this scene, recursively, into a single scene without prefabs.
+    ///
+    /// The entities of each prefab get new identifiers, derived from the identifier of the prefab
+    /// and their identifier in the scene of the prefab so that they are kept when the rest of the
+    /// scene is modified, and the entities they reference are mapped accordingly. Resources of a prefab are only
+    /// added if the scene doesn't already have a resource of the same type.
+    ///
+    /// Returns `None` if this scene has no prefabs.
+    pub(crate) fn resolve_prefabs(
+        &self,
+        scenes: &Assets<DynamicScene>,
+        type_registry: &TypeRegistry,
+    ) -> Result<Option<ResolvedDynamicScene>, SceneSpawnError> {
+        if self.prefabs.is_empty() {
+            return Ok(None);
+        }
+        self.resolve_prefabs_internal(scenes, type_registry, &mut Vec::new())
+            .map(Some)
+    }
+
+    fn resolve_prefabs_internal(
+        &self,
+        scenes: &Assets<DynamicScene>,
+        type_registry: &TypeRegistry,
+        ancestors: &mut Vec<AssetId<DynamicScene>>,
+    ) -> Result<ResolvedDynamicScene, SceneSpawnError> {
+        let mut resolved = ResolvedDynamicScene {
+            scene: DynamicScene {
+                resources: self
+                    .resources
+                    .iter()
+                    .map(|resource| resource.clone_value())
+                    .collect(),
+                entities: self
+                    .entities
+                    .iter()
+                    .map(DynamicEntity::clone_dynamic)
+                    .collect(),
+                prefabs: Vec::new(),
+            },
+            parents: Vec::new(),
+        };
+        let mut used_entities = self
+            .entities
+            .iter()
+            .map(|scene_entity| scene_entity.entity)
+            .chain(self.prefabs.iter().map(|prefab| prefab.entity))
+            .collect::<HashSet<_>>();
+
+        for prefab in &self.prefabs {
+            let id = prefab.scene.id();
+            if ancestors.contains(&id) {
+                return Err(SceneSpawnError::RecursivePrefab { id });
+            }
+            let scene = scenes
+                .get(id)
+                .ok_or(SceneSpawnError::NonExistentScene { id })?;
+            ancestors.push(id);
+            let nested = scene.resolve_prefabs_internal(scenes, type_registry, ancestors)?;
+            ancestors.pop();
+
+            // Give the entities of the prefab identifiers that are unique in the resolved scene.
+            let mut entity_map = EntityHashMap::default();
+            for scene_entity in &nested.scene.entities {
+                let mut entity = prefab_entity(prefab.entity, scene_entity.entity);
+                // In the unlikely case of a collision, derive another identifier.
+                while !used_entities.insert(entity) {
+                    entity = prefab_entity(entity, scene_entity.entity);
+                }
+                entity_map.insert(scene_entity.entity, entity);
+            }
+
+            for scene_entity in &nested.scene.entities {
+                let is_root = !scene_entity.components.iter().any(|component| {
+                    component
+                        .get_represented_type_info()
+                        .is_some_and(|type_info| type_info.type_id() == TypeId::of::<Parent>())
+                });
+                if is_root {
+                    resolved
+                        .parents
+                        .push((prefab.entity, entity_map[&scene_entity.entity]));
+                }
+
+                let mut components = Vec::with_capacity(scene_entity.components.len());
+                for component in &scene_entity.components {
+                    components.push(map_prefab_value(
+                        type_registry,
+                        component.as_ref(),
+                        &entity_map,
+                    )?);
+                }
+                resolved.scene.entities.push(DynamicEntity {
+                    entity: entity_map[&scene_entity.entity],
+                    components,
+                });
+            }
+            resolved.parents.extend(
+                nested
+                    .parents
+                    .iter()
+                    .map(|(parent, child)| (entity_map[parent], entity_map[child])),
+            );
+
+            for scene_override in &prefab.overrides {
+                let Some(&entity) = entity_map.get(&scene_override.entity) else {
+                    continue;
+                };
+                let scene_entity = resolved
+                    .scene
+                    .entities
+                    .iter_mut()
+                    .find(|scene_entity| scene_entity.entity == entity)
+                    .expect("prefab entities should have been added to the resolved scene");
+                for component in &scene_override.components {
+                    let component =
+                        map_prefab_value(type_registry, component.as_ref(), &entity_map)?;
+                    let type_id = component.get_represented_type_info().map(TypeInfo::type_id);
+                    match scene_entity.components.iter_mut().find(|existing| {
+                        existing.get_represented_type_info().map(TypeInfo::type_id) == type_id
+                    }) {
+                        Some(existing) => *existing = component,
+                        None => scene_entity.components.push(component),
+                    }
+                }
+            }
+
+            let resource_types = values_by_type_id(&resolved.scene.resources)
+                .into_keys()
+                .collect::<HashSet<_>>();
+            for resource in &nested.scene.resources {
+                let is_new = resource
+                    .get_represented_type_info()
+                    .is_some_and(|type_info| !resource_types.contains(&type_info.type_id()));
+                if is_new {
+                    resolved.scene.resources.push(map_prefab_value(
+                        type_registry,
+                        resource.as_ref(),
+                        &entity_map,
+                    )?);
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into the official Bevy scene format (`.scn` / `.scn.ron`).
     ///
@@ -183,6 +621,236 @@ impl DynamicScene {
     }
 }
 
+impl DynamicEntity {
+    /// Create a copy of this entity, cloning every component value.
+    fn clone_dynamic(&self) -> Self {
+        Self {
+            entity: self.entity,
+            components: self
+                .components
+                .iter()
+                .map(|component| component.clone_value())
+                .collect(),
+        }
+    }
+}
+
+/// A [`DynamicScene`] whose prefabs have been resolved into plain entities, along with the
+/// hierarchy to set up between the root entities of the prefabs and their parents.
+pub(crate) struct ResolvedDynamicScene {
+    /// The resolved scene, which has no prefabs.
+    pub(crate) scene: DynamicScene,
+    /// `(parent, child)` pairs of scene entities for the root entities of the prefabs.
+    parents: Vec<(Entity, Entity)>,
+}
+
+impl ResolvedDynamicScene {
+    /// Write the resolved scene to the world, see [`DynamicScene::write_to_world_with`].
+    pub(crate) fn write_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        self.scene
+            .write_to_world_with(world, entity_map, type_registry)?;
+        self.parent_prefab_roots(world, entity_map);
+        Ok(())
+    }
+
+    /// Reconcile the resolved scene with the `previous` resolved version of the scene,
+    /// see [`DynamicScene::reconcile_world_with`].
+    pub(crate) fn reconcile_world_with(
+        &self,
+        previous: &DynamicScene,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        self.scene
+            .reconcile_world_with(previous, world, entity_map, type_registry)?;
+        self.parent_prefab_roots(world, entity_map);
+        Ok(())
+    }
+
+    /// Add the root entities of the prefabs as children of their parent, unless they already
+    /// have a parent.
+    fn parent_prefab_roots(&self, world: &mut World, entity_map: &EntityHashMap<Entity>) {
+        for (parent, child) in &self.parents {
+            let (Some(&parent), Some(&child)) = (entity_map.get(parent), entity_map.get(child))
+            else {
+                continue;
+            };
+            if world.get_entity(parent).is_err() {
+                continue;
+            }
+            if world
+                .get_entity(child)
+                .is_ok_and(|child| !child.contains::<Parent>())
+            {
+                AddChild { parent, child }.apply(world);
+            }
+        }
+    }
+}
+
+/// Maps the entities of a prefab's scene to the entities of the scene it is resolved into.
+///
+/// Entities that aren't part of the prefab's scene are left untouched.
+struct PrefabEntityMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for PrefabEntityMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Returns the identifier in a resolved scene of the `entity` of the scene of the prefab
+/// instantiated as `prefab`.
+///
+/// This only depends on both identifiers, so that the entities of a prefab keep their identifier
+/// when other entities or prefabs of the scene are added or removed.
+fn prefab_entity(prefab: Entity, entity: Entity) -> Entity {
+    let hash = FixedState.hash_one((prefab.to_bits(), entity.to_bits()));
+    // The generation must be non-zero, and its highest bit is reserved.
+    let generation = ((hash >> 32) as u32 & 0x7FFF_FFFF).max(1);
+    Entity::from_bits((u64::from(generation) << 32) | (hash & 0xFFFF_FFFF))
+}
+
+/// Clone a value of a prefab's scene, mapping the entities it references to the entities of
+/// the scene it is resolved into.
+fn map_prefab_value(
+    type_registry: &TypeRegistry,
+    value: &dyn PartialReflect,
+    entity_map: &EntityHashMap<Entity>,
+) -> Result<Box<dyn PartialReflect>, SceneSpawnError> {
+    let mut value = value.clone_value();
+    let registration = get_registration(type_registry, value.as_ref())?;
+    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+        map_entities.map_entities(
+            value.as_partial_reflect_mut(),
+            &mut PrefabEntityMapper(entity_map),
+        );
+    }
+    Ok(value)
+}
+
+/// Get the type registration and the [`ReflectComponent`] of a reflected component value.
+fn get_component_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    component: &dyn PartialReflect,
+) -> Result<(&'r TypeRegistration, &'r ReflectComponent), SceneSpawnError> {
+    let registration = get_registration(type_registry, component)?;
+    let reflect_component = registration.data::<ReflectComponent>().ok_or_else(|| {
+        SceneSpawnError::UnregisteredComponent {
+            type_path: registration.type_info().type_path().to_string(),
+        }
+    })?;
+    Ok((registration, reflect_component))
+}
+
+/// Get the type registration and the [`ReflectResource`] of a reflected resource value.
+fn get_resource_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    resource: &dyn PartialReflect,
+) -> Result<(&'r TypeRegistration, &'r ReflectResource), SceneSpawnError> {
+    let registration = get_registration(type_registry, resource)?;
+    let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+        SceneSpawnError::UnregisteredResource {
+            type_path: registration.type_info().type_path().to_string(),
+        }
+    })?;
+    Ok((registration, reflect_resource))
+}
+
+/// Find the `State<S>` resources among the `resources` of a scene, returning the type of each
+/// one along with the value of its state and the type data to queue a transition to it.
+#[cfg(feature = "bevy_state")]
+fn state_transitions<'r>(
+    type_registry: &'r TypeRegistry,
+    resources: &[Box<dyn PartialReflect>],
+) -> Result<
+    Vec<(
+        TypeId,
+        &'r bevy_state::reflect::ReflectFreelyMutableState,
+        Box<dyn bevy_reflect::Reflect>,
+    )>,
+    SceneSpawnError,
+> {
+    use bevy_reflect::{ReflectFromReflect, ReflectRef};
+    use bevy_state::reflect::{ReflectFreelyMutableState, ReflectState};
+
+    if resources.is_empty() {
+        return Ok(Vec::new());
+    }
+    let states = type_registry
+        .iter_with_data::<ReflectState>()
+        .map(|(registration, reflect_state)| (reflect_state.state_resource_type_id(), registration))
+        .collect::<HashMap<_, _>>();
+
+    let mut transitions = Vec::new();
+    for resource in resources {
+        let Some((type_id, registration)) = resource
+            .get_represented_type_info()
+            .and_then(|type_info| states.get_key_value(&type_info.type_id()))
+        else {
+            continue;
+        };
+        let unregistered = || SceneSpawnError::UnregisteredState {
+            type_path: registration.type_info().type_path().to_string(),
+        };
+        let reflect_state = registration
+            .data::<ReflectFreelyMutableState>()
+            .ok_or_else(unregistered)?;
+
+        // Deserialized values are dynamic, so the state must be converted into a concrete value.
+        let ReflectRef::TupleStruct(resource) = resource.reflect_ref() else {
+            return Err(unregistered());
+        };
+        let state = resource
+            .field(0)
+            .and_then(|state| {
+                registration
+                    .data::<ReflectFromReflect>()?
+                    .from_reflect(state)
+            })
+            .ok_or_else(unregistered)?;
+        transitions.push((*type_id, reflect_state, state));
+    }
+    Ok(transitions)
+}
+
+/// Get the type registration of the type represented by a reflected value.
+fn get_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    value: &dyn PartialReflect,
+) -> Result<&'r TypeRegistration, SceneSpawnError> {
+    let type_info =
+        value
+            .get_represented_type_info()
+            .ok_or_else(|| SceneSpawnError::NoRepresentedType {
+                type_path: value.reflect_type_path().to_string(),
+            })?;
+    type_registry.get(type_info.type_id()).ok_or_else(|| {
+        SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_info.type_path().to_string(),
+        }
+    })
+}
+
+/// Index reflected values by the [`TypeId`] of the type they represent.
+///
+/// Values without a represented type are skipped.
+fn values_by_type_id(values: &[Box<dyn PartialReflect>]) -> HashMap<TypeId, &dyn PartialReflect> {
+    values
+        .iter()
+        .filter_map(|value| {
+            let type_info = value.get_represented_type_info()?;
+            Some((type_info.type_id(), value.as_ref()))
+        })
+        .collect()
+}
+
 /// Serialize a given Rust data structure into rust object notation (ron).
 #[cfg(feature = "serialize")]
 pub fn serialize_ron<S>(serialize: S) -> Result<String, ron::Error>
@@ -381,4 +1049,123 @@ mod tests {
             .write_to_world(&mut dst_world, &mut Default::default())
             .unwrap();
     }
+
+    #[cfg(all(feature = "bevy_state", feature = "serialize"))]
+    #[test]
+    fn states_are_restored_with_transitions() {
+        use bevy_app::App;
+        use bevy_ecs::system::ResMut;
+        use bevy_state::{
+            app::{AppExtStates, StatesPlugin},
+            state::{NextState, OnEnter, State, States},
+        };
+        use serde::de::DeserializeSeed;
+
+        use crate::serde::{SceneDeserializer, SceneSerializer};
+
+        #[derive(States, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+        enum Level {
+            #[default]
+            Menu,
+            Forest,
+        }
+
+        #[derive(Resource, Default)]
+        struct Entered(usize);
+
+        fn app() -> App {
+            let mut app = App::new();
+            app.add_plugins(StatesPlugin)
+                .init_state::<Level>()
+                .register_type_mutable_state::<Level>()
+                .init_resource::<Entered>()
+                .add_systems(OnEnter(Level::Forest), |mut entered: ResMut<Entered>| {
+                    entered.0 += 1;
+                });
+            app.update();
+            app
+        }
+
+        let mut source = app();
+        source
+            .world_mut()
+            .resource_mut::<NextState<Level>>()
+            .set(Level::Forest);
+        source.update();
+        let scene = DynamicSceneBuilder::from_world(source.world())
+            .extract_states()
+            .build();
+        assert_eq!(scene.resources.len(), 1);
+        assert!(scene.resources[0]
+            .get_represented_type_info()
+            .is_some_and(bevy_reflect::TypeInfo::is::<State<Level>>));
+
+        let registry = source.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let serialized = crate::ron::to_string(&SceneSerializer::new(&scene, &registry)).unwrap();
+        let mut deserializer = crate::ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut destination = app();
+        scene
+            .write_to_world(destination.world_mut(), &mut Default::default())
+            .unwrap();
+        assert_eq!(
+            *destination.world().resource::<State<Level>>().get(),
+            Level::Menu
+        );
+        destination.update();
+        assert_eq!(
+            *destination.world().resource::<State<Level>>().get(),
+            Level::Forest
+        );
+        assert_eq!(destination.world().resource::<Entered>().0, 1);
+    }
+
+    #[cfg(feature = "bevy_state")]
+    #[test]
+    fn unregistered_states_are_reported_before_writing() {
+        use bevy_app::App;
+        use bevy_state::{
+            app::{AppExtStates, StatesPlugin},
+            state::{State, States},
+        };
+
+        use crate::{DynamicEntity, SceneSpawnError};
+
+        #[derive(States, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+        enum Level {
+            #[default]
+            Menu,
+            Forest,
+        }
+
+        let mut app = App::new();
+        // The state can be reflected, but not set through reflection.
+        app.add_plugins(StatesPlugin)
+            .init_state::<Level>()
+            .register_type_state::<Level>();
+        app.update();
+
+        let scene = DynamicScene {
+            resources: vec![Box::new(State::new(Level::Forest))],
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components: Vec::new(),
+            }],
+            prefabs: Vec::new(),
+        };
+        let entities = app.world().entities().len();
+        let result = scene.write_to_world(app.world_mut(), &mut Default::default());
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::UnregisteredState { .. })
+        ));
+        assert_eq!(app.world().entities().len(), entities);
+        assert_eq!(*app.world().resource::<State<Level>>().get(), Level::Menu);
+    }
 }
@@ -30,6 +30,12 @@ use bevy_utils::default;
 ///
 /// Extraction happens immediately and uses the filter as it exists during the time of extraction.
 ///
+/// # State Extraction
+///
+/// With the `bevy_state` feature, the `State<S>` resources of states can be extracted on their own
+/// with `DynamicSceneBuilder::extract_states`. They are restored by queueing transitions when the
+/// scene is written to a world, however they were extracted.
+///
 /// # Entity Order
 ///
 /// Extracted entities will always be stored in ascending order based on their [index](Entity::index).
@@ -209,6 +215,7 @@ impl<'w> DynamicSceneBuilder<'w> {
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            prefabs: Vec::new(),
         }
     }
 
@@ -386,6 +393,52 @@ impl<'w> DynamicSceneBuilder<'w> {
         drop(type_registry);
         self
     }
+
+    /// Extract the `State<S>` resources of the [states](bevy_state::state::States) of the
+    /// builder's [`World`], regardless of the resource filter.
+    ///
+    /// Only states registered with [`register_type_mutable_state`] are extracted, since
+    /// other states can't be restored: computed states and sub states are restored along
+    /// with their source states instead.
+    ///
+    /// Re-extracting a state that was already extracted will update its value.
+    ///
+    /// [`register_type_mutable_state`]: bevy_state::app::AppExtStates::register_type_mutable_state
+    #[cfg(feature = "bevy_state")]
+    #[must_use]
+    pub fn extract_states(mut self) -> Self {
+        use bevy_state::reflect::{ReflectFreelyMutableState, ReflectState};
+
+        let type_registry = self.original_world.resource::<AppTypeRegistry>().read();
+
+        for (registration, reflect_state) in type_registry.iter_with_data::<ReflectState>() {
+            if !registration.contains::<ReflectFreelyMutableState>() {
+                continue;
+            }
+            let mut extract_and_push = || {
+                let type_id = reflect_state.state_resource_type_id();
+                let component_id = self.original_world.components().get_resource_id(type_id)?;
+                let type_registration = type_registry.get(type_id)?;
+
+                let resource = type_registration
+                    .data::<ReflectResource>()?
+                    .reflect(self.original_world)?;
+
+                let resource = type_registration
+                    .data::<ReflectFromReflect>()
+                    .and_then(|fr| fr.from_reflect(resource.as_partial_reflect()))
+                    .map(PartialReflect::into_partial_reflect)
+                    .unwrap_or_else(|| resource.clone_value());
+
+                self.extracted_resources.insert(component_id, resource);
+                Some(())
+            };
+            extract_and_push();
+        }
+
+        drop(type_registry);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -21,7 +21,11 @@ mod scene;
 mod scene_filter;
 mod scene_loader;
 mod scene_spawner;
+#[cfg(feature = "bevy_state")]
+mod state_scenes;
 
+#[cfg(feature = "serialize")]
+pub mod binary;
 #[cfg(feature = "serialize")]
 pub mod serde;
 
@@ -37,6 +41,8 @@ pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
 pub use scene_spawner::*;
+#[cfg(feature = "bevy_state")]
+pub use state_scenes::*;
 
 /// The scene prelude.
 ///
@@ -52,6 +58,8 @@ pub mod prelude {
 
 use bevy_app::prelude::*;
 use bevy_asset::AssetApp;
+#[cfg(feature = "serialize")]
+use bevy_ecs::world::FromWorld;
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -63,26 +71,37 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<binary::BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
 
+        // Allow the asset processor to turn scenes into binary scenes
+        if app
+            .world()
+            .contains_resource::<bevy_asset::processor::AssetProcessor>()
+        {
+            let saver = binary::BinarySceneSaver::from_world(app.world_mut());
+            let processor = app
+                .world()
+                .resource::<bevy_asset::processor::AssetProcessor>();
+            processor.register_processor::<bevy_asset::processor::LoadTransformAndSave<
+                SceneLoader,
+                bevy_asset::transformer::IdentityAssetTransformer<DynamicScene>,
+                binary::BinarySceneSaver,
+            >>(saver.into());
+        }
+
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
             .register_component_hooks::<DynamicSceneRoot>()
             .on_remove(|mut world, entity, _| {
-                let Some(handle) = world.get::<DynamicSceneRoot>(entity) else {
-                    return;
-                };
-                let id = handle.id();
                 if let Some(&SceneInstance(scene_instance)) = world.get::<SceneInstance>(entity) {
                     let Some(mut scene_spawner) = world.get_resource_mut::<SceneSpawner>() else {
                         return;
                     };
-                    if let Some(instance_ids) = scene_spawner.spawned_dynamic_scenes.get_mut(&id) {
-                        instance_ids.remove(&scene_instance);
-                    }
+                    scene_spawner.remove_dynamic_instance(scene_instance);
                     scene_spawner.despawn_instance(scene_instance);
                 }
             });
@@ -1,5 +1,5 @@
 #[cfg(feature = "serialize")]
-use crate::serde::SceneDeserializer;
+use crate::serde::{SceneDeserializer, SceneLoadContext};
 use crate::{ron, DynamicScene};
 use bevy_asset::{io::Reader, AssetLoader, LoadContext};
 use bevy_ecs::{
@@ -50,14 +50,17 @@ impl AssetLoader for SceneLoader {
         &self,
         reader: &mut dyn Reader,
         _settings: &(),
-        _load_context: &mut LoadContext<'_>,
+        load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
         let mut bytes = Vec::new();
         reader.read_to_end(&mut bytes).await?;
         let mut deserializer = ron::de::Deserializer::from_bytes(&bytes)?;
+        let type_registry = self.type_registry.read();
+        let mut assets = SceneLoadContext::new(load_context);
         let scene_deserializer = SceneDeserializer {
-            type_registry: &self.type_registry.read(),
-        };
+            type_registry: &type_registry,
+        }
+        .with_assets(&mut assets);
         Ok(scene_deserializer
             .deserialize(&mut deserializer)
             .map_err(|e| deserializer.span_error(e))?)
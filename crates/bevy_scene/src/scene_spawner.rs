@@ -8,7 +8,7 @@ use bevy_ecs::{
     world::{Command, Mut, World},
 };
 use bevy_hierarchy::{AddChild, BuildChildren, DespawnRecursiveExt, Parent};
-use bevy_reflect::Reflect;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_utils::{HashMap, HashSet};
 use derive_more::derive::{Display, Error};
 use uuid::Uuid;
@@ -43,6 +43,29 @@ impl InstanceId {
     }
 }
 
+/// How the live instances of a [`DynamicScene`] are updated when the scene asset is modified,
+/// for example when it is hot-reloaded.
+///
+/// See [`SceneSpawner::set_reload_mode`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Reflect)]
+#[reflect(Debug, Default, PartialEq, Hash)]
+pub enum SceneReloadMode {
+    /// Write the whole modified scene to every instance again.
+    ///
+    /// Every component in the scene overwrites its runtime value, and entities that were
+    /// removed from the scene stay in the world.
+    #[default]
+    Reapply,
+    /// Diff the previous and the modified version of the scene and only apply the differences
+    /// to every instance, with [`DynamicScene::reconcile_world`].
+    ///
+    /// Only components that changed in the scene are overwritten, entities added to the scene
+    /// are spawned and entities removed from the scene are despawned. Components added at runtime
+    /// are preserved. This requires keeping a copy of the last applied version of every spawned
+    /// dynamic scene.
+    Reconcile,
+}
+
 /// Handles spawning and despawning scenes in the world, either synchronously or batched through the [`scene_spawner_system`].
 ///
 /// Synchronous methods: (Scene operations will take effect immediately)
@@ -72,6 +95,10 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    reload_mode: SceneReloadMode,
+    /// The last version of each spawned dynamic scene written to its instances, used to diff
+    /// modified scenes in [`SceneReloadMode::Reconcile`].
+    applied_dynamic_scenes: HashMap<AssetId<DynamicScene>, DynamicScene>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -126,9 +153,34 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Scene contains a state whose type isn't registered as a freely mutable state.
+    #[display("scene contains the state `{type_path}` which can't be restored. consider registering it with `app.register_type_mutable_state::<S>()`")]
+    UnregisteredState {
+        /// Type of the state.
+        type_path: String,
+    },
+    /// Dynamic scene with the given id contains itself as a prefab.
+    #[display("scene contains itself as a prefab")]
+    RecursivePrefab {
+        /// Id of the dynamic scene containing itself.
+        id: AssetId<DynamicScene>,
+    },
 }
 
 impl SceneSpawner {
+    /// Get how the live instances of dynamic scenes are updated when their asset is modified.
+    pub fn reload_mode(&self) -> SceneReloadMode {
+        self.reload_mode
+    }
+
+    /// Set how the live instances of dynamic scenes are updated when their asset is modified.
+    pub fn set_reload_mode(&mut self, reload_mode: SceneReloadMode) {
+        self.reload_mode = reload_mode;
+        if reload_mode != SceneReloadMode::Reconcile {
+            self.applied_dynamic_scenes.clear();
+        }
+    }
+
     /// Schedule the spawn of a new instance of the provided dynamic scene.
     pub fn spawn_dynamic(&mut self, id: impl Into<Handle<DynamicScene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -182,7 +234,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.applied_dynamic_scenes.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -192,6 +246,7 @@ impl SceneSpawner {
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        self.remove_dynamic_instance(*instance_id);
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
             for &entity in instance.entity_map.values() {
                 if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
@@ -202,6 +257,22 @@ impl SceneSpawner {
         }
     }
 
+    /// Forget that `instance_id` is an instance of a dynamic scene, along with the version of the
+    /// scene applied to its instances once it has none left.
+    pub(crate) fn remove_dynamic_instance(&mut self, instance_id: InstanceId) {
+        let Some((&id, instance_ids)) = self
+            .spawned_dynamic_scenes
+            .iter_mut()
+            .find(|(_, instance_ids)| instance_ids.contains(&instance_id))
+        else {
+            return;
+        };
+        instance_ids.remove(&instance_id);
+        if instance_ids.is_empty() {
+            self.applied_dynamic_scenes.remove(&id);
+        }
+    }
+
     /// Immediately spawns a new instance of the provided dynamic scene.
     pub fn spawn_dynamic_sync(
         &mut self,
@@ -216,20 +287,69 @@ impl SceneSpawner {
             .insert(instance_id, InstanceInfo { entity_map });
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
+        self.record_applied_scene(world, id);
         Ok(instance_id)
     }
 
+    /// Keep a copy of the current version of a spawned dynamic scene, if it is needed to
+    /// reconcile its instances later on and none was kept yet.
+    fn record_applied_scene(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.reload_mode != SceneReloadMode::Reconcile
+            || self.applied_dynamic_scenes.contains_key(&id)
+        {
+            return;
+        }
+        let scenes = world.resource::<Assets<DynamicScene>>();
+        let Some(scene) = scenes.get(id) else {
+            return;
+        };
+        // Keep the scene with its prefabs resolved, so that modifications of the prefabs are
+        // diffed as well.
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+        let applied = match scene.resolve_prefabs(scenes, &type_registry) {
+            Ok(Some(resolved)) => resolved.scene,
+            Ok(None) => scene.clone_dynamic(),
+            Err(_) => return,
+        };
+        drop(type_registry);
+        self.applied_dynamic_scenes.insert(id, applied);
+    }
+
     fn spawn_dynamic_internal(
         world: &mut World,
         id: AssetId<DynamicScene>,
         entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        Self::apply_dynamic_scene(world, id, entity_map, None)
+    }
+
+    /// Write a dynamic scene to the world, resolving its prefabs, or reconcile it with the
+    /// `previous` version of the scene written to the world.
+    fn apply_dynamic_scene(
+        world: &mut World,
+        id: AssetId<DynamicScene>,
+        entity_map: &mut EntityHashMap<Entity>,
+        previous: Option<&DynamicScene>,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
             let scene = scenes
                 .get(id)
                 .ok_or(SceneSpawnError::NonExistentScene { id })?;
+            let type_registry = world.resource::<AppTypeRegistry>().clone();
+            let resolved = scene.resolve_prefabs(&scenes, &type_registry.read())?;
 
-            scene.write_to_world(world, entity_map)
+            match (resolved, previous) {
+                (Some(resolved), Some(previous)) => {
+                    resolved.reconcile_world_with(previous, world, entity_map, &type_registry)
+                }
+                (Some(resolved), None) => {
+                    resolved.write_to_world_with(world, entity_map, &type_registry)
+                }
+                (None, Some(previous)) => {
+                    scene.reconcile_world_with(previous, world, entity_map, &type_registry)
+                }
+                (None, None) => scene.write_to_world_with(world, entity_map, &type_registry),
+            }
         })
     }
 
@@ -269,19 +389,37 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// How the instances are updated depends on the [`SceneReloadMode`].
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
         for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
-                    }
-                }
+            let Some(spawned_instances) = self
+                .spawned_dynamic_scenes
+                .get(id)
+                .filter(|instances| !instances.is_empty())
+            else {
+                continue;
+            };
+
+            let previous = match self.reload_mode {
+                SceneReloadMode::Reapply => None,
+                SceneReloadMode::Reconcile => self.applied_dynamic_scenes.get(id),
+            };
+
+            for instance_id in spawned_instances {
+                let Some(instance_info) = self.spawned_instances.get_mut(instance_id) else {
+                    continue;
+                };
+                Self::apply_dynamic_scene(world, *id, &mut instance_info.entity_map, previous)?;
             }
+
+            // Only replace the applied scene once every instance was reconciled with it, so that
+            // a failed reload is reconciled with the same scene again on the next modification.
+            self.applied_dynamic_scenes.remove(id);
+            self.record_applied_scene(world, *id);
         }
         Ok(())
     }
@@ -321,6 +459,7 @@ impl SceneSpawner {
                         .entry(handle.id())
                         .or_insert_with(HashSet::new);
                     spawned.insert(instance_id);
+                    self.record_applied_scene(world, handle.id());
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -454,8 +593,17 @@ pub fn scene_spawner_system(world: &mut World) {
             .read(scene_asset_events)
         {
             if let AssetEvent::Modified { id } = event {
-                if scene_spawner.spawned_dynamic_scenes.contains_key(id) {
-                    updated_spawned_scenes.push(*id);
+                // Instances of scenes which contain the modified scene as a prefab are updated too.
+                let scenes = world.resource::<Assets<DynamicScene>>();
+                for spawned_id in scene_spawner.spawned_dynamic_scenes.keys() {
+                    if (spawned_id == id
+                        || scenes
+                            .get(*spawned_id)
+                            .is_some_and(|scene| scene.contains_prefab(*id, scenes)))
+                        && !updated_spawned_scenes.contains(spawned_id)
+                    {
+                        updated_spawned_scenes.push(*spawned_id);
+                    }
                 }
             }
         }
@@ -485,7 +633,7 @@ mod tests {
     };
     use bevy_reflect::Reflect;
 
-    use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
+    use crate::{DynamicSceneBuilder, DynamicScenePrefab, DynamicSceneRoot, ScenePlugin};
 
     use super::*;
 
@@ -493,6 +641,327 @@ mod tests {
     #[reflect(Component)]
     struct A(usize);
 
+    #[derive(Reflect, Component, Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[reflect(Component)]
+    struct B(usize);
+
+    #[derive(Component)]
+    struct RuntimeOnly;
+
+    fn scene_entity(
+        index: u32,
+        components: Vec<Box<dyn bevy_reflect::PartialReflect>>,
+    ) -> crate::DynamicEntity {
+        crate::DynamicEntity {
+            entity: Entity::from_raw(index),
+            components,
+        }
+    }
+
+    #[test]
+    fn reconcile_modified_dynamic_scene() {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        atr.write().register::<B>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let mut scene_spawner = SceneSpawner::default();
+        scene_spawner.set_reload_mode(SceneReloadMode::Reconcile);
+
+        let scene_id = world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene {
+                resources: Vec::new(),
+                entities: vec![
+                    scene_entity(0, vec![Box::new(A(1))]),
+                    scene_entity(1, vec![Box::new(A(2)), Box::new(B(7))]),
+                    scene_entity(2, vec![Box::new(A(5)), Box::new(B(1))]),
+                ],
+                prefabs: Vec::new(),
+            });
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, &scene_id)
+            .unwrap();
+        let entity_map = &scene_spawner.spawned_instances[&instance_id].entity_map;
+        let [removed, kept, stripped] = [0, 1, 2].map(|index| entity_map[&Entity::from_raw(index)]);
+
+        // Runtime edits.
+        world.entity_mut(kept).insert((A(50), RuntimeOnly));
+
+        // Modify the scene: remove entity 0, change `B` on entity 1, remove `B` from entity 2
+        // and add entity 3.
+        world.resource_mut::<Assets<DynamicScene>>().insert(
+            &scene_id,
+            DynamicScene {
+                resources: Vec::new(),
+                entities: vec![
+                    scene_entity(1, vec![Box::new(A(2)), Box::new(B(8))]),
+                    scene_entity(2, vec![Box::new(A(5))]),
+                    scene_entity(3, vec![Box::new(A(3))]),
+                ],
+                prefabs: Vec::new(),
+            },
+        );
+        scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id.id()])
+            .unwrap();
+
+        assert!(world.get_entity(removed).is_err());
+        assert_eq!(world.get::<A>(kept), Some(&A(50)));
+        assert_eq!(world.get::<B>(kept), Some(&B(8)));
+        assert!(world.get::<RuntimeOnly>(kept).is_some());
+        assert_eq!(world.get::<A>(stripped), Some(&A(5)));
+        assert!(world.get::<B>(stripped).is_none());
+
+        let entity_map = &scene_spawner.spawned_instances[&instance_id].entity_map;
+        assert_eq!(entity_map.len(), 3);
+        let added = entity_map[&Entity::from_raw(3)];
+        assert_eq!(world.get::<A>(added), Some(&A(3)));
+        assert_eq!(world.query::<&A>().iter(&world).len(), 3);
+    }
+
+    #[test]
+    fn forget_applied_scene_of_despawned_instances() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>();
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .set_reload_mode(SceneReloadMode::Reconcile);
+        let scene = app.world().resource::<AssetServer>().add(DynamicScene {
+            resources: Vec::new(),
+            entities: vec![scene_entity(0, vec![Box::new(A(1))])],
+            prefabs: Vec::new(),
+        });
+        let is_applied = |app: &App| {
+            app.world()
+                .resource::<SceneSpawner>()
+                .applied_dynamic_scenes
+                .contains_key(&scene.id())
+        };
+
+        // Despawning the only instance forgets the applied scene.
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene.clone());
+        app.update();
+        assert!(is_applied(&app));
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .despawn_instance(instance_id);
+        app.update();
+        assert!(!is_applied(&app));
+
+        // So does removing the roots of every instance, but only once the last one is removed.
+        let roots = [(); 2].map(|_| app.world_mut().spawn(DynamicSceneRoot(scene.clone())).id());
+        app.update();
+        assert!(is_applied(&app));
+        app.world_mut().entity_mut(roots[0]).despawn_recursive();
+        app.update();
+        assert!(is_applied(&app));
+        app.world_mut()
+            .entity_mut(roots[1])
+            .remove::<DynamicSceneRoot>();
+        app.update();
+        assert!(!is_applied(&app));
+
+        // Reloading the scene without instances doesn't record it again.
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&scene)
+            .unwrap();
+        app.update();
+        assert!(!is_applied(&app));
+    }
+
+    #[test]
+    fn reconcile_after_failed_reload() {
+        #[derive(Reflect, Component)]
+        #[reflect(Component)]
+        struct Unregistered;
+
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        atr.write().register::<B>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let mut scene_spawner = SceneSpawner::default();
+        scene_spawner.set_reload_mode(SceneReloadMode::Reconcile);
+
+        let scene_id = world
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(DynamicScene {
+                resources: Vec::new(),
+                entities: vec![scene_entity(0, vec![Box::new(A(1)), Box::new(B(1))])],
+                prefabs: Vec::new(),
+            });
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, &scene_id)
+            .unwrap();
+        let entity = scene_spawner.spawned_instances[&instance_id].entity_map[&Entity::from_raw(0)];
+
+        let mut modify_scene = |world: &mut World, components| {
+            world.resource_mut::<Assets<DynamicScene>>().insert(
+                &scene_id,
+                DynamicScene {
+                    resources: Vec::new(),
+                    entities: vec![scene_entity(0, components)],
+                    prefabs: Vec::new(),
+                },
+            );
+            scene_spawner.update_spawned_scenes(world, &[scene_id.id()])
+        };
+
+        // Removing `B` while adding an unregistered component fails...
+        assert!(modify_scene(&mut world, vec![Box::new(A(1)), Box::new(Unregistered)]).is_err());
+
+        // ...but the next modification is still reconciled with the scene spawned in the first
+        // place, so `B` is removed.
+        modify_scene(&mut world, vec![Box::new(A(2))]).unwrap();
+        assert_eq!(world.get::<A>(entity), Some(&A(2)));
+        assert!(world.get::<B>(entity).is_none());
+    }
+
+    #[test]
+    fn spawn_and_reload_prefabs() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>()
+            .register_type::<B>();
+
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let prefab = scenes.add(DynamicScene {
+            resources: Vec::new(),
+            entities: vec![scene_entity(0, vec![Box::new(A(1)), Box::new(B(1))])],
+            prefabs: Vec::new(),
+        });
+        let scene = scenes.add(DynamicScene {
+            resources: Vec::new(),
+            entities: vec![scene_entity(0, vec![Box::new(A(100))])],
+            prefabs: vec![DynamicScenePrefab::new(Entity::from_raw(0), prefab.clone())
+                .with_override(Entity::from_raw(0), vec![Box::new(B(5))])],
+        });
+
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(scene.clone());
+        app.update();
+
+        let world = app.world();
+        let entities = world
+            .resource::<SceneSpawner>()
+            .iter_instance_entities(instance_id)
+            .collect::<Vec<_>>();
+        assert_eq!(entities.len(), 2);
+        let root = *entities
+            .iter()
+            .find(|&&entity| world.get::<A>(entity) == Some(&A(100)))
+            .unwrap();
+        let nested = *entities.iter().find(|&&entity| entity != root).unwrap();
+        assert_eq!(world.get::<A>(nested), Some(&A(1)));
+        assert_eq!(world.get::<B>(nested), Some(&B(5)));
+        assert_eq!(world.get::<Parent>(nested).map(Parent::get), Some(root));
+
+        // Modifying the prefab updates the instances of the scenes containing it.
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .get_mut(&prefab)
+            .unwrap()
+            .entities[0]
+            .components[0] = Box::new(A(9));
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<A>(nested), Some(&A(9)));
+        assert_eq!(app.world().get::<B>(nested), Some(&B(5)));
+    }
+
+    #[test]
+    fn reconcile_keeps_prefab_entities() {
+        let mut world = World::default();
+        let atr = AppTypeRegistry::default();
+        atr.write().register::<A>();
+        world.insert_resource(atr);
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let mut scene_spawner = SceneSpawner::default();
+        scene_spawner.set_reload_mode(SceneReloadMode::Reconcile);
+
+        let mut scenes = world.resource_mut::<Assets<DynamicScene>>();
+        let prefab = scenes.add(DynamicScene {
+            resources: Vec::new(),
+            entities: vec![scene_entity(0, vec![Box::new(A(1))])],
+            prefabs: Vec::new(),
+        });
+        let scene_with_entities = |entities| DynamicScene {
+            resources: Vec::new(),
+            entities,
+            prefabs: vec![DynamicScenePrefab::new(Entity::from_raw(0), prefab.clone())],
+        };
+        let scene_id = scenes.add(scene_with_entities(vec![scene_entity(0, Vec::new())]));
+        let instance_id = scene_spawner
+            .spawn_dynamic_sync(&mut world, &scene_id)
+            .unwrap();
+        let nested = world.query::<(Entity, &A)>().single(&world).0;
+        world.entity_mut(nested).insert(RuntimeOnly);
+
+        // Adding an entity to the scene doesn't respawn the entity of the prefab.
+        world.resource_mut::<Assets<DynamicScene>>().insert(
+            &scene_id,
+            scene_with_entities(vec![
+                scene_entity(0, Vec::new()),
+                scene_entity(1, vec![Box::new(A(2))]),
+            ]),
+        );
+        scene_spawner
+            .update_spawned_scenes(&mut world, &[scene_id.id()])
+            .unwrap();
+
+        assert_eq!(world.get::<A>(nested), Some(&A(1)));
+        assert!(world.get::<RuntimeOnly>(nested).is_some());
+        assert_eq!(
+            scene_spawner
+                .iter_instance_entities(instance_id)
+                .filter(|&entity| world.get::<A>(entity).is_some())
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn recursive_prefab_is_an_error() {
+        let mut world = World::default();
+        world.init_resource::<AppTypeRegistry>();
+        world.insert_resource(Assets::<DynamicScene>::default());
+
+        let scene_id = world
+            .resource_mut::<Assets<DynamicScene>>()
+            .reserve_handle();
+        world.resource_mut::<Assets<DynamicScene>>().insert(
+            &scene_id,
+            DynamicScene {
+                resources: Vec::new(),
+                entities: vec![scene_entity(0, Vec::new())],
+                prefabs: vec![DynamicScenePrefab::new(
+                    Entity::from_raw(0),
+                    scene_id.clone(),
+                )],
+            },
+        );
+
+        let result = SceneSpawner::default().spawn_dynamic_sync(&mut world, &scene_id);
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::RecursivePrefab { id }) if id == scene_id.id()
+        ));
+    }
+
     #[test]
     fn clone_dynamic_entities() {
         let mut world = World::default();
@@ -1,21 +1,25 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
-use bevy_ecs::entity::Entity;
+use crate::{DynamicEntity, DynamicScene, DynamicScenePrefab};
+use bevy_asset::{
+    AssetPath, LoadContext, ReflectAsset, ReflectHandle, UntypedAssetId, UntypedHandle,
+};
+use bevy_ecs::{entity::Entity, world::World};
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        ReflectDeserializer, ReflectDeserializerProcessor, ReflectSerializerProcessor,
+        TypeRegistrationDeserializer, TypedReflectDeserializer, TypedReflectSerializer,
     },
-    PartialReflect, ReflectFromReflect, TypeRegistry,
+    PartialReflect, ReflectFromReflect, TypeRegistration, TypeRegistry,
 };
 use bevy_utils::HashSet;
-use core::fmt::Formatter;
+use core::{any::TypeId, fmt::Formatter};
 use serde::{
-    de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor},
+    de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor},
     ser::{SerializeMap, SerializeStruct},
     Deserialize, Deserializer, Serialize, Serializer,
 };
+use uuid::Uuid;
 
 /// Name of the serialized scene struct type.
 pub const SCENE_STRUCT: &str = "Scene";
@@ -23,12 +27,104 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized prefabs field in a scene struct.
+pub const SCENE_PREFABS: &str = "prefabs";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized prefab struct type.
+pub const PREFAB_STRUCT: &str = "Prefab";
+/// Name of the serialized scene field in a prefab struct.
+pub const PREFAB_FIELD_SCENE: &str = "scene";
+/// Name of the serialized overrides field in a prefab struct.
+pub const PREFAB_FIELD_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized asset handle enum type.
+pub const HANDLE_ENUM: &str = "Handle";
+/// Name of the handle variant holding the asset path of the asset.
+pub const HANDLE_VARIANT_PATH: &str = "Path";
+/// Name of the handle variant holding the [`Uuid`] of the asset.
+pub const HANDLE_VARIANT_UUID: &str = "Uuid";
+/// Name of the handle variant holding the inlined value of the asset.
+pub const HANDLE_VARIANT_INLINE: &str = "Inline";
+
+/// Provides handles to the assets referenced by a scene while it is deserialized,
+/// such as the scenes of its [prefabs](DynamicScenePrefab) or the assets of its
+/// [`Handle`](bevy_asset::Handle) fields.
+///
+/// This is implemented for [`SceneLoadContext`], so that the referenced assets are loaded
+/// along with the scene asset, and inlined assets become labeled assets of the scene.
+pub trait SceneAssets {
+    /// Get a handle to the asset of the type with the given [`TypeId`] at `path`.
+    fn load_erased(&mut self, type_id: TypeId, path: AssetPath<'static>) -> UntypedHandle;
+
+    /// Add an asset whose value was inlined in the scene, returning a handle to it.
+    ///
+    /// Returns `None` if the value can't be converted to the asset type of `reflect_asset`.
+    fn add_inlined(
+        &mut self,
+        reflect_asset: &ReflectAsset,
+        asset: &dyn PartialReflect,
+    ) -> Option<UntypedHandle>;
+}
+
+/// [`SceneAssets`] loading the assets referenced by a scene from the [`LoadContext`] of the
+/// scene asset, and adding its inlined assets as labeled assets named `Inlined<index>`.
+pub struct SceneLoadContext<'a, 'ctx> {
+    load_context: &'a mut LoadContext<'ctx>,
+    labels: HashSet<String>,
+    next_inlined: usize,
+}
+
+impl<'a, 'ctx> SceneLoadContext<'a, 'ctx> {
+    /// Create [`SceneAssets`] for the scene loaded with `load_context`.
+    pub fn new(load_context: &'a mut LoadContext<'ctx>) -> Self {
+        let labels = load_context.iter_labels().map(str::to_owned).collect();
+        Self {
+            load_context,
+            labels,
+            next_inlined: 0,
+        }
+    }
+}
+
+impl SceneAssets for SceneLoadContext<'_, '_> {
+    fn load_erased(&mut self, type_id: TypeId, path: AssetPath<'static>) -> UntypedHandle {
+        self.load_context
+            .loader()
+            .with_dynamic_type(type_id)
+            .load(path)
+    }
+
+    fn add_inlined(
+        &mut self,
+        reflect_asset: &ReflectAsset,
+        asset: &dyn PartialReflect,
+    ) -> Option<UntypedHandle> {
+        let label = loop {
+            let label = format!("Inlined{}", self.next_inlined);
+            self.next_inlined += 1;
+            if self.labels.insert(label.clone()) {
+                break label;
+            }
+        };
+        reflect_asset.add_labeled(self.load_context, label, asset)
+    }
+}
+
+/// Reborrow optional [`SceneAssets`] for a nested deserializer.
+pub(crate) fn reborrow<'s>(
+    assets: &'s mut Option<&mut dyn SceneAssets>,
+) -> Option<&'s mut dyn SceneAssets> {
+    match assets {
+        Some(assets) => Some(&mut **assets),
+        None => None,
+    }
+}
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -54,6 +150,12 @@ pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 /// // Serialize through any serde-compatible Serializer
 /// let ron_string = bevy_scene::ron::ser::to_string(&scene_serializer);
 /// ```
+///
+/// [`Handle`](bevy_asset::Handle)s in the scene are serialized as the asset path of their asset:
+/// see [`HandleSerializerProcessor`].
+///
+/// Scenes with [prefabs](DynamicScenePrefab) can only be serialized to human-readable formats:
+/// use [`serialize_binary_scene`](crate::binary::serialize_binary_scene) for a binary format.
 pub struct SceneSerializer<'a> {
     /// The scene to serialize.
     pub scene: &'a DynamicScene,
@@ -71,6 +173,64 @@ impl<'a> SceneSerializer<'a> {
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
         SceneSerializer { scene, registry }
     }
+
+    /// Inline the value of the assets created at runtime, which have no asset path,
+    /// taking them from the [`Assets`](bevy_asset::Assets) in `world`.
+    ///
+    /// This is meant for small assets such as materials: larger assets should be saved
+    /// to their own file and referenced by path instead.
+    pub fn with_inlined_assets(self, world: &'a World) -> InliningSceneSerializer<'a> {
+        InliningSceneSerializer {
+            serializer: self,
+            inline_assets: world,
+        }
+    }
+}
+
+/// Serializer for a [`DynamicScene`] inlining the value of its assets without an asset path.
+///
+/// Created with [`SceneSerializer::with_inlined_assets`].
+pub struct InliningSceneSerializer<'a> {
+    serializer: SceneSerializer<'a>,
+    inline_assets: &'a World,
+}
+
+impl<'a> Serialize for InliningSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serializer
+            .serialize_inlining(Some(self.inline_assets), serializer)
+    }
+}
+
+/// Serialization of a part of a scene, inlining the value of its assets without an asset path
+/// taken from `inline_assets`, if any.
+trait SerializeInlining {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// A part of a scene to serialize along with the world holding the assets to inline.
+struct Inlining<'w, T> {
+    value: T,
+    inline_assets: Option<&'w World>,
+}
+
+impl<T: SerializeInlining> Serialize for Inlining<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value
+            .serialize_inlining(self.inline_assets, serializer)
+    }
 }
 
 impl<'a> Serialize for SceneSerializer<'a> {
@@ -78,19 +238,149 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for SceneSerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Scenes without prefabs keep the layout they had before prefabs were supported. Formats
+        // that aren't human-readable may not tell whether the prefabs follow, so they are read
+        // with this layout: scenes with prefabs use the binary scene format instead.
+        let with_prefabs = !self.scene.prefabs.is_empty();
+        if with_prefabs && !serializer.is_human_readable() {
+            return Err(serde::ser::Error::custom(
+                "scenes with prefabs can only be serialized to human-readable formats, \
+                use `serialize_binary_scene` for a binary format",
+            ));
+        }
+        let mut state =
+            serializer.serialize_struct(SCENE_STRUCT, if with_prefabs { 3 } else { 2 })?;
         state.serialize_field(
             SCENE_RESOURCES,
-            &SceneMapSerializer {
-                entries: &self.scene.resources,
-                registry: self.registry,
+            &Inlining {
+                value: SceneMapSerializer {
+                    entries: &self.scene.resources,
+                    registry: self.registry,
+                },
+                inline_assets,
             },
         )?;
         state.serialize_field(
             SCENE_ENTITIES,
-            &EntitiesSerializer {
-                entities: &self.scene.entities,
-                registry: self.registry,
+            &Inlining {
+                value: EntitiesSerializer {
+                    entities: &self.scene.entities,
+                    registry: self.registry,
+                },
+                inline_assets,
+            },
+        )?;
+        if with_prefabs {
+            state.serialize_field(
+                SCENE_PREFABS,
+                &Inlining {
+                    value: PrefabsSerializer {
+                        prefabs: &self.scene.prefabs,
+                        registry: self.registry,
+                    },
+                    inline_assets,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of the prefabs of a scene as a map of entity id to serialized prefab.
+pub struct PrefabsSerializer<'a> {
+    /// The prefabs to serialize.
+    pub prefabs: &'a [DynamicScenePrefab],
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for PrefabsSerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.prefabs.len()))?;
+        for prefab in self.prefabs {
+            state.serialize_entry(
+                &prefab.entity,
+                &Inlining {
+                    value: PrefabSerializer {
+                        prefab,
+                        registry: self.registry,
+                    },
+                    inline_assets,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles prefab serialization as the asset path of its scene and its overrides.
+pub struct PrefabSerializer<'a> {
+    /// The prefab to serialize.
+    pub prefab: &'a DynamicScenePrefab,
+    /// Type registry in which the component types used by the overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PrefabSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for PrefabSerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let path = self.prefab.scene.path().ok_or_else(|| {
+            serde::ser::Error::custom("the scene of a prefab must have an asset path")
+        })?;
+        let mut state = serializer.serialize_struct(PREFAB_STRUCT, 2)?;
+        state.serialize_field(PREFAB_FIELD_SCENE, path)?;
+        state.serialize_field(
+            PREFAB_FIELD_OVERRIDES,
+            &Inlining {
+                value: EntitiesSerializer {
+                    entities: &self.prefab.overrides,
+                    registry: self.registry,
+                },
+                inline_assets,
             },
         )?;
         state.end()
@@ -107,6 +397,19 @@ pub struct EntitiesSerializer<'a> {
 
 impl<'a> Serialize for EntitiesSerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for EntitiesSerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -114,9 +417,12 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
         for entity in self.entities {
             state.serialize_entry(
                 &entity.entity,
-                &EntitySerializer {
-                    entity,
-                    registry: self.registry,
+                &Inlining {
+                    value: EntitySerializer {
+                        entity,
+                        registry: self.registry,
+                    },
+                    inline_assets,
                 },
             )?;
         }
@@ -134,15 +440,31 @@ pub struct EntitySerializer<'a> {
 
 impl<'a> Serialize for EntitySerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for EntitySerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let mut state = serializer.serialize_struct(ENTITY_STRUCT, 1)?;
         state.serialize_field(
             ENTITY_FIELD_COMPONENTS,
-            &SceneMapSerializer {
-                entries: &self.entity.components,
-                registry: self.registry,
+            &Inlining {
+                value: SceneMapSerializer {
+                    entries: &self.entity.components,
+                    registry: self.registry,
+                },
+                inline_assets,
             },
         )?;
         state.end()
@@ -165,6 +487,19 @@ pub struct SceneMapSerializer<'a> {
 
 impl<'a> Serialize for SceneMapSerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.serialize_inlining(None, serializer)
+    }
+}
+
+impl<'a> SerializeInlining for SceneMapSerializer<'a> {
+    fn serialize_inlining<S>(
+        &self,
+        inline_assets: Option<&World>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -184,21 +519,126 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
             entries
         };
 
+        let processor = HandleSerializerProcessor { inline_assets };
         for (type_path, partial_reflect) in sorted_entries {
             state.serialize_entry(
                 type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
+                &TypedReflectSerializer::with_processor(partial_reflect, self.registry, &processor),
             )?;
         }
         state.end()
     }
 }
 
+/// A [`ReflectSerializerProcessor`] serializing [`Handle`](bevy_asset::Handle)s as a reference
+/// to their asset rather than as their runtime state.
+///
+/// Handles are serialized as the [`AssetPath`] of their asset, or as its [`Uuid`] for assets
+/// with a fixed id. Assets created at runtime have neither: their value is inlined if
+/// `inline_assets` is set, and serializing their handles fails otherwise.
+///
+/// The asset types of inlined assets must be registered with
+/// [`register_asset_reflect`](bevy_asset::AssetApp::register_asset_reflect).
+pub struct HandleSerializerProcessor<'a> {
+    /// The world holding the assets without an asset path to inline, if any.
+    pub inline_assets: Option<&'a World>,
+}
+
+impl<'a> HandleSerializerProcessor<'a> {
+    /// Get the reflected value of the asset of `handle` to inline it, if possible.
+    fn inlined_asset(
+        &self,
+        reflect_handle: &ReflectHandle,
+        handle: UntypedHandle,
+        registry: &TypeRegistry,
+    ) -> Option<&'a dyn PartialReflect> {
+        let world = self.inline_assets?;
+        let reflect_asset =
+            registry.get_type_data::<ReflectAsset>(reflect_handle.asset_type_id())?;
+        let component_id = world
+            .components()
+            .get_resource_id(reflect_asset.assets_resource_type_id())?;
+        if !world.contains_resource_by_id(component_id) {
+            return None;
+        }
+        reflect_asset
+            .get(world, handle)
+            .map(PartialReflect::as_partial_reflect)
+    }
+}
+
+impl ReflectSerializerProcessor for HandleSerializerProcessor<'_> {
+    fn try_serialize<S>(
+        &self,
+        value: &dyn PartialReflect,
+        registry: &TypeRegistry,
+        serializer: S,
+    ) -> Result<Result<S::Ok, S>, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(type_info) = value.get_represented_type_info() else {
+            return Ok(Err(serializer));
+        };
+        let Some(reflect_handle) = registry.get_type_data::<ReflectHandle>(type_info.type_id())
+        else {
+            return Ok(Err(serializer));
+        };
+
+        // Values cloned with reflection are dynamic, so they must be converted back into a handle.
+        let handle = value
+            .try_as_reflect()
+            .and_then(|value| reflect_handle.downcast_handle_untyped(value.as_any()))
+            .or_else(|| {
+                let handle = registry
+                    .get_type_data::<ReflectFromReflect>(type_info.type_id())?
+                    .from_reflect(value)?;
+                reflect_handle.downcast_handle_untyped(handle.as_any())
+            })
+            .ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "failed to convert `{}` into a handle",
+                    type_info.type_path()
+                ))
+            })?;
+
+        if let Some(path) = handle.path() {
+            return serializer
+                .serialize_newtype_variant(HANDLE_ENUM, 0, HANDLE_VARIANT_PATH, path)
+                .map(Ok);
+        }
+        match handle.id() {
+            UntypedAssetId::Uuid { uuid, .. } => serializer
+                .serialize_newtype_variant(HANDLE_ENUM, 1, HANDLE_VARIANT_UUID, &uuid)
+                .map(Ok),
+            UntypedAssetId::Index { .. } => {
+                let id = handle.id();
+                let asset = self
+                    .inlined_asset(reflect_handle, handle, registry)
+                    .ok_or_else(|| {
+                        serde::ser::Error::custom(format_args!(
+                            "the asset {id:?} has no asset path and can't be inlined"
+                        ))
+                    })?;
+                serializer
+                    .serialize_newtype_variant(
+                        HANDLE_ENUM,
+                        2,
+                        HANDLE_VARIANT_INLINE,
+                        &TypedReflectSerializer::with_processor(asset, registry, self),
+                    )
+                    .map(Ok)
+            }
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
 enum SceneField {
     Resources,
     Entities,
+    Prefabs,
 }
 
 #[derive(Deserialize)]
@@ -207,12 +647,33 @@ enum EntityField {
     Components,
 }
 
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PrefabField {
+    Scene,
+    Overrides,
+}
+
 /// Handles scene deserialization.
+///
+/// Scenes with [prefabs](DynamicScenePrefab) can't be deserialized with this deserializer,
+/// since handles to their scenes must be created: use [`SceneDeserializer::with_assets`] instead.
 pub struct SceneDeserializer<'a> {
     /// Type registry in which the components and resources types used in the scene to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
+impl<'a> SceneDeserializer<'a> {
+    /// Get handles to the assets referenced by the scene, such as the scenes of its
+    /// [prefabs](DynamicScenePrefab), from `assets`, usually a [`SceneLoadContext`].
+    pub fn with_assets(self, assets: &'a mut dyn SceneAssets) -> SceneAssetsDeserializer<'a> {
+        SceneAssetsDeserializer {
+            type_registry: self.type_registry,
+            assets,
+        }
+    }
+}
+
 impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     type Value = DynamicScene;
 
@@ -220,18 +681,84 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
+        let fields = scene_fields(deserializer.is_human_readable());
         deserializer.deserialize_struct(
             SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            fields,
             SceneVisitor {
                 type_registry: self.type_registry,
+                assets: None,
             },
         )
     }
 }
 
+/// Handles scene deserialization, getting handles to the assets referenced by the scene,
+/// such as the scenes of its [prefabs](DynamicScenePrefab), from [`SceneAssets`].
+///
+/// Created with [`SceneDeserializer::with_assets`].
+pub struct SceneAssetsDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+    assets: &'a mut dyn SceneAssets,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneAssetsDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields = scene_fields(deserializer.is_human_readable());
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            fields,
+            SceneVisitor {
+                type_registry: self.type_registry,
+                assets: Some(self.assets),
+            },
+        )
+    }
+}
+
+/// The fields of a serialized scene: formats that aren't human-readable can't hold prefabs, see
+/// [`SceneSerializer`].
+fn scene_fields(is_human_readable: bool) -> &'static [&'static str] {
+    if is_human_readable {
+        &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_PREFABS]
+    } else {
+        &[SCENE_RESOURCES, SCENE_ENTITIES]
+    }
+}
+
 struct SceneVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub assets: Option<&'a mut dyn SceneAssets>,
+}
+
+/// Create the prefabs of a scene from their deserialized asset path and overrides.
+fn load_prefabs<E: Error>(
+    assets: Option<&mut dyn SceneAssets>,
+    prefabs: Vec<PrefabEntry>,
+) -> Result<Vec<DynamicScenePrefab>, E> {
+    if prefabs.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(assets) = assets else {
+        return Err(Error::custom(
+            "the scene contains prefabs, which requires deserializing it with `SceneDeserializer::with_assets`",
+        ));
+    };
+    Ok(prefabs
+        .into_iter()
+        .map(|prefab| DynamicScenePrefab {
+            entity: prefab.entity,
+            scene: assets
+                .load_erased(TypeId::of::<DynamicScene>(), prefab.path)
+                .typed(),
+            overrides: prefab.overrides,
+        })
+        .collect())
 }
 
 impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
@@ -245,21 +772,32 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
+        let mut assets = self.assets;
         let resources = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneMapVisitor {
                 registry: self.type_registry,
+                assets: reborrow(&mut assets),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
         let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
+            .next_element_seed(SceneEntitiesVisitor {
                 type_registry: self.type_registry,
+                assets: reborrow(&mut assets),
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
+        let prefabs = seq
+            .next_element_seed(ScenePrefabsDeserializer {
+                type_registry: self.type_registry,
+                assets: reborrow(&mut assets),
+            })?
+            .unwrap_or_default();
+
         Ok(DynamicScene {
             resources,
             entities,
+            prefabs: load_prefabs(assets, prefabs)?,
         })
     }
 
@@ -267,24 +805,37 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let mut assets = self.assets;
         let mut resources = None;
         let mut entities = None;
+        let mut prefabs = None;
         while let Some(key) = map.next_key()? {
             match key {
                 SceneField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
-                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                    resources = Some(map.next_value_seed(SceneMapVisitor {
                         registry: self.type_registry,
+                        assets: reborrow(&mut assets),
                     })?);
                 }
                 SceneField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                    entities = Some(map.next_value_seed(SceneEntitiesVisitor {
+                        type_registry: self.type_registry,
+                        assets: reborrow(&mut assets),
+                    })?);
+                }
+                SceneField::Prefabs => {
+                    if prefabs.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PREFABS));
+                    }
+                    prefabs = Some(map.next_value_seed(ScenePrefabsDeserializer {
                         type_registry: self.type_registry,
+                        assets: reborrow(&mut assets),
                     })?);
                 }
             }
@@ -296,6 +847,141 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         Ok(DynamicScene {
             resources,
             entities,
+            prefabs: load_prefabs(assets, prefabs.unwrap_or_default())?,
+        })
+    }
+}
+
+/// A deserialized prefab, whose scene hasn't been loaded yet.
+struct PrefabEntry {
+    entity: Entity,
+    path: AssetPath<'static>,
+    overrides: Vec<DynamicEntity>,
+}
+
+/// Handles deserialization of the prefabs of a scene.
+struct ScenePrefabsDeserializer<'a> {
+    type_registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabsDeserializer<'a> {
+    type Value = Vec<PrefabEntry>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePrefabsDeserializer<'a> {
+    type Value = Vec<PrefabEntry>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of prefabs")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut prefabs = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let prefab = map.next_value_seed(ScenePrefabDeserializer {
+                entity,
+                type_registry: self.type_registry,
+                assets: reborrow(&mut self.assets),
+            })?;
+            prefabs.push(prefab);
+        }
+
+        Ok(prefabs)
+    }
+}
+
+/// Handles deserialization of a prefab.
+struct ScenePrefabDeserializer<'a> {
+    entity: Entity,
+    type_registry: &'a TypeRegistry,
+    assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabDeserializer<'a> {
+    type Value = PrefabEntry;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            PREFAB_STRUCT,
+            &[PREFAB_FIELD_SCENE, PREFAB_FIELD_OVERRIDES],
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ScenePrefabDeserializer<'a> {
+    type Value = PrefabEntry;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("prefab struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let path = seq
+            .next_element::<AssetPath<'static>>()?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        let overrides = seq
+            .next_element_seed(SceneEntitiesVisitor {
+                type_registry: self.type_registry,
+                assets: self.assets,
+            })?
+            .ok_or_else(|| Error::missing_field(PREFAB_FIELD_OVERRIDES))?;
+
+        Ok(PrefabEntry {
+            entity: self.entity,
+            path,
+            overrides,
+        })
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut path = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PrefabField::Scene => {
+                    if path.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_SCENE));
+                    }
+                    path = Some(map.next_value::<AssetPath<'static>>()?);
+                }
+                PrefabField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(PREFAB_FIELD_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(SceneEntitiesVisitor {
+                        type_registry: self.type_registry,
+                        assets: reborrow(&mut self.assets),
+                    })?);
+                }
+            }
+        }
+
+        let path = path.ok_or_else(|| Error::missing_field(PREFAB_FIELD_SCENE))?;
+        Ok(PrefabEntry {
+            entity: self.entity,
+            path,
+            overrides: overrides.unwrap_or_default(),
         })
     }
 }
@@ -313,14 +999,30 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        SceneEntitiesVisitor {
             type_registry: self.type_registry,
-        })
+            assets: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
+/// Deserializes a collection of entities, getting handles to the assets they reference from
+/// `assets`, if any.
 struct SceneEntitiesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
+    pub assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
@@ -330,15 +1032,16 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
         formatter.write_str("map of entities")
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
         let mut entities = Vec::new();
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
+            let entity = map.next_value_seed(SceneEntityVisitor {
                 entity,
-                type_registry: self.type_registry,
+                registry: self.type_registry,
+                assets: reborrow(&mut self.assets),
             })?;
             entities.push(entity);
         }
@@ -362,20 +1065,32 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
-                entity: self.entity,
-                registry: self.type_registry,
-            },
-        )
+        SceneEntityVisitor {
+            entity: self.entity,
+            registry: self.type_registry,
+            assets: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
+/// Deserializes an entity and its components, getting handles to the assets they reference
+/// from `assets`, if any.
 struct SceneEntityVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
+    pub assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_FIELD_COMPONENTS], self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
@@ -390,8 +1105,9 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let components = seq
-            .next_element_seed(SceneMapDeserializer {
+            .next_element_seed(SceneMapVisitor {
                 registry: self.registry,
+                assets: self.assets,
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
@@ -401,7 +1117,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         })
     }
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
     where
         A: MapAccess<'de>,
     {
@@ -413,8 +1129,9 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
 
-                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                    components = Some(map.next_value_seed(SceneMapVisitor {
                         registry: self.registry,
+                        assets: reborrow(&mut self.assets),
                     })?);
                 }
             }
@@ -443,14 +1160,30 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneMapVisitor {
+        SceneMapVisitor {
             registry: self.registry,
-        })
+            assets: None,
+        }
+        .deserialize(deserializer)
     }
 }
 
+/// Deserializes a sequence of values with unique types, getting handles to the assets they
+/// reference from `assets`, if any.
 struct SceneMapVisitor<'a> {
     pub registry: &'a TypeRegistry,
+    pub assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
 }
 
 impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
@@ -464,8 +1197,14 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     where
         A: SeqAccess<'de>,
     {
+        let mut processor = HandleDeserializerProcessor {
+            assets: self.assets,
+        };
         let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
+        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::with_processor(
+            self.registry,
+            &mut processor,
+        ))? {
             dynamic_properties.push(entity);
         }
 
@@ -476,6 +1215,9 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let mut processor = HandleDeserializerProcessor {
+            assets: self.assets,
+        };
         let mut added = HashSet::new();
         let mut entries = Vec::new();
         while let Some(registration) =
@@ -488,8 +1230,11 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            let value = map.next_value_seed(TypedReflectDeserializer::with_processor(
+                registration,
+                self.registry,
+                &mut processor,
+            ))?;
 
             // Attempt to convert using FromReflect.
             let value = self
@@ -507,13 +1252,126 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
     }
 }
 
+/// A [`ReflectDeserializerProcessor`] deserializing [`Handle`](bevy_asset::Handle)s
+/// serialized by a [`HandleSerializerProcessor`].
+///
+/// Handles to an asset path are loaded and inlined assets are added through [`SceneAssets`]:
+/// without it, only handles to assets with a fixed [`Uuid`] can be deserialized.
+pub struct HandleDeserializerProcessor<'a> {
+    /// Provider of the handles to the referenced assets, usually a [`LoadContext`].
+    pub assets: Option<&'a mut dyn SceneAssets>,
+}
+
+impl ReflectDeserializerProcessor for HandleDeserializerProcessor<'_> {
+    fn try_deserialize<'de, D>(
+        &mut self,
+        registration: &TypeRegistration,
+        registry: &TypeRegistry,
+        deserializer: D,
+    ) -> Result<Result<Box<dyn PartialReflect>, D>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(reflect_handle) = registration.data::<ReflectHandle>() else {
+            return Ok(Err(deserializer));
+        };
+        let handle = deserializer.deserialize_enum(
+            HANDLE_ENUM,
+            &[
+                HANDLE_VARIANT_PATH,
+                HANDLE_VARIANT_UUID,
+                HANDLE_VARIANT_INLINE,
+            ],
+            HandleVisitor {
+                reflect_handle,
+                registry,
+                processor: self,
+            },
+        )?;
+        Ok(Ok(reflect_handle.typed(handle).into_partial_reflect()))
+    }
+}
+
+#[derive(Deserialize)]
+enum HandleVariant {
+    Path,
+    Uuid,
+    Inline,
+}
+
+struct HandleVisitor<'a, 'p> {
+    reflect_handle: &'a ReflectHandle,
+    registry: &'a TypeRegistry,
+    processor: &'a mut HandleDeserializerProcessor<'p>,
+}
+
+impl<'a, 'p, 'de> Visitor<'de> for HandleVisitor<'a, 'p> {
+    type Value = UntypedHandle;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("asset handle")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let type_id = self.reflect_handle.asset_type_id();
+        let (variant, access) = data.variant::<HandleVariant>()?;
+        match variant {
+            HandleVariant::Path => {
+                let path = access.newtype_variant::<AssetPath<'static>>()?;
+                let assets = self.processor.assets.as_mut().ok_or_else(|| {
+                    Error::custom(format_args!(
+                        "loading the asset at `{path}` requires deserializing the scene with `SceneAssets`"
+                    ))
+                })?;
+                Ok(assets.load_erased(type_id, path))
+            }
+            HandleVariant::Uuid => {
+                let uuid = access.newtype_variant::<Uuid>()?;
+                Ok(UntypedHandle::Weak(UntypedAssetId::Uuid { type_id, uuid }))
+            }
+            HandleVariant::Inline => {
+                let reflect_asset = self
+                    .registry
+                    .get_type_data::<ReflectAsset>(type_id)
+                    .ok_or_else(|| {
+                        Error::custom("the type of an inlined asset must be registered with `register_asset_reflect`")
+                    })?;
+                let registration = self.registry.get(type_id).unwrap();
+                let asset =
+                    access.newtype_variant_seed(TypedReflectDeserializer::with_processor(
+                        registration,
+                        self.registry,
+                        &mut *self.processor,
+                    ))?;
+                let assets = self.processor.assets.as_mut().ok_or_else(|| {
+                    Error::custom("adding an inlined asset requires deserializing the scene with `SceneAssets`")
+                })?;
+                assets
+                    .add_inlined(reflect_asset, asset.as_partial_reflect())
+                    .ok_or_else(|| {
+                        Error::custom(format_args!(
+                            "the inlined asset can't be converted to `{}`",
+                            registration.type_info().type_path()
+                        ))
+                    })
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         ron,
-        serde::{SceneDeserializer, SceneSerializer},
+        serde::{SceneAssets, SceneDeserializer, SceneSerializer},
         DynamicScene, DynamicSceneBuilder,
     };
+    use bevy_asset::{
+        Asset, AssetPath, Assets, Handle, ReflectAsset, UntypedAssetId, UntypedHandle,
+    };
     use bevy_ecs::{
         entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
@@ -521,10 +1379,12 @@ mod tests {
         reflect::{AppTypeRegistry, ReflectMapEntities},
         world::FromWorld,
     };
-    use bevy_reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+    use bevy_reflect::{PartialReflect, Reflect, ReflectDeserialize, ReflectSerialize};
     use bincode::Options;
+    use core::any::TypeId;
     use serde::{de::DeserializeSeed, Deserialize, Serialize};
     use std::io::BufReader;
+    use uuid::Uuid;
 
     #[derive(Component, Reflect, Default)]
     #[reflect(Component)]
@@ -594,6 +1454,45 @@ mod tests {
         }
     }
 
+    #[derive(Asset, Reflect, Debug, PartialEq)]
+    struct Material(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct WithHandles {
+        fixed: Handle<Material>,
+        runtime: Handle<Material>,
+    }
+
+    /// [`SceneAssets`] recording the loaded asset paths and the inlined assets.
+    #[derive(Default)]
+    struct RecordedAssets {
+        loaded: Vec<(TypeId, AssetPath<'static>)>,
+        inlined: Vec<Box<dyn PartialReflect>>,
+    }
+
+    impl SceneAssets for RecordedAssets {
+        fn load_erased(&mut self, type_id: TypeId, path: AssetPath<'static>) -> UntypedHandle {
+            self.loaded.push((type_id, path));
+            UntypedHandle::Weak(UntypedAssetId::Uuid {
+                type_id,
+                uuid: Uuid::from_u128(42),
+            })
+        }
+
+        fn add_inlined(
+            &mut self,
+            _reflect_asset: &ReflectAsset,
+            asset: &dyn PartialReflect,
+        ) -> Option<UntypedHandle> {
+            self.inlined.push(asset.clone_value());
+            Some(UntypedHandle::Weak(UntypedAssetId::Uuid {
+                type_id: asset.get_represented_type_info().unwrap().type_id(),
+                uuid: Uuid::from_u128(self.inlined.len() as u128),
+            }))
+        }
+    }
+
     fn create_world() -> World {
         let mut world = World::new();
         let registry = AppTypeRegistry::default();
@@ -612,6 +1511,9 @@ mod tests {
             registry.register::<MyEntityRef>();
             registry.register::<Entity>();
             registry.register::<MyResource>();
+            registry.register::<WithHandles>();
+            registry.register::<Material>();
+            registry.register_type_data::<Material, ReflectAsset>();
         }
         world.insert_resource(registry);
         world
@@ -665,6 +1567,171 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    fn should_deserialize_prefabs() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {},
+    ),
+  },
+  prefabs: {
+    4294967296: (
+      scene: "prefabs/tree.scn.ron",
+      overrides: {
+        4294967296: (
+          components: {
+            "bevy_scene::serde::tests::Foo": (7),
+          },
+        ),
+      },
+    ),
+  },
+)"#;
+
+        // Handles to the scenes of the prefabs can't be created without `SceneAssets`.
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        assert!(SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .is_err());
+
+        let mut assets = RecordedAssets::default();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .with_assets(&mut assets)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(
+            assets.loaded,
+            vec![(
+                TypeId::of::<DynamicScene>(),
+                AssetPath::from("prefabs/tree.scn.ron")
+            )]
+        );
+        assert_eq!(scene.prefabs.len(), 1);
+        let prefab = &scene.prefabs[0];
+        assert_eq!(prefab.entity, Entity::from_raw(0));
+        assert_eq!(prefab.scene, Handle::weak_from_u128(42));
+        assert_eq!(prefab.overrides.len(), 1);
+        assert_eq!(
+            prefab.overrides[0].components[0]
+                .try_downcast_ref::<Foo>()
+                .map(|foo| foo.0),
+            Some(7)
+        );
+
+        // Formats that aren't human-readable keep the layout of scenes without prefabs.
+        let serializer = SceneSerializer::new(&scene, &registry);
+        assert!(postcard::to_allocvec(&serializer).is_err());
+    }
+
+    #[test]
+    fn should_serialize_handles_as_asset_references() {
+        let mut world = create_world();
+        world.init_resource::<Assets<Material>>();
+        let runtime = world.resource_mut::<Assets<Material>>().add(Material(5));
+        let entity = world
+            .spawn(WithHandles {
+                fixed: Handle::weak_from_u128(7),
+                runtime,
+            })
+            .id();
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entity(entity)
+            .build();
+
+        // Assets created at runtime have no asset path, so they must be inlined.
+        assert!(scene.serialize(&registry).is_err());
+
+        let serialized = ron::ser::to_string(
+            &SceneSerializer::new(&scene, &registry).with_inlined_assets(&world),
+        )
+        .unwrap();
+        assert!(serialized.contains(r#"fixed:Uuid("00000000-0000-0000-0000-000000000007")"#));
+        assert!(serialized.contains("runtime:Inline((5))"));
+
+        let mut assets = RecordedAssets::default();
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let deserialized = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .with_assets(&mut assets)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(assets.inlined.len(), 1);
+        assert_eq!(
+            assets.inlined[0].reflect_partial_eq(&Material(5)),
+            Some(true)
+        );
+        let component = deserialized.entities[0].components[0]
+            .try_downcast_ref::<WithHandles>()
+            .unwrap();
+        assert_eq!(component.fixed, Handle::weak_from_u128(7));
+        assert_eq!(component.runtime, Handle::weak_from_u128(1));
+    }
+
+    #[test]
+    fn should_load_handles_by_asset_path() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::WithHandles": (
+          fixed: Uuid("00000000-0000-0000-0000-000000000007"),
+          runtime: Path("materials/wood.mat#Inner"),
+        ),
+      },
+    ),
+  },
+)"#;
+
+        // Assets can't be loaded without `SceneAssets`.
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        assert!(SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .is_err());
+
+        let mut assets = RecordedAssets::default();
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry,
+        }
+        .with_assets(&mut assets)
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        assert_eq!(
+            assets.loaded,
+            vec![(
+                TypeId::of::<Material>(),
+                AssetPath::from("materials/wood.mat#Inner")
+            )]
+        );
+        let component = scene.entities[0].components[0]
+            .try_downcast_ref::<WithHandles>()
+            .unwrap();
+        assert_eq!(component.fixed, Handle::weak_from_u128(7));
+        assert_eq!(component.runtime, Handle::weak_from_u128(42));
+    }
+
     #[test]
     fn should_deserialize() {
         let world = create_world();
@@ -0,0 +1,356 @@
+use bevy_app::App;
+use bevy_asset::{AssetServer, Assets, Handle, RecursiveDependencyLoadState, UntypedAssetId};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    schedule::IntoSystemConfigs,
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::DespawnRecursiveExt;
+use bevy_state::{
+    app::AppExtStates,
+    state::{
+        FreelyMutableState, OnEnter, OnExit, StateTransition, StateTransitionSteps, States,
+        TransitionBlockers,
+    },
+};
+use bevy_utils::{tracing::warn, HashSet};
+use derive_more::derive::From;
+
+use crate::{DynamicScene, DynamicSceneRoot, Scene, SceneRoot};
+
+/// A scene bound to a state with [`AppExtStateScenes`].
+#[derive(Clone, Debug, From)]
+pub enum StateBoundScene {
+    /// A [`Scene`], spawned with a [`SceneRoot`].
+    Scene(Handle<Scene>),
+    /// A [`DynamicScene`], spawned with a [`DynamicSceneRoot`].
+    Dynamic(Handle<DynamicScene>),
+}
+
+impl StateBoundScene {
+    fn id(&self) -> UntypedAssetId {
+        match self {
+            StateBoundScene::Scene(handle) => handle.id().untyped(),
+            StateBoundScene::Dynamic(handle) => handle.id().untyped(),
+        }
+    }
+}
+
+/// Marker for the root entity of a scene spawned when entering the state `S`,
+/// which is despawned when exiting it.
+///
+/// See [`AppExtStateScenes`].
+#[derive(Component, Clone, Debug)]
+pub struct StateSceneRoot<S: States>(pub S);
+
+/// The scenes which must be ready before entering a state of type `S`,
+/// added with [`AppExtStateScenes::add_gated_state_scene`].
+#[derive(Resource)]
+struct GatedStateScenes<S: States> {
+    scenes: Vec<(S, StateBoundScene)>,
+}
+
+/// The states of type `S` which already despawn their [`StateSceneRoot<S>`]s on exit, so
+/// that states with several scenes only register one exit system.
+#[derive(Resource)]
+struct StateSceneExits<S: States>(HashSet<S>);
+
+impl<S: States> Default for StateSceneExits<S> {
+    fn default() -> Self {
+        Self(HashSet::new())
+    }
+}
+
+/// Extension trait for [`App`] binding scenes to [states](States).
+pub trait AppExtStateScenes {
+    /// Spawns `scene` when entering `state`, and despawns it when exiting `state`.
+    ///
+    /// The scene is spawned under a root entity marked with [`StateSceneRoot<S>`] as soon as
+    /// its asset is loaded, which may be a few frames after entering the state: use
+    /// [`add_gated_state_scene`](Self::add_gated_state_scene) to only enter the state once
+    /// the scene is ready instead.
+    ///
+    /// The handle is kept alive for as long as the app runs, so the scene is only loaded once.
+    fn add_state_scene<S: States>(
+        &mut self,
+        state: S,
+        scene: impl Into<StateBoundScene>,
+    ) -> &mut Self;
+
+    /// Like [`add_state_scene`](Self::add_state_scene), but transitions to `state` requested
+    /// through [`NextState<S>`](bevy_state::state::NextState) are held with
+    /// [`TransitionBlockers<S>`] until the scene and its dependencies are loaded, so the scene
+    /// is spawned in the same frame as the state is entered.
+    ///
+    /// This enables transition blockers for `S`. While a transition is held, the current state
+    /// is kept. If the scene fails to load, the transition is released and the scene isn't
+    /// spawned.
+    ///
+    /// Gating doesn't apply to the initial state of `S`.
+    fn add_gated_state_scene<S: FreelyMutableState>(
+        &mut self,
+        state: S,
+        scene: impl Into<StateBoundScene>,
+    ) -> &mut Self;
+}
+
+impl AppExtStateScenes for App {
+    fn add_state_scene<S: States>(
+        &mut self,
+        state: S,
+        scene: impl Into<StateBoundScene>,
+    ) -> &mut Self {
+        let scene = scene.into();
+        let root = StateSceneRoot(state.clone());
+        self.add_systems(
+            OnEnter(state.clone()),
+            move |mut commands: Commands| match &scene {
+                StateBoundScene::Scene(handle) => {
+                    commands.spawn((SceneRoot(handle.clone()), root.clone()));
+                }
+                StateBoundScene::Dynamic(handle) => {
+                    commands.spawn((DynamicSceneRoot(handle.clone()), root.clone()));
+                }
+            },
+        );
+
+        let first_scene = self
+            .world_mut()
+            .get_resource_or_init::<StateSceneExits<S>>()
+            .0
+            .insert(state.clone());
+        if !first_scene {
+            return self;
+        }
+
+        let exited = state.clone();
+        self.add_systems(
+            OnExit(state),
+            move |mut commands: Commands, roots: Query<(Entity, &StateSceneRoot<S>)>| {
+                for (entity, root) in &roots {
+                    if root.0 == exited {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+            },
+        )
+    }
+
+    fn add_gated_state_scene<S: FreelyMutableState>(
+        &mut self,
+        state: S,
+        scene: impl Into<StateBoundScene>,
+    ) -> &mut Self {
+        let scene = scene.into();
+        match self.world_mut().get_resource_mut::<GatedStateScenes<S>>() {
+            Some(mut gated) => gated.scenes.push((state.clone(), scene.clone())),
+            None => {
+                self.insert_resource(GatedStateScenes {
+                    scenes: vec![(state.clone(), scene.clone())],
+                })
+                .enable_transition_blockers::<S>()
+                .add_systems(
+                    StateTransition,
+                    gate_state_scene_transitions::<S>
+                        .before(StateTransitionSteps::DependentTransitions),
+                );
+            }
+        }
+        self.add_state_scene(state, scene)
+    }
+}
+
+/// Name of the transition blocker held while the gated scenes of the pending state load.
+const GATED_SCENE_BLOCKER: &str = "gated_state_scene";
+
+/// Blocks the pending transition while the gated scenes of its state aren't ready yet,
+/// and releases it once they are.
+fn gate_state_scene_transitions<S: FreelyMutableState>(
+    gated: Res<GatedStateScenes<S>>,
+    asset_server: Res<AssetServer>,
+    scenes: Res<Assets<Scene>>,
+    dynamic_scenes: Res<Assets<DynamicScene>>,
+    mut blockers: ResMut<TransitionBlockers<S>>,
+) {
+    let Some(state) = blockers.pending() else {
+        blockers.release(GATED_SCENE_BLOCKER);
+        return;
+    };
+
+    let ready = gated
+        .scenes
+        .iter()
+        .filter(|(gated_state, _)| gated_state == state)
+        .all(|(_, scene)| {
+            match asset_server.get_recursive_dependency_load_state(scene.id()) {
+                Some(RecursiveDependencyLoadState::Loaded) => true,
+                Some(RecursiveDependencyLoadState::Failed(error)) => {
+                    warn!(
+                        "Entering state {state:?} without its scene, which failed to load: {error}"
+                    );
+                    true
+                }
+                Some(_) => false,
+                // Assets which aren't loaded by the asset server are ready once they're added.
+                None => match scene {
+                    StateBoundScene::Scene(handle) => scenes.contains(handle),
+                    StateBoundScene::Dynamic(handle) => dynamic_scenes.contains(handle),
+                },
+            }
+        });
+
+    if ready {
+        blockers.release(GATED_SCENE_BLOCKER);
+    } else {
+        blockers.block(GATED_SCENE_BLOCKER);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, Assets, Handle};
+    use bevy_ecs::{component::Component, prelude::ReflectComponent, query::With, world::World};
+    use bevy_reflect::Reflect;
+    use bevy_state::{
+        app::{AppExtStates, StatesPlugin},
+        state::{NextState, OnExit, State, States, TransitionBlockers},
+    };
+
+    use crate::{DynamicEntity, DynamicScene, ScenePlugin};
+
+    use super::{AppExtStateScenes, StateSceneRoot};
+
+    #[derive(States, Clone, Debug, PartialEq, Eq, Hash, Default)]
+    enum GameState {
+        #[default]
+        Menu,
+        Level,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Tree;
+
+    fn level_scene() -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: bevy_ecs::entity::Entity::from_raw(0),
+                components: vec![Box::new(Tree)],
+            }],
+            prefabs: Vec::new(),
+        }
+    }
+
+    fn trees(world: &mut World) -> usize {
+        world.query_filtered::<(), With<Tree>>().iter(world).count()
+    }
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin, StatesPlugin))
+            .register_type::<Tree>()
+            .init_state::<GameState>();
+        app
+    }
+
+    fn set_state(app: &mut App, state: GameState) {
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(state);
+    }
+
+    #[test]
+    fn spawn_on_enter_and_despawn_on_exit() {
+        let mut app = app();
+        let scene = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(level_scene());
+        app.add_state_scene(GameState::Level, scene);
+
+        app.update();
+        assert_eq!(trees(app.world_mut()), 0);
+
+        set_state(&mut app, GameState::Level);
+        app.update();
+        assert_eq!(trees(app.world_mut()), 1);
+
+        set_state(&mut app, GameState::Menu);
+        app.update();
+        assert_eq!(trees(app.world_mut()), 0);
+        let roots = app
+            .world_mut()
+            .query::<&StateSceneRoot<GameState>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(roots, 0);
+    }
+
+    #[test]
+    fn several_scenes_on_one_state() {
+        let mut app = app();
+        for _ in 0..2 {
+            let scene = app
+                .world_mut()
+                .resource_mut::<Assets<DynamicScene>>()
+                .add(level_scene());
+            app.add_state_scene(GameState::Level, scene);
+        }
+        assert_eq!(
+            app.get_schedule(OnExit(GameState::Level))
+                .unwrap()
+                .systems_len(),
+            1
+        );
+
+        set_state(&mut app, GameState::Level);
+        app.update();
+        assert_eq!(trees(app.world_mut()), 2);
+
+        set_state(&mut app, GameState::Menu);
+        app.update();
+        assert_eq!(trees(app.world_mut()), 0);
+        let roots = app
+            .world_mut()
+            .query::<&StateSceneRoot<GameState>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(roots, 0);
+    }
+
+    #[test]
+    fn gated_transition_waits_for_the_scene() {
+        let mut app = app();
+        let scene = Handle::<DynamicScene>::weak_from_u128(7);
+        app.add_gated_state_scene(GameState::Level, scene.clone());
+
+        set_state(&mut app, GameState::Level);
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Menu
+        );
+        assert!(app
+            .world()
+            .resource::<TransitionBlockers<GameState>>()
+            .is_blocked());
+
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&scene, level_scene());
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::Level
+        );
+        assert_eq!(trees(app.world_mut()), 1);
+        assert!(!app
+            .world()
+            .resource::<TransitionBlockers<GameState>>()
+            .is_blocked());
+    }
+}
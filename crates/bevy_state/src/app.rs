@@ -4,8 +4,10 @@ use bevy_utils::{tracing::warn, warn_once};
 
 use crate::{
     state::{
-        setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
+        apply_state_stack, hold_blocked_transitions, setup_state_transitions_in_world,
+        ApplyStateTransition, ComputedStates, FreelyMutableState, NextState, State, StateStack,
         StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
+        TransitionBlockers,
     },
     state_scoped::clear_state_scoped_entities,
 };
@@ -64,6 +66,20 @@ pub trait AppExtStates {
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
 
+    /// Enable [`TransitionBlockers<S>`] for state `S`, letting systems hold its transitions
+    /// open until they are released.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn enable_transition_blockers<S: FreelyMutableState>(&mut self) -> &mut Self;
+
+    /// Enables a [`StateStack<S>`], letting systems push and pop states of type `S`, and
+    /// enables use of the [`OnPause`](crate::state::OnPause) and [`OnResume`](crate::state::OnResume) schedules.
+    ///
+    /// Pushing or popping a state isn't held by [transition blockers](Self::enable_transition_blockers).
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self;
+
     #[cfg(feature = "bevy_reflect")]
     /// Registers the state type `T` using [`App::register_type`],
     /// and adds [`ReflectState`](crate::reflect::ReflectState) type data to `T` in the type registry.
@@ -227,6 +243,42 @@ impl AppExtStates for SubApp {
         )
     }
 
+    fn enable_transition_blockers<S: FreelyMutableState>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<NextState<S>>() {
+            let name = core::any::type_name::<S>();
+            warn!("Transition blockers are enabled for state `{}`, but the state isn't installed in the app!", name);
+        }
+        if !self.world().contains_resource::<TransitionBlockers<S>>() {
+            self.init_resource::<TransitionBlockers<S>>().add_systems(
+                StateTransition,
+                hold_blocked_transitions::<S>
+                    .before(ApplyStateTransition::<S>::default())
+                    .in_set(StateTransitionSteps::DependentTransitions),
+            );
+        }
+        self
+    }
+
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<NextState<S>>() {
+            let name = core::any::type_name::<S>();
+            warn!(
+                "State stack is enabled for state `{}`, but the state isn't installed in the app!",
+                name
+            );
+        }
+        if !self.world().contains_resource::<StateStack<S>>() {
+            self.init_resource::<StateStack<S>>().add_systems(
+                StateTransition,
+                apply_state_stack::<S>
+                    .after(hold_blocked_transitions::<S>)
+                    .before(ApplyStateTransition::<S>::default())
+                    .in_set(StateTransitionSteps::DependentTransitions),
+            );
+        }
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -278,6 +330,16 @@ impl AppExtStates for App {
         self
     }
 
+    fn enable_transition_blockers<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.main_mut().enable_transition_blockers::<S>();
+        self
+    }
+
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.main_mut().enable_state_stack::<S>();
+        self
+    }
+
     #[cfg(feature = "bevy_reflect")]
     fn register_type_state<S>(&mut self) -> &mut Self
     where
@@ -315,10 +377,17 @@ mod tests {
     use crate::{
         self as bevy_state,
         app::StatesPlugin,
-        state::{State, StateTransition, StateTransitionEvent},
+        state::{
+            NextState, OnEnter, OnExit, OnPause, OnResume, State, StateSet, StateStack,
+            StateTransition, StateTransitionEvent, SubStates, TransitionBlockers,
+        },
+        state_scoped::StateScoped,
     };
     use bevy_app::App;
-    use bevy_ecs::event::Events;
+    use bevy_ecs::{
+        event::Events,
+        system::{ResMut, Resource},
+    };
     use bevy_state_macros::States;
 
     use super::AppExtStates;
@@ -370,4 +439,213 @@ mod tests {
         assert_eq!(last.exited, None);
         assert_eq!(last.entered, Some(TestState::C));
     }
+
+    #[test]
+    fn transition_blockers_hold_transitions() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>()
+            .enable_transition_blockers::<TestState>();
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+
+        // Requested transitions are pending for at least one run.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert_eq!(
+            world.resource::<TransitionBlockers<TestState>>().pending(),
+            Some(&TestState::B)
+        );
+
+        world
+            .resource_mut::<TransitionBlockers<TestState>>()
+            .block("loading");
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+
+        // Requesting another transition replaces the pending one.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+
+        assert!(world
+            .resource_mut::<TransitionBlockers<TestState>>()
+            .release("loading"));
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+        assert_eq!(
+            world.resource::<TransitionBlockers<TestState>>().pending(),
+            None
+        );
+    }
+
+    #[derive(Resource, Default, Debug, PartialEq, Eq)]
+    struct Runs {
+        enter: usize,
+        exit: usize,
+        pause: usize,
+        resume: usize,
+    }
+
+    #[test]
+    fn state_stack_pauses_and_resumes() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>()
+            .enable_state_stack::<TestState>()
+            .enable_state_scoped_entities::<TestState>()
+            .init_resource::<Runs>()
+            .add_systems(OnEnter(TestState::A), |mut runs: ResMut<Runs>| {
+                runs.enter += 1;
+            })
+            .add_systems(OnExit(TestState::A), |mut runs: ResMut<Runs>| {
+                runs.exit += 1;
+            })
+            .add_systems(OnPause(TestState::A), |mut runs: ResMut<Runs>| {
+                runs.pause += 1;
+            })
+            .add_systems(OnResume(TestState::A), |mut runs: ResMut<Runs>| {
+                runs.resume += 1;
+            });
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        let scoped = world.spawn(StateScoped(TestState::A)).id();
+
+        world
+            .resource_mut::<StateStack<TestState>>()
+            .push(TestState::B);
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<StateStack<TestState>>()
+            .push(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::C);
+        assert_eq!(
+            world.resource::<StateStack<TestState>>().covered(),
+            &[TestState::A, TestState::B]
+        );
+        assert!(world.get_entity(scoped).is_ok());
+
+        world.resource_mut::<StateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        world.resource_mut::<StateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert!(world
+            .resource::<StateStack<TestState>>()
+            .covered()
+            .is_empty());
+        assert_eq!(
+            *world.resource::<Runs>(),
+            Runs {
+                enter: 1,
+                exit: 0,
+                pause: 1,
+                resume: 1,
+            }
+        );
+        assert!(world.get_entity(scoped).is_ok());
+
+        // Leaving the state through `NextState` exits it.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<Runs>().exit, 1);
+        assert!(world.get_entity(scoped).is_err());
+    }
+
+    #[test]
+    fn other_transitions_clear_state_stack() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>()
+            .enable_state_stack::<TestState>()
+            .enable_state_scoped_entities::<TestState>()
+            .init_resource::<Runs>()
+            .add_systems(OnExit(TestState::A), |mut runs: ResMut<Runs>| {
+                runs.exit += 1;
+            })
+            .add_systems(OnExit(TestState::B), |mut runs: ResMut<Runs>| {
+                runs.exit += 10;
+            })
+            .add_systems(OnExit(TestState::C), |mut runs: ResMut<Runs>| {
+                runs.exit += 100;
+            });
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        let scoped_a = world.spawn(StateScoped(TestState::A)).id();
+        world
+            .resource_mut::<StateStack<TestState>>()
+            .push(TestState::B);
+        world.run_schedule(StateTransition);
+        let scoped_b = world.spawn(StateScoped(TestState::B)).id();
+        world
+            .resource_mut::<StateStack<TestState>>()
+            .push(TestState::C);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<Runs>().exit, 0);
+
+        // Every state of the stack is exited, including the covered ones.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::A);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestState>>().0, TestState::A);
+        assert!(world
+            .resource::<StateStack<TestState>>()
+            .covered()
+            .is_empty());
+        assert_eq!(world.resource::<Runs>().exit, 111);
+        assert!(world.get_entity(scoped_a).is_err());
+        assert!(world.get_entity(scoped_b).is_err());
+
+        // The cleared states are only exited once.
+        world
+            .resource_mut::<NextState<TestState>>()
+            .set(TestState::B);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<Runs>().exit, 112);
+    }
+
+    #[derive(SubStates, Default, PartialEq, Eq, Hash, Debug, Clone)]
+    #[source(TestState = TestState::A)]
+    enum TestSubState {
+        #[default]
+        X,
+        Y,
+    }
+
+    #[test]
+    fn state_stack_removes_sub_states_of_covered_states() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin);
+        app.init_state::<TestState>()
+            .add_sub_state::<TestSubState>()
+            .enable_state_stack::<TestState>();
+        let world = app.world_mut();
+        world.run_schedule(StateTransition);
+        world
+            .resource_mut::<NextState<TestSubState>>()
+            .set(TestSubState::Y);
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestSubState>>().0, TestSubState::Y);
+
+        // Sub states follow the current state, so they don't exist while their source is covered.
+        world
+            .resource_mut::<StateStack<TestState>>()
+            .push(TestState::B);
+        world.run_schedule(StateTransition);
+        assert!(!world.contains_resource::<State<TestSubState>>());
+
+        // And they are re-entered with their default value once it is uncovered.
+        world.resource_mut::<StateStack<TestState>>().pop();
+        world.run_schedule(StateTransition);
+        assert_eq!(world.resource::<State<TestSubState>>().0, TestSubState::X);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::state::{State, States};
+use crate::state::{FreelyMutableState, State, States, TransitionBlockers};
 use bevy_ecs::{change_detection::DetectChanges, system::Res};
 
 /// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
@@ -160,6 +160,42 @@ pub fn state_changed<S: States>(current_state: Option<Res<State<S>>>) -> bool {
     current_state.is_changed()
 }
 
+/// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
+/// if a transition of the state machine is pending in its [`TransitionBlockers`].
+///
+/// Will return `false` if transition blockers aren't enabled for the state.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_state::prelude::*;
+/// # let mut app = Schedule::default();
+/// # let mut world = World::new();
+/// #[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     Playing,
+///     Paused,
+/// }
+///
+/// app.add_systems(
+///     // `state_transition_pending` will only return true while a
+///     // transition of the given state is held
+///     show_loading_screen.run_if(state_transition_pending::<GameState>),
+/// );
+///
+/// fn show_loading_screen() {}
+///
+/// world.init_resource::<TransitionBlockers<GameState>>();
+/// app.run(&mut world);
+/// ```
+pub fn state_transition_pending<S: FreelyMutableState>(
+    blockers: Option<Res<TransitionBlockers<S>>>,
+) -> bool {
+    blockers.is_some_and(|blockers| blockers.pending().is_some())
+}
+
 #[cfg(test)]
 mod tests {
     use crate as bevy_state;
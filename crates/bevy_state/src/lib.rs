@@ -23,6 +23,8 @@
 //!
 //! - 3 Transition Schedules - [`OnEnter<S>`](crate::state::OnEnter), [`OnExit<S>`](crate::state::OnExit) and [`OnTransition<S>`](crate::state::OnTransition) - which are used
 //!   to trigger systems specifically during matching transitions.
+//! - A [`StateStack<S>`](crate::state::StateStack) to push states over the current one and pop them, running the
+//!   [`OnPause<S>`](crate::state::OnPause) and [`OnResume<S>`](crate::state::OnResume) schedules of the covered state.
 //! - A [`StateTransitionEvent<S>`](crate::state::StateTransitionEvent) that gets fired when a given state changes.
 //! - The [`in_state<S>`](crate::condition::in_state) and [`state_changed<S>`](crate::condition::state_changed) run conditions - which are used
 //!   to determine whether a system should run based on the current state.
@@ -31,6 +33,8 @@
 #![allow(internal_features)]
 #![cfg_attr(any(docsrs, docsrs_dep), feature(rustdoc_internals))]
 
+extern crate alloc;
+
 #[cfg(feature = "bevy_app")]
 /// Provides [`App`](bevy_app::App) and [`SubApp`](bevy_app::SubApp) with state installation methods
 pub mod app;
@@ -71,8 +75,8 @@ pub mod prelude {
         condition::*,
         state::{
             last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState, OnEnter,
-            OnExit, OnTransition, State, StateSet, StateTransition, StateTransitionEvent, States,
-            SubStates, TransitionSchedules,
+            OnExit, OnPause, OnResume, OnTransition, State, StateSet, StateStack, StateTransition,
+            StateTransitionEvent, States, SubStates, TransitionBlockers, TransitionSchedules,
         },
         state_scoped::StateScoped,
     };
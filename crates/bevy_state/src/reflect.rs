@@ -2,6 +2,7 @@ use crate::state::{FreelyMutableState, NextState, State, States};
 
 use bevy_ecs::{reflect::from_reflect_with_fallback, world::World};
 use bevy_reflect::{FromType, Reflect, TypePath, TypeRegistry};
+use core::any::TypeId;
 
 /// A struct used to operate on the reflected [`States`] trait of a type.
 ///
@@ -15,6 +16,8 @@ pub struct ReflectState(ReflectStateFns);
 pub struct ReflectStateFns {
     /// Function pointer implementing [`ReflectState::reflect()`].
     pub reflect: fn(&World) -> Option<&dyn Reflect>,
+    /// The [`TypeId`] of the [`State<S>`] resource holding the state.
+    pub state_resource_type_id: TypeId,
 }
 
 impl ReflectStateFns {
@@ -33,6 +36,11 @@ impl ReflectState {
     pub fn reflect<'a>(&self, world: &'a World) -> Option<&'a dyn Reflect> {
         (self.0.reflect)(world)
     }
+
+    /// The [`TypeId`] of the [`State<S>`] resource holding this [`States`] type.
+    pub fn state_resource_type_id(&self) -> TypeId {
+        self.0.state_resource_type_id
+    }
 }
 
 impl<S: States + Reflect> FromType<S> for ReflectState {
@@ -43,6 +51,7 @@ impl<S: States + Reflect> FromType<S> for ReflectState {
                     .get_resource::<State<S>>()
                     .map(|res| res.get() as &dyn Reflect)
             },
+            state_resource_type_id: TypeId::of::<State<S>>(),
         })
     }
 }
@@ -0,0 +1,169 @@
+use core::mem;
+
+use bevy_ecs::system::{Res, ResMut, Resource};
+use bevy_utils::tracing::warn;
+
+use super::{FreelyMutableState, NextState, State, States};
+
+/// A stack of states of type `S`, letting systems [`push`](Self::push) a state over the current one
+/// and [`pop`](Self::pop) it to get back to the covered state, for example to go from gameplay
+/// to a pause menu and then to a settings menu.
+///
+/// The top of the stack is the current [`State<S>`], while the states below it are
+/// [covered](Self::covered). Pushing a state runs the [`OnPause`](crate::state::OnPause) schedule
+/// of the covered state instead of its [`OnExit`](crate::state::OnExit) schedule, and popping a
+/// state runs the [`OnResume`](crate::state::OnResume) schedule of the uncovered state instead of
+/// its [`OnEnter`](crate::state::OnEnter) schedule. [`StateScoped`](crate::state_scoped::StateScoped)
+/// entities of a covered state are kept until the state is exited.
+///
+/// Any other transition of `S`, requested through [`NextState<S>`], clears the stack: along with
+/// the current state, every covered state is exited, running their [`OnExit`](crate::state::OnExit)
+/// schedules from the top of the stack to the bottom and despawning their
+/// [`StateScoped`](crate::state_scoped::StateScoped) entities.
+///
+/// The other schedules and [computed](crate::state::ComputedStates) or [sub states](crate::state::SubStates)
+/// follow the current state, like for any other transition. In particular, the sub states of a
+/// covered state aren't kept: they are removed when a state is pushed over their source state,
+/// and re-entered with their default value once it is popped. Keep their value in a resource
+/// if it must survive a pause.
+///
+/// Enable the stack with [`enable_state_stack`](crate::app::AppExtStates::enable_state_stack).
+/// Push and pop operations are applied by the [`StateTransition`](crate::state::StateTransition)
+/// schedule, and take precedence over a transition requested through [`NextState<S>`] in the same frame.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum Screen {
+///     #[default]
+///     Gameplay,
+///     Pause,
+///     Settings,
+/// }
+///
+/// fn pause(mut stack: ResMut<StateStack<Screen>>) {
+///     stack.push(Screen::Pause);
+/// }
+///
+/// fn close_menu(mut stack: ResMut<StateStack<Screen>>) {
+///     stack.pop();
+/// }
+/// ```
+#[derive(Resource, Debug)]
+pub struct StateStack<S: States> {
+    covered: Vec<S>,
+    pending: Option<StackOperation<S>>,
+    applied: Option<StackOperation<()>>,
+    cleared: Vec<S>,
+}
+
+/// An operation on a [`StateStack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StackOperation<S> {
+    Push(S),
+    Pop,
+}
+
+impl<S: States> Default for StateStack<S> {
+    fn default() -> Self {
+        Self {
+            covered: Vec::new(),
+            pending: None,
+            applied: None,
+            cleared: Vec::new(),
+        }
+    }
+}
+
+impl<S: States> StateStack<S> {
+    /// Push `state` over the current state, which gets paused until `state` is popped.
+    ///
+    /// This replaces any push or pop requested since the last state transition.
+    pub fn push(&mut self, state: S) {
+        self.pending = Some(StackOperation::Push(state));
+    }
+
+    /// Pop the current state, resuming the state it covers.
+    ///
+    /// This replaces any push or pop requested since the last state transition,
+    /// and has no effect if no state is covered.
+    pub fn pop(&mut self) {
+        self.pending = Some(StackOperation::Pop);
+    }
+
+    /// The states covered by the current state, from the bottom of the stack to the top.
+    pub fn covered(&self) -> &[S] {
+        &self.covered
+    }
+
+    /// Returns `true` if `state` is covered by the current state.
+    pub fn is_covered(&self, state: &S) -> bool {
+        self.covered.contains(state)
+    }
+
+    /// Returns `true` if the state transition being applied pauses the exited state.
+    pub(crate) fn is_pausing(&self) -> bool {
+        matches!(self.applied, Some(StackOperation::Push(())))
+    }
+
+    /// Returns `true` if the state transition being applied resumes the entered state.
+    pub(crate) fn is_resuming(&self) -> bool {
+        matches!(self.applied, Some(StackOperation::Pop))
+    }
+
+    /// The covered states exited by the state transition being applied, from the top of the
+    /// stack to the bottom.
+    pub(crate) fn cleared(&self) -> &[S] {
+        &self.cleared
+    }
+}
+
+/// Turns the push or pop requested on the [`StateStack<S>`] into a transition of [`NextState<S>`],
+/// or clears the stack if another transition was requested.
+#[cfg_attr(
+    not(feature = "bevy_app"),
+    expect(dead_code, reason = "only used by `AppExtStates`")
+)]
+pub(crate) fn apply_state_stack<S: FreelyMutableState>(
+    mut stack: ResMut<StateStack<S>>,
+    current_state: Option<Res<State<S>>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    stack.applied = None;
+    stack.cleared.clear();
+    let Some(operation) = stack.pending.take() else {
+        if let NextState::Pending(_) = *next_state {
+            let mut covered = mem::take(&mut stack.covered);
+            covered.reverse();
+            stack.cleared = covered;
+        }
+        return;
+    };
+    let Some(current_state) = current_state else {
+        warn!(
+            "Tried to change the state stack of `{}`, but the state doesn't exist",
+            core::any::type_name::<S>()
+        );
+        return;
+    };
+    match operation {
+        StackOperation::Push(state) => {
+            stack.covered.push(current_state.get().clone());
+            stack.applied = Some(StackOperation::Push(()));
+            next_state.set(state);
+        }
+        StackOperation::Pop => {
+            let Some(state) = stack.covered.pop() else {
+                warn!(
+                    "Tried to pop the state stack of `{}`, but no state is covered",
+                    core::any::type_name::<S>()
+                );
+                return;
+            };
+            stack.applied = Some(StackOperation::Pop);
+            next_state.set(state);
+        }
+    }
+}
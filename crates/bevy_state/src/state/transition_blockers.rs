@@ -0,0 +1,123 @@
+use alloc::borrow::Cow;
+use core::mem;
+
+use bevy_ecs::system::{ResMut, Resource};
+use bevy_utils::HashSet;
+
+use super::{FreelyMutableState, NextState};
+
+/// Blockers holding transitions of the state `S` open, for example while assets load,
+/// a fade-out animation plays or a network handshake completes.
+///
+/// Once enabled with [`enable_transition_blockers`](crate::app::AppExtStates::enable_transition_blockers),
+/// transitions requested through [`NextState<S>`] aren't applied right away: they become
+/// [pending](Self::pending) for at least one frame, during which systems can [`block`](Self::block)
+/// them. The state keeps its current value until all blockers are [released](Self::release),
+/// after which the transition is applied by the next run of the
+/// [`StateTransition`](crate::state::StateTransition) schedule.
+///
+/// Requesting another transition while one is pending replaces the pending transition,
+/// without releasing the blockers.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     MainMenu,
+///     InGame,
+/// }
+///
+/// #[derive(Resource)]
+/// struct FadeOut(f32);
+///
+/// fn start_fade_out(mut commands: Commands, mut blockers: ResMut<TransitionBlockers<GameState>>) {
+///     if blockers.block("fade_out") {
+///         commands.insert_resource(FadeOut(1.0));
+///     }
+/// }
+///
+/// fn fade_out(mut fade: ResMut<FadeOut>, mut blockers: ResMut<TransitionBlockers<GameState>>) {
+///     fade.0 -= 0.1;
+///     if fade.0 <= 0.0 {
+///         blockers.release("fade_out");
+///     }
+/// }
+///
+/// # let mut schedule = Schedule::default();
+/// schedule.add_systems((
+///     start_fade_out.run_if(state_transition_pending::<GameState>),
+///     fade_out.run_if(resource_exists::<FadeOut>),
+/// ));
+/// ```
+#[derive(Resource, Debug)]
+pub struct TransitionBlockers<S: FreelyMutableState> {
+    pending: Option<S>,
+    blockers: HashSet<Cow<'static, str>>,
+}
+
+impl<S: FreelyMutableState> Default for TransitionBlockers<S> {
+    fn default() -> Self {
+        Self {
+            pending: None,
+            blockers: HashSet::default(),
+        }
+    }
+}
+
+impl<S: FreelyMutableState> TransitionBlockers<S> {
+    /// The state of the pending transition, if any.
+    pub fn pending(&self) -> Option<&S> {
+        self.pending.as_ref()
+    }
+
+    /// Hold the pending transition, or the next one if there is none, until `blocker` is released.
+    ///
+    /// Returns `false` if `blocker` was already blocking transitions.
+    pub fn block(&mut self, blocker: impl Into<Cow<'static, str>>) -> bool {
+        self.blockers.insert(blocker.into())
+    }
+
+    /// Release `blocker`, letting the pending transition through once no blockers remain.
+    ///
+    /// Returns `false` if `blocker` wasn't blocking transitions.
+    pub fn release(&mut self, blocker: &str) -> bool {
+        self.blockers.remove(blocker)
+    }
+
+    /// Returns `true` if any blocker is holding transitions.
+    pub fn is_blocked(&self) -> bool {
+        !self.blockers.is_empty()
+    }
+
+    /// Iterate over the blockers holding transitions.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.blockers.iter().map(|blocker| &**blocker)
+    }
+}
+
+/// Moves transitions requested through [`NextState<S>`] to [`TransitionBlockers<S>`],
+/// and requests them again once they are no longer blocked.
+#[cfg_attr(
+    not(feature = "bevy_app"),
+    expect(dead_code, reason = "only used by `AppExtStates`")
+)]
+pub(crate) fn hold_blocked_transitions<S: FreelyMutableState>(
+    mut blockers: ResMut<TransitionBlockers<S>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    if let NextState::Pending(_) = *next_state {
+        // New transitions stay pending for at least one frame, so that systems can block them.
+        if let NextState::Pending(state) = mem::take(&mut *next_state) {
+            blockers.pending = Some(state);
+        }
+        return;
+    }
+    if !blockers.is_blocked() {
+        if let Some(state) = blockers.pending.take() {
+            next_state.set(state);
+        }
+    }
+}
@@ -7,7 +7,7 @@ use bevy_ecs::{
     world::World,
 };
 
-use super::{resources::State, states::States};
+use super::{resources::State, state_stack::StateStack, states::States};
 
 /// The label of a [`Schedule`] that **only** runs whenever [`State<S>`] enters the provided state.
 ///
@@ -21,6 +21,20 @@ pub struct OnEnter<S: States>(pub S);
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OnExit<S: States>(pub S);
 
+/// The label of a [`Schedule`] that runs instead of [`OnExit`] whenever the provided state
+/// gets covered by a state pushed on the [`StateStack<S>`](super::StateStack).
+///
+/// This schedule ignores identity transitions.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnPause<S: States>(pub S);
+
+/// The label of a [`Schedule`] that runs instead of [`OnEnter`] whenever the provided state
+/// gets uncovered by popping the [`StateStack<S>`](super::StateStack).
+///
+/// This schedule ignores identity transitions.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnResume<S: States>(pub S);
+
 /// The label of a [`Schedule`] that **only** runs whenever [`State<S>`]
 /// exits AND enters the provided `exited` and `entered` states.
 ///
@@ -219,7 +233,14 @@ pub(crate) fn run_enter<S: States>(
         return;
     };
 
-    let _ = world.try_run_schedule(OnEnter(entered));
+    let resuming = world
+        .get_resource::<StateStack<S>>()
+        .is_some_and(StateStack::is_resuming);
+    if resuming {
+        let _ = world.try_run_schedule(OnResume(entered));
+    } else {
+        let _ = world.try_run_schedule(OnEnter(entered));
+    }
 }
 
 pub(crate) fn run_exit<S: States>(
@@ -229,14 +250,27 @@ pub(crate) fn run_exit<S: States>(
     let Some(transition) = transition.0 else {
         return;
     };
-    if transition.entered == transition.exited {
-        return;
+    if transition.entered != transition.exited {
+        if let Some(exited) = transition.exited {
+            let pausing = world
+                .get_resource::<StateStack<S>>()
+                .is_some_and(StateStack::is_pausing);
+            if pausing {
+                let _ = world.try_run_schedule(OnPause(exited));
+            } else {
+                let _ = world.try_run_schedule(OnExit(exited));
+            }
+        }
     }
-    let Some(exited) = transition.exited else {
-        return;
-    };
 
-    let _ = world.try_run_schedule(OnExit(exited));
+    // Covered states are exited after the current state when the stack is cleared.
+    let cleared = world
+        .get_resource::<StateStack<S>>()
+        .map(|stack| stack.cleared().to_vec())
+        .unwrap_or_default();
+    for exited in cleared {
+        let _ = world.try_run_schedule(OnExit(exited));
+    }
 }
 
 pub(crate) fn run_transition<S: States>(
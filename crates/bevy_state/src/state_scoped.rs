@@ -4,14 +4,14 @@ use bevy_ecs::{
     component::Component,
     entity::Entity,
     event::EventReader,
-    system::{Commands, Query},
+    system::{Commands, Query, Res},
 };
 #[cfg(feature = "bevy_hierarchy")]
 use bevy_hierarchy::DespawnRecursiveExt;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 
-use crate::state::{StateTransitionEvent, States};
+use crate::state::{StateStack, StateTransitionEvent, States};
 
 /// Entities marked with this component will be removed
 /// when the world's state of the matching type no longer matches the supplied value.
@@ -63,10 +63,14 @@ pub struct StateScoped<S: States>(pub S);
 /// Removes entities marked with [`StateScoped<S>`]
 /// when their state no longer matches the world state.
 ///
+/// Entities of a state covered by pushing a state on the [`StateStack<S>`] are kept, until
+/// the stack is cleared by another transition.
+///
 /// If `bevy_hierarchy` feature is enabled, which it is by default, the despawn will be recursive.
 pub fn clear_state_scoped_entities<S: States>(
     mut commands: Commands,
     mut transitions: EventReader<StateTransitionEvent<S>>,
+    stack: Option<Res<StateStack<S>>>,
     query: Query<(Entity, &StateScoped<S>)>,
 ) {
     // We use the latest event, because state machine internals generate at most 1
@@ -75,14 +79,18 @@ pub fn clear_state_scoped_entities<S: States>(
     let Some(transition) = transitions.read().last() else {
         return;
     };
-    if transition.entered == transition.exited {
-        return;
+    let stack = stack.as_deref();
+    let mut exited: Vec<&S> = stack
+        .map(|stack| stack.cleared().iter().collect())
+        .unwrap_or_default();
+    if transition.entered != transition.exited && !stack.is_some_and(StateStack::is_pausing) {
+        exited.extend(&transition.exited);
     }
-    let Some(exited) = &transition.exited else {
+    if exited.is_empty() {
         return;
-    };
+    }
     for (entity, binding) in &query {
-        if binding.0 == *exited {
+        if exited.contains(&&binding.0) {
             #[cfg(feature = "bevy_hierarchy")]
             commands.entity(entity).despawn_recursive();
             #[cfg(not(feature = "bevy_hierarchy"))]
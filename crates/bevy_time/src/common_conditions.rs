@@ -1,4 +1,4 @@
-use crate::{Real, Time, Timer, TimerMode, Virtual};
+use crate::{Domain, Real, Time, TimeDomain, Timer, TimerMode, Virtual};
 use bevy_ecs::system::Res;
 use bevy_utils::Duration;
 
@@ -81,6 +81,48 @@ pub fn on_real_timer(duration: Duration) -> impl FnMut(Res<Time<Real>>) -> bool
     }
 }
 
+/// Run condition that is active on a regular time interval,
+/// using [`Time<Domain<D>>`] to advance the timer.
+/// The timer ticks at the rate of the time domain `D`, and stops while it is paused.
+///
+/// ```no_run
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup, Update};
+/// # use bevy_ecs::schedule::IntoSystemConfigs;
+/// # use bevy_utils::Duration;
+/// # use bevy_time::{common_conditions::on_domain_timer, TimeDomain, TimeDomainPlugin};
+/// struct Ui;
+///
+/// impl TimeDomain for Ui {}
+///
+/// fn main() {
+///     App::new()
+///         .add_plugins((DefaultPlugins, TimeDomainPlugin::<Ui>::default()))
+///         .add_systems(
+///             Update,
+///             blink_cursor.run_if(on_domain_timer::<Ui>(Duration::from_millis(500))),
+///         )
+///     .run();
+/// }
+/// fn blink_cursor() {
+///     // ran twice a second of UI time
+/// }
+/// ```
+///
+/// Note that this does **not** guarantee that systems will run at exactly the
+/// specified interval. If delta time is larger than the specified `duration` then
+/// the system will only run once even though the timer may have completed multiple
+/// times. This condition should only be used with large time durations (relative to
+/// delta time).
+pub fn on_domain_timer<D: TimeDomain>(
+    duration: Duration,
+) -> impl FnMut(Res<Time<Domain<D>>>) -> bool + Clone {
+    let mut timer = Timer::new(duration, TimerMode::Repeating);
+    move |time: Res<Time<Domain<D>>>| {
+        timer.tick(time.delta());
+        timer.just_finished()
+    }
+}
+
 /// Run condition that is active *once* after the specified delay,
 /// using [`Time`] to advance the timer.
 /// The timer ticks at the rate of [`Time::relative_speed`].
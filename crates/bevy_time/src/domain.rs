@@ -0,0 +1,478 @@
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::{
+    component::Component,
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut, Resource},
+};
+use bevy_utils::{Duration, HashMap};
+use core::{any::TypeId, fmt, marker::PhantomData};
+
+use crate::{real::Real, time::Time, time_system, virt::Virtual, TimeSystem};
+
+/// A marker type naming a time domain, a clock with its own pause state and speed,
+/// used as `Time<Domain<D>>`.
+///
+/// Time domains let groups of systems and entities run at their own pace: for example the UI can
+/// keep running at normal speed while the world is in slow motion, or the bullets of a faction
+/// can enter bullet-time while everything else keeps going.
+///
+/// ```
+/// # use bevy_app::{App, Update};
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # use bevy_time::{TimeDomain, TimeDomainPlugin};
+/// struct BulletTime;
+///
+/// impl TimeDomain for BulletTime {}
+///
+/// fn slow_down(mut time: ResMut<Time<Domain<BulletTime>>>) {
+///     time.set_relative_speed(0.2);
+/// }
+///
+/// fn move_bullets(time: Res<Time<Domain<BulletTime>>>) {
+///     let delta = time.delta_secs();
+///     // ...
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins(TimeDomainPlugin::<BulletTime>::default())
+///     .add_systems(Update, (slow_down, move_bullets));
+/// ```
+pub trait TimeDomain: Send + Sync + 'static {}
+
+/// Where a time domain takes the time it advances by on each update.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DomainSource {
+    /// The domain advances with [`Time<Virtual>`], so it is paused and scaled along with it.
+    #[default]
+    Virtual,
+    /// The domain advances with [`Time<Real>`], ignoring the pause state and speed of
+    /// [`Time<Virtual>`], for example for UI that keeps running while the game is paused.
+    Real,
+}
+
+/// The clock of the time domain `D`.
+///
+/// A specialization of the [`Time`] structure. **For method documentation, see
+/// [`Time<Domain<D>>#impl-Time<Domain<D>>`].**
+///
+/// Normally used as `Time<Domain<D>>`. It is inserted as a resource by [`TimeDomainPlugin<D>`]
+/// and updated on each update from its [source](DomainSource), [`Time<Virtual>`] by default.
+///
+/// Like the virtual clock, a domain clock can be [paused](Time::pause) and its
+/// [speed](Time::set_relative_speed) can be changed, relative to its source. When the
+/// source is the virtual clock, pausing the virtual clock pauses all the domains as well.
+pub struct Domain<D: TimeDomain> {
+    source: DomainSource,
+    paused: bool,
+    relative_speed: f64,
+    effective_speed: f64,
+    marker: PhantomData<fn() -> D>,
+}
+
+impl<D: TimeDomain> Default for Domain<D> {
+    fn default() -> Self {
+        Self {
+            source: DomainSource::default(),
+            paused: false,
+            relative_speed: 1.0,
+            effective_speed: 1.0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<D: TimeDomain> Clone for Domain<D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<D: TimeDomain> Copy for Domain<D> {}
+
+impl<D: TimeDomain> fmt::Debug for Domain<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Domain")
+            .field("domain", &core::any::type_name::<D>())
+            .field("source", &self.source)
+            .field("paused", &self.paused)
+            .field("relative_speed", &self.relative_speed)
+            .field("effective_speed", &self.effective_speed)
+            .finish()
+    }
+}
+
+impl<D: TimeDomain> Time<Domain<D>> {
+    /// Returns the clock this domain advances with.
+    #[inline]
+    pub fn source(&self) -> DomainSource {
+        self.context().source
+    }
+
+    /// Sets the clock this domain advances with.
+    #[inline]
+    pub fn set_source(&mut self, source: DomainSource) {
+        self.context_mut().source = source;
+    }
+
+    /// Returns the speed the clock advances relative to its source, as [`f32`].
+    #[inline]
+    pub fn relative_speed(&self) -> f32 {
+        self.relative_speed_f64() as f32
+    }
+
+    /// Returns the speed the clock advances relative to its source, as [`f64`].
+    #[inline]
+    pub fn relative_speed_f64(&self) -> f64 {
+        self.context().relative_speed
+    }
+
+    /// Returns the speed the clock advanced relative to its source in this update, as [`f32`].
+    ///
+    /// Returns `0.0` if the domain was paused or what the `relative_speed` value
+    /// was at the start of this update.
+    #[inline]
+    pub fn effective_speed(&self) -> f32 {
+        self.context().effective_speed as f32
+    }
+
+    /// Returns the speed the clock advanced relative to its source in this update, as [`f64`].
+    ///
+    /// Returns `0.0` if the domain was paused or what the `relative_speed` value
+    /// was at the start of this update.
+    #[inline]
+    pub fn effective_speed_f64(&self) -> f64 {
+        self.context().effective_speed
+    }
+
+    /// Sets the speed the clock advances relative to its source, given as an [`f32`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed(&mut self, ratio: f32) {
+        self.set_relative_speed_f64(ratio as f64);
+    }
+
+    /// Sets the speed the clock advances relative to its source, given as an [`f64`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is negative or not finite.
+    #[inline]
+    pub fn set_relative_speed_f64(&mut self, ratio: f64) {
+        assert!(ratio.is_finite(), "tried to go infinitely fast");
+        assert!(ratio >= 0.0, "tried to go back in time");
+        self.context_mut().relative_speed = ratio;
+    }
+
+    /// Stops the clock, preventing it from advancing until resumed.
+    #[inline]
+    pub fn pause(&mut self) {
+        self.context_mut().paused = true;
+    }
+
+    /// Resumes the clock if paused.
+    #[inline]
+    pub fn unpause(&mut self) {
+        self.context_mut().paused = false;
+    }
+
+    /// Returns `true` if the clock is currently paused.
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.context().paused
+    }
+
+    /// Returns `true` if the clock was paused at the start of this update.
+    #[inline]
+    pub fn was_paused(&self) -> bool {
+        self.context().effective_speed == 0.0
+    }
+
+    /// Updates the elapsed duration of `self` by `source_delta`, scaled by the relative speed.
+    fn advance_with_source_delta(&mut self, source_delta: Duration) {
+        let effective_speed = if self.context().paused {
+            0.0
+        } else {
+            self.context().relative_speed
+        };
+        let delta = if effective_speed != 1.0 {
+            source_delta.mul_f64(effective_speed)
+        } else {
+            // avoid rounding when at normal speed
+            source_delta
+        };
+        self.context_mut().effective_speed = effective_speed;
+        self.advance_by(delta);
+    }
+}
+
+/// Binds an entity to a time domain, so that the engine systems advancing it, such as
+/// animation players, use the time of this domain instead of the default [`Time`].
+///
+/// The delta time of the bound domain can be read from [`TimeDomainDeltas`], for example to
+/// tick a [`Timer`](crate::Timer) stored in a component of the entity:
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::prelude::*;
+/// # use bevy_time::{BoundTimeDomain, TimeDomainDeltas};
+/// #[derive(Component)]
+/// struct Cooldown(Timer);
+///
+/// fn tick_cooldowns(
+///     time: Res<Time>,
+///     deltas: Res<TimeDomainDeltas>,
+///     mut cooldowns: Query<(&mut Cooldown, Option<&BoundTimeDomain>)>,
+/// ) {
+///     for (mut cooldown, domain) in &mut cooldowns {
+///         cooldown.0.tick(deltas.delta_or(domain, time.delta()));
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BoundTimeDomain(TypeId);
+
+impl BoundTimeDomain {
+    /// Bind an entity to the time domain `D`.
+    pub fn new<D: TimeDomain>() -> Self {
+        Self(TypeId::of::<D>())
+    }
+
+    /// Returns `true` if this binds to the time domain `D`.
+    pub fn is<D: TimeDomain>(&self) -> bool {
+        self.0 == TypeId::of::<D>()
+    }
+}
+
+/// The delta time of every time domain added with a [`TimeDomainPlugin`] in the current update,
+/// to advance entities [bound](BoundTimeDomain) to a time domain without knowing its type.
+#[derive(Resource, Debug, Default)]
+pub struct TimeDomainDeltas {
+    deltas: HashMap<TypeId, Duration>,
+}
+
+impl TimeDomainDeltas {
+    /// Returns the delta time of the domain `bound` to, if this domain was added.
+    pub fn delta(&self, bound: &BoundTimeDomain) -> Option<Duration> {
+        self.deltas.get(&bound.0).copied()
+    }
+
+    /// Returns the delta time of the domain `bound` to, or `default` for unbound entities
+    /// and domains that weren't added.
+    pub fn delta_or(&self, bound: Option<&BoundTimeDomain>, default: Duration) -> Duration {
+        bound.and_then(|bound| self.delta(bound)).unwrap_or(default)
+    }
+}
+
+/// Adds the time domain `D`, inserting and updating the [`Time<Domain<D>>`] resource.
+pub struct TimeDomainPlugin<D: TimeDomain> {
+    /// The clock the domain advances with.
+    pub source: DomainSource,
+    marker: PhantomData<fn() -> D>,
+}
+
+impl<D: TimeDomain> Default for TimeDomainPlugin<D> {
+    fn default() -> Self {
+        Self {
+            source: DomainSource::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<D: TimeDomain> TimeDomainPlugin<D> {
+    /// Create a plugin adding the time domain `D`, advancing with the given `source`.
+    pub fn with_source(source: DomainSource) -> Self {
+        Self {
+            source,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<D: TimeDomain> Plugin for TimeDomainPlugin<D> {
+    fn build(&self, app: &mut App) {
+        let mut time = Time::<Domain<D>>::default();
+        time.set_source(self.source);
+        app.insert_resource(time)
+            .init_resource::<TimeDomainDeltas>()
+            .add_systems(
+                First,
+                update_domain_time::<D>
+                    .in_set(TimeSystem)
+                    .after(time_system),
+            );
+    }
+}
+
+/// Advances [`Time<Domain<D>>`] based on the elapsed time of its source.
+pub fn update_domain_time<D: TimeDomain>(
+    mut time: ResMut<Time<Domain<D>>>,
+    mut deltas: ResMut<TimeDomainDeltas>,
+    real: Res<Time<Real>>,
+    virt: Res<Time<Virtual>>,
+) {
+    let source_delta = match time.source() {
+        DomainSource::Virtual => virt.delta(),
+        DomainSource::Real => real.delta(),
+    };
+    time.advance_with_source_delta(source_delta);
+    deltas.deltas.insert(TypeId::of::<D>(), time.delta());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{common_conditions::on_domain_timer, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::Update;
+
+    struct Slow;
+
+    impl TimeDomain for Slow {}
+
+    struct Ui;
+
+    impl TimeDomain for Ui {}
+
+    #[derive(Resource, Default)]
+    struct Ticks(u32);
+
+    fn app(time_step: Duration) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            TimeDomainPlugin::<Slow>::default(),
+            TimeDomainPlugin::<Ui>::with_source(DomainSource::Real),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step));
+        app
+    }
+
+    #[test]
+    fn test_relative_speed_and_pause() {
+        let mut time = Time::<Domain<Slow>>::default();
+
+        time.set_relative_speed(0.5);
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert_eq!(time.effective_speed(), 0.5);
+        assert_eq!(time.delta(), Duration::from_millis(125));
+        assert_eq!(time.elapsed(), Duration::from_millis(125));
+
+        time.pause();
+        time.advance_with_source_delta(Duration::from_millis(250));
+
+        assert!(time.was_paused());
+        assert_eq!(time.delta(), Duration::ZERO);
+        assert_eq!(time.elapsed(), Duration::from_millis(125));
+    }
+
+    #[test]
+    fn update_domain_time_advances_with_the_source() {
+        let mut app = app(Duration::from_millis(100));
+        app.world_mut()
+            .resource_mut::<Time<Domain<Slow>>>()
+            .set_relative_speed(0.5);
+
+        // The first update only starts the real clock.
+        app.update();
+        assert_eq!(
+            app.world().resource::<Time<Domain<Slow>>>().delta(),
+            Duration::ZERO
+        );
+
+        app.update();
+        assert_eq!(
+            app.world().resource::<Time<Domain<Slow>>>().delta(),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            app.world().resource::<Time<Domain<Ui>>>().delta(),
+            Duration::from_millis(100)
+        );
+        let deltas = app.world().resource::<TimeDomainDeltas>();
+        assert_eq!(
+            deltas.delta(&BoundTimeDomain::new::<Slow>()),
+            Some(Duration::from_millis(50))
+        );
+        assert_eq!(
+            deltas.delta_or(None, Duration::from_millis(100)),
+            Duration::from_millis(100)
+        );
+
+        // Pausing the virtual clock pauses the domains advancing with it, but not the others.
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Time<Domain<Slow>>>().delta(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            app.world().resource::<Time<Domain<Slow>>>().elapsed(),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            app.world().resource::<Time<Domain<Ui>>>().delta(),
+            Duration::from_millis(100)
+        );
+
+        // Pausing a domain doesn't affect the other domains.
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        app.world_mut().resource_mut::<Time<Domain<Ui>>>().pause();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Time<Domain<Slow>>>().delta(),
+            Duration::from_millis(50)
+        );
+        assert_eq!(
+            app.world().resource::<Time<Domain<Ui>>>().delta(),
+            Duration::ZERO
+        );
+        assert_eq!(
+            app.world()
+                .resource::<TimeDomainDeltas>()
+                .delta(&BoundTimeDomain::new::<Ui>()),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn on_domain_timer_ticks_with_the_domain() {
+        let mut app = app(Duration::from_millis(100));
+        app.init_resource::<Ticks>()
+            .add_systems(
+                Update,
+                (|mut ticks: ResMut<Ticks>| ticks.0 += 1)
+                    .run_if(on_domain_timer::<Slow>(Duration::from_millis(200))),
+            )
+            .world_mut()
+            .resource_mut::<Time<Domain<Slow>>>()
+            .set_relative_speed(0.5);
+
+        // The domain advances by 50ms on each update after the first.
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Ticks>().0, 0);
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        // The timer doesn't tick while the domain is paused.
+        app.world_mut().resource_mut::<Time<Domain<Slow>>>().pause();
+        for _ in 0..8 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Ticks>().0, 1);
+
+        app.world_mut()
+            .resource_mut::<Time<Domain<Slow>>>()
+            .unpause();
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+    }
+}
@@ -64,11 +64,20 @@ use crate::{time::Time, virt::Virtual};
 /// [`FixedUpdate`](bevy_app::FixedUpdate), even if it is still during the same
 /// frame. Any [`overstep()`](Time::overstep) present in the accumulator will be
 /// processed according to the new [`timestep()`](Time::timestep) value.
+///
+/// Each run of the [`FixedMain`] schedules is numbered by its [`tick()`](Time::tick),
+/// which can be used to identify simulation steps across machines, for example
+/// to pin inputs to a tick with a [`TickInputBuffer`](crate::TickInputBuffer).
+/// Past ticks can be simulated again with [`rollback_to()`](Time::rollback_to), after
+/// restoring the state of the world at that tick, to correct mispredictions.
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub struct Fixed {
     timestep: Duration,
     overstep: Duration,
+    tick: u64,
+    rollback: Option<u64>,
+    resimulating: bool,
 }
 
 impl Time<Fixed> {
@@ -205,6 +214,51 @@ impl Time<Fixed> {
         self.context().overstep.as_secs_f64() / self.context().timestep.as_secs_f64()
     }
 
+    /// Returns the number of the current fixed tick.
+    ///
+    /// Ticks are numbered from `1`, and the number increases by one before each run of the
+    /// [`FixedMain`] schedules, so it is the number of the tick being simulated while they run.
+    /// It is `0` until the first run.
+    #[inline]
+    pub fn tick(&self) -> u64 {
+        self.context().tick
+    }
+
+    /// Requests the ticks from `tick` to the current tick to be simulated again, before the
+    /// next ticks are simulated.
+    ///
+    /// The ticks are simulated again at the start of the next run of the fixed main loop, with
+    /// the same [`tick()`](Self::tick) numbers and [`elapsed()`](Self::elapsed) times as the
+    /// first time, assuming the timestep hasn't changed since. Restoring the state of the world
+    /// at the start of `tick` is left to the caller.
+    ///
+    /// If several rollbacks are requested before they are processed, the earliest tick is used.
+    /// Requests for ticks that weren't simulated yet are ignored.
+    pub fn rollback_to(&mut self, tick: u64) {
+        let tick = tick.max(1);
+        if tick > self.tick() {
+            return;
+        }
+        let context = self.context_mut();
+        context.rollback = Some(context.rollback.map_or(tick, |rollback| rollback.min(tick)));
+    }
+
+    /// Returns the earliest tick requested with [`rollback_to()`](Self::rollback_to)
+    /// that wasn't simulated again yet.
+    #[inline]
+    pub fn pending_rollback(&self) -> Option<u64> {
+        self.context().rollback
+    }
+
+    /// Returns `true` if the current tick is being simulated again after a
+    /// [rollback](Self::rollback_to).
+    ///
+    /// This can be used to skip side effects that shouldn't be repeated, like playing sounds.
+    #[inline]
+    pub fn is_resimulating(&self) -> bool {
+        self.context().resimulating
+    }
+
     fn accumulate(&mut self, delta: Duration) {
         self.context_mut().overstep += delta;
     }
@@ -214,6 +268,7 @@ impl Time<Fixed> {
         if let Some(new_value) = self.context_mut().overstep.checked_sub(timestep) {
             // reduce accumulated and increase elapsed by period
             self.context_mut().overstep = new_value;
+            self.context_mut().tick += 1;
             self.advance_by(timestep);
             true
         } else {
@@ -228,6 +283,9 @@ impl Default for Fixed {
         Self {
             timestep: Time::<Fixed>::DEFAULT_TIMESTEP,
             overstep: Duration::ZERO,
+            tick: 0,
+            rollback: None,
+            resimulating: false,
         }
     }
 }
@@ -236,7 +294,18 @@ impl Default for Fixed {
 /// [`Time<Virtual>`](Virtual) and [`Time::overstep`].
 /// You can order your systems relative to this by using
 /// [`RunFixedMainLoopSystem`](bevy_app::prelude::RunFixedMainLoopSystem).
+///
+/// Ticks requested with [`Time::rollback_to`] are simulated again first.
 pub(super) fn run_fixed_main_schedule(world: &mut World) {
+    if let Some(tick) = world
+        .resource_mut::<Time<Fixed>>()
+        .context_mut()
+        .rollback
+        .take()
+    {
+        resimulate_fixed_main(world, tick);
+    }
+
     let delta = world.resource::<Time<Virtual>>().delta();
     world.resource_mut::<Time<Fixed>>().accumulate(delta);
 
@@ -251,6 +320,48 @@ pub(super) fn run_fixed_main_schedule(world: &mut World) {
     *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
 }
 
+/// Runs [`FixedMain`] again for the ticks from `from_tick` to the current
+/// [`tick()`](Time::tick) of [`Time<Fixed>`], without consuming any [`overstep()`](Time::overstep).
+///
+/// During these runs, [`Time<Fixed>`] and the generic [`Time`] report the same
+/// [`tick()`](Time::tick) and [`elapsed()`](Time::elapsed) values as during the first
+/// simulation of the ticks, assuming the timestep hasn't changed since, and
+/// [`is_resimulating()`](Time::is_resimulating) returns `true`.
+///
+/// Restoring the state of the world at the start of `from_tick` is left to the caller.
+/// Does nothing if `from_tick` is after the current tick.
+///
+/// This is usually called through [`Time::rollback_to`], which makes the next run of the
+/// fixed main loop call it.
+pub fn resimulate_fixed_main(world: &mut World, from_tick: u64) {
+    let from_tick = from_tick.max(1);
+    let mut fixed = world.resource_mut::<Time<Fixed>>();
+    let current_tick = fixed.tick();
+    if from_tick > current_tick {
+        return;
+    }
+    let ticks = current_tick - from_tick + 1;
+    let timestep = fixed.timestep();
+    fixed.rewind_by(timestep.saturating_mul(u32::try_from(ticks).unwrap_or(u32::MAX)));
+    let context = fixed.context_mut();
+    context.tick = from_tick - 1;
+    context.resimulating = true;
+
+    for _ in 0..ticks {
+        let mut fixed = world.resource_mut::<Time<Fixed>>();
+        fixed.context_mut().tick += 1;
+        fixed.advance_by(timestep);
+        *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+        let _ = world.try_run_schedule(FixedMain);
+    }
+
+    world
+        .resource_mut::<Time<Fixed>>()
+        .context_mut()
+        .resimulating = false;
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,219 @@
+use alloc::collections::VecDeque;
+use bevy_app::{App, FixedFirst, Plugin, RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut, Resource},
+};
+use bevy_utils::tracing::warn;
+use core::marker::PhantomData;
+
+use crate::{fixed::Fixed, time::Time};
+
+/// A buffer of snapshots of the input resource `I`, one per fixed [tick](Time::tick),
+/// filled by [`TickInputPlugin<I>`].
+///
+/// At the start of each tick, the [`FixedMain`](bevy_app::FixedMain) schedules either record
+/// the current value of `I` for the tick, or, if the buffer already holds an input for the tick,
+/// write this input to `I` instead. The value of `I` replaced by a buffered input is restored
+/// before recording the next tick, and after the fixed main loop. Inputs can be [inserted](Self::insert) for ticks that
+/// haven't been simulated yet, for example when they're received from the network, and ticks
+/// simulated again after a [rollback](Time::rollback_to) see the same inputs as the first time.
+///
+/// `I` is usually one of the input resources of `bevy_input`, such as `ButtonInput<KeyCode>`,
+/// or a resource holding the actions of a player.
+///
+/// Only the inputs of the last [`capacity`](Self::capacity) ticks are kept. Ticks older than
+/// the buffer that are simulated again after a rollback see the current value of `I`, which isn't
+/// recorded for them, and a warning is logged.
+#[derive(Resource, Debug)]
+pub struct TickInputBuffer<I> {
+    inputs: VecDeque<(u64, I)>,
+    capacity: usize,
+    live: Option<I>,
+}
+
+impl<I> TickInputBuffer<I> {
+    /// The default number of ticks whose inputs are kept, which is two seconds
+    /// with the default timestep.
+    pub const DEFAULT_CAPACITY: usize = 128;
+
+    /// Create an empty buffer keeping the inputs of the last `capacity` ticks.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inputs: VecDeque::with_capacity(capacity),
+            capacity,
+            live: None,
+        }
+    }
+
+    /// The number of ticks whose inputs are kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the number of ticks whose inputs are kept, dropping the inputs of the oldest
+    /// ticks if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.trim();
+    }
+
+    /// Returns the input of `tick`, if it is in the buffer.
+    pub fn get(&self, tick: u64) -> Option<&I> {
+        self.position(tick).ok().map(|index| &self.inputs[index].1)
+    }
+
+    /// Sets the input of `tick`, replacing the input already in the buffer for this tick.
+    ///
+    /// The input is dropped if `tick` is older than all the ticks kept in a full buffer.
+    pub fn insert(&mut self, tick: u64, input: I) {
+        match self.position(tick) {
+            Ok(index) => self.inputs[index].1 = input,
+            Err(index) => {
+                self.inputs.insert(index, (tick, input));
+                self.trim();
+            }
+        }
+    }
+
+    /// Removes the inputs of all ticks after `tick`, for example to record them again
+    /// from local input after a rollback.
+    pub fn truncate_after(&mut self, tick: u64) {
+        let index = match self.position(tick) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+        self.inputs.truncate(index);
+    }
+
+    /// The oldest tick whose input is in the buffer.
+    pub fn oldest_tick(&self) -> Option<u64> {
+        self.inputs.front().map(|(tick, _)| *tick)
+    }
+
+    /// The latest tick whose input is in the buffer.
+    pub fn latest_tick(&self) -> Option<u64> {
+        self.inputs.back().map(|(tick, _)| *tick)
+    }
+
+    /// Iterate over the ticks in the buffer and their inputs, from the oldest to the latest.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &I)> {
+        self.inputs.iter().map(|(tick, input)| (*tick, input))
+    }
+
+    fn position(&self, tick: u64) -> Result<usize, usize> {
+        self.inputs.binary_search_by_key(&tick, |(tick, _)| *tick)
+    }
+
+    fn trim(&mut self) {
+        while self.inputs.len() > self.capacity {
+            self.inputs.pop_front();
+        }
+    }
+}
+
+impl<I> Default for TickInputBuffer<I> {
+    fn default() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+}
+
+/// Records the input resource `I` in a [`TickInputBuffer<I>`] at the start of each fixed tick,
+/// and restores it from the buffer for ticks whose input is already known.
+pub struct TickInputPlugin<I> {
+    /// The number of ticks whose inputs are kept.
+    pub capacity: usize,
+    marker: PhantomData<fn() -> I>,
+}
+
+impl<I> Default for TickInputPlugin<I> {
+    fn default() -> Self {
+        Self {
+            capacity: TickInputBuffer::<I>::DEFAULT_CAPACITY,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I: Resource + Clone> Plugin for TickInputPlugin<I> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TickInputBuffer::<I>::with_capacity(self.capacity))
+            .add_systems(FixedFirst, buffer_tick_input::<I>)
+            .add_systems(
+                RunFixedMainLoop,
+                restore_live_input::<I>.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+            );
+    }
+}
+
+/// Records the input of the current fixed tick, or restores it if it is already in the buffer.
+pub fn buffer_tick_input<I: Resource + Clone>(
+    time: Res<Time<Fixed>>,
+    mut buffer: ResMut<TickInputBuffer<I>>,
+    input: Option<ResMut<I>>,
+) {
+    let Some(mut input) = input else {
+        return;
+    };
+    let tick = time.tick();
+    let buffer = &mut *buffer;
+    match buffer.get(tick) {
+        Some(buffered) => {
+            let buffered = buffered.clone();
+            let live = core::mem::replace(&mut *input, buffered);
+            buffer.live.get_or_insert(live);
+        }
+        None => {
+            if let Some(live) = buffer.live.take() {
+                *input = live;
+            }
+            // The input of a tick older than the buffer was lost, so the current input
+            // mustn't be recorded in its place.
+            if time.is_resimulating() && buffer.oldest_tick().is_some_and(|oldest| tick < oldest) {
+                warn!(
+                    "Simulating tick {tick} again without its input `{}`, which is older than the buffered inputs",
+                    core::any::type_name::<I>()
+                );
+                return;
+            }
+            buffer.insert(tick, input.clone());
+        }
+    }
+}
+
+/// Restores the value of the input resource `I` replaced by a buffered input during
+/// the fixed main loop.
+pub fn restore_live_input<I: Resource + Clone>(
+    mut buffer: ResMut<TickInputBuffer<I>>,
+    input: Option<ResMut<I>>,
+) {
+    let Some(live) = buffer.live.take() else {
+        return;
+    };
+    if let Some(mut input) = input {
+        *input = live;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_buffer_keeps_latest_ticks() {
+        let mut buffer = TickInputBuffer::with_capacity(2);
+
+        buffer.insert(2, 'b');
+        buffer.insert(1, 'a');
+        buffer.insert(3, 'c');
+
+        assert_eq!(buffer.oldest_tick(), Some(2));
+        assert_eq!(buffer.get(1), None);
+        assert_eq!(buffer.get(2), Some(&'b'));
+        assert_eq!(buffer.get(3), Some(&'c'));
+
+        buffer.insert(2, 'x');
+        buffer.truncate_after(2);
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![(2, &'x')]);
+    }
+}
@@ -6,16 +6,22 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
+extern crate alloc;
+
 /// Common run conditions
 pub mod common_conditions;
+mod domain;
 mod fixed;
+mod input_buffer;
 mod real;
 mod stopwatch;
 mod time;
 mod timer;
 mod virt;
 
+pub use domain::*;
 pub use fixed::*;
+pub use input_buffer::*;
 pub use real::*;
 pub use stopwatch::*;
 pub use time::*;
@@ -27,7 +33,7 @@ pub use virt::*;
 /// This includes the most common types in this crate, re-exported for your convenience.
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
+    pub use crate::{Domain, Fixed, Real, Time, Timer, TimerMode, Virtual};
 }
 
 use bevy_app::{prelude::*, RunFixedMainLoop};
@@ -155,7 +161,9 @@ pub fn time_system(
 
 #[cfg(test)]
 mod tests {
-    use crate::{Fixed, Time, TimePlugin, TimeUpdateStrategy, Virtual};
+    use crate::{
+        Fixed, TickInputBuffer, TickInputPlugin, Time, TimePlugin, TimeUpdateStrategy, Virtual,
+    };
     use bevy_app::{App, FixedUpdate, Startup, Update};
     use bevy_ecs::{
         event::{Event, EventReader, EventRegistry, EventWriter, Events, ShouldUpdateEvents},
@@ -373,4 +381,91 @@ mod tests {
             }
         }
     }
+
+    #[derive(Resource, Clone, Default, Debug, PartialEq)]
+    struct Thrust(u32);
+
+    #[derive(Resource, Default)]
+    struct Position {
+        value: u32,
+        ticks: Vec<(u64, bool)>,
+    }
+
+    fn integrate(time: Res<Time<Fixed>>, thrust: Res<Thrust>, mut position: ResMut<Position>) {
+        position.value += thrust.0;
+        position.ticks.push((time.tick(), time.is_resimulating()));
+    }
+
+    #[test]
+    fn rollback_resimulates_ticks_with_buffered_inputs() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TickInputPlugin::<Thrust>::default()))
+            .init_resource::<Thrust>()
+            .init_resource::<Position>()
+            .add_systems(FixedUpdate, integrate)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ));
+
+        // The first update only starts the clock.
+        app.update();
+        for thrust in [1, 2, 3] {
+            app.world_mut().resource_mut::<Thrust>().0 = thrust;
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Time<Fixed>>().tick(), 3);
+        assert_eq!(app.world().resource::<Position>().value, 6);
+
+        // A late input for tick 2 is received: restore the state at tick 2 and simulate again.
+        app.world_mut()
+            .resource_mut::<TickInputBuffer<Thrust>>()
+            .insert(2, Thrust(10));
+        app.world_mut().resource_mut::<Position>().value = 1;
+        app.world_mut().resource_mut::<Time<Fixed>>().rollback_to(2);
+        app.world_mut().resource_mut::<Thrust>().0 = 0;
+        app.update();
+
+        let position = app.world().resource::<Position>();
+        assert_eq!(position.value, 1 + 10 + 3);
+        assert_eq!(
+            position.ticks[3..],
+            [(2, true), (3, true), (4, false)],
+            "ticks 2 and 3 should be simulated again before tick 4"
+        );
+        assert_eq!(app.world().resource::<Thrust>().0, 0);
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().elapsed(),
+            Time::<Fixed>::default().timestep() * 4
+        );
+    }
+
+    #[test]
+    fn rollback_before_buffered_inputs_does_not_record_them() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TickInputPlugin::<Thrust>::default()))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ));
+
+        // The input only exists from tick 2, so the input of tick 1 isn't buffered.
+        app.update();
+        app.update();
+        for thrust in [2, 3] {
+            app.insert_resource(Thrust(thrust));
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Time<Fixed>>().tick(), 3);
+
+        app.world_mut().resource_mut::<Time<Fixed>>().rollback_to(1);
+        app.world_mut().resource_mut::<Thrust>().0 = 0;
+        app.update();
+
+        let buffer = app.world().resource::<TickInputBuffer<Thrust>>();
+        assert_eq!(buffer.get(1), None);
+        assert_eq!(
+            buffer.iter().collect::<Vec<_>>(),
+            [(2, &Thrust(2)), (3, &Thrust(3)), (4, &Thrust(0))]
+        );
+        assert_eq!(app.world().resource::<Thrust>().0, 0);
+    }
 }
@@ -249,6 +249,17 @@ impl<T: Default> Time<T> {
         self.advance_by(elapsed - self.elapsed);
     }
 
+    /// Move this clock back by `duration`, down to zero elapsed time, without changing
+    /// [`Self::delta`].
+    pub(crate) fn rewind_by(&mut self, duration: Duration) {
+        self.elapsed = self.elapsed.saturating_sub(duration);
+        self.elapsed_secs = self.elapsed.as_secs_f32();
+        self.elapsed_secs_f64 = self.elapsed.as_secs_f64();
+        self.elapsed_wrapped = duration_rem(self.elapsed, self.wrap_period);
+        self.elapsed_secs_wrapped = self.elapsed_wrapped.as_secs_f32();
+        self.elapsed_secs_wrapped_f64 = self.elapsed_wrapped.as_secs_f64();
+    }
+
     /// Returns the modulus used to calculate [`elapsed_wrapped`](#method.elapsed_wrapped).
     ///
     /// **Note:** The default modulus is one hour.
@@ -0,0 +1,105 @@
+//! Tool that processes assets without running a game, for build pipelines on headless machines.
+//!
+//! It registers the asset loaders, processors and savers of Bevy's default plugins, processes the assets of the
+//! source directory into the destination directory, reports which assets failed and exits with a non-zero code
+//! if any did. With `--cache`, processed assets are reused from a directory shared with other machines.
+//! Relative paths are resolved from the current directory, which also holds the processor log in
+//! `imported_assets/log`.
+
+use std::process::ExitCode;
+
+use bevy::{
+    asset::{
+        io::{pak::PakOutput, AssetSourceId},
+        processor::{AssetProcessor, ProcessCache},
+        AssetMode,
+    },
+    prelude::*,
+    render::{settings::WgpuSettings, RenderPlugin},
+    tasks::block_on,
+};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[arg(short, long, default_value = "assets")]
+    /// Directory of the source assets
+    source: String,
+
+    #[arg(short, long, default_value = "imported_assets/Default")]
+    /// Directory the processed assets are written to
+    destination: String,
+
+    #[arg(short, long)]
+    /// Keep running and reprocess the assets that change
+    watch: bool,
+
+    #[arg(long)]
+    /// Also bundle the processed assets into this pak archive
+    pak: Option<String>,
+
+    #[arg(long)]
+    /// Reuse processed assets from this directory, shared with other machines, and store new ones in it
+    cache: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if std::env::var_os("BEVY_ASSET_ROOT").is_none() {
+        std::env::set_var("BEVY_ASSET_ROOT", std::env::current_dir().unwrap());
+    }
+
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(AssetPlugin {
+                file_path: args.source,
+                processed_file_path: args.destination,
+                mode: AssetMode::Processed,
+                watch_for_changes_override: Some(args.watch),
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: WgpuSettings {
+                    backends: None,
+                    ..default()
+                }
+                .into(),
+                ..default()
+            }),
+    );
+    // some plugins register their asset loaders when finishing
+    app.finish();
+    app.cleanup();
+
+    let processor = app.world().resource::<AssetProcessor>().clone();
+    if let Some(pak) = args.pak {
+        processor.add_pak_output(AssetSourceId::Default, PakOutput::new(pak));
+    }
+    if let Some(cache) = args.cache {
+        processor.set_cache(ProcessCache::from_directory(cache));
+    }
+    processor.process_assets();
+
+    let summary = block_on(processor.summary());
+    println!(
+        "{} assets processed successfully, {} failed",
+        summary.processed.len(),
+        summary.failed.len()
+    );
+    for path in &summary.failed {
+        println!("  failed: {path}");
+    }
+
+    if args.watch {
+        println!("Watching for changes");
+        block_on(processor.listen_for_source_change_events());
+    }
+
+    if summary.is_success() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}